@@ -0,0 +1,641 @@
+// Standard library
+use std::collections::HashSet;
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+// 3rd party crates
+use config::FileFormat;
+
+// Project imports
+use crate::metrics::types::MetricsManager;
+use crate::notifications::functions::get_notifiers;
+use crate::notifications::types::TestNotification;
+use crate::providers::cloudflare::functions::{delete_dns_record, fetch_zone_records};
+use crate::providers::cloudflare::types::{CfRecordType, Cloudflare};
+use crate::providers::functions::{get_providers, record_content_matches};
+use crate::providers::AnyDnsProvider;
+use crate::settings::functions::default_config;
+use crate::settings::types::{ConfigManager, Settings};
+use crate::utility::ip_detector::types::{IpDetector, IpVersion};
+
+// Current module imports
+use super::types::{ConfigFormat, RecordReport, RecordStatus};
+
+/// Prints the configuration file path the client would use, and whether
+/// the file exists. Returns the process exit code.
+pub fn config_path(config_path: Option<PathBuf>) -> i32 {
+    let path: PathBuf = match ConfigManager::get_config_path(config_path) {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!("{}", e);
+            return 1;
+        }
+    };
+
+    println!("{}", path.display());
+    if path.exists() {
+        println!("The file exists.");
+    } else {
+        println!("The file does not exist yet, a default one is created on the first run.");
+    }
+    0
+}
+
+/// Prints the default configuration in the given format.
+/// Returns the process exit code.
+pub fn export_config(format: ConfigFormat) -> i32 {
+    let format = match format {
+        ConfigFormat::Toml => FileFormat::Toml,
+        ConfigFormat::Yaml => FileFormat::Yaml,
+        ConfigFormat::Json => FileFormat::Json,
+    };
+    match default_config(format) {
+        Ok(contents) => {
+            print!("{}", contents);
+            0
+        }
+        Err(e) => {
+            eprintln!("Failed to render the default configuration: {}", e);
+            1
+        }
+    }
+}
+
+/// Validates the configuration without contacting any DNS API.
+/// On success the managed records are printed; otherwise every error found
+/// is printed to stderr. Returns the process exit code.
+pub async fn validate(config_path: Option<PathBuf>) -> i32 {
+    let config: Arc<ConfigManager> = match ConfigManager::load_existing(config_path).await {
+        Ok(config) => Arc::new(config),
+        Err(e) => {
+            eprintln!("Invalid configuration: {}", e);
+            return 1;
+        }
+    };
+
+    let providers = match get_providers(Arc::clone(&config), Arc::new(MetricsManager::new())).await
+    {
+        Ok(providers) => providers,
+        Err(e) => {
+            eprintln!("Failed to set up providers: {}", e);
+            return 1;
+        }
+    };
+
+    let mut valid = true;
+    if providers.is_empty() {
+        eprintln!("Invalid configuration: no providers are enabled");
+        valid = false;
+    }
+    for provider in &providers {
+        if let Err(e) = provider.validate_config() {
+            eprintln!(
+                "Invalid configuration for provider '{}': {}",
+                provider.get_name(),
+                e
+            );
+            valid = false;
+        }
+    }
+    if !valid {
+        return 1;
+    }
+
+    println!(
+        "Configuration at {} is valid.",
+        config.config_path.display()
+    );
+    for line in describe_records(&*config.settings.read().await) {
+        println!("{}", line);
+    }
+    0
+}
+
+/// Lists every enabled provider with the records it manages.
+fn describe_records(settings: &Settings) -> Vec<String> {
+    fn record_name(subdomain: &str, zone: &str) -> String {
+        if subdomain.is_empty() {
+            zone.to_string()
+        } else {
+            format!("{}.{}", subdomain, zone)
+        }
+    }
+
+    let mut lines = Vec::new();
+
+    for cf in settings.cloudflare.iter().filter(|cf| cf.enabled) {
+        lines.push(format!("Cloudflare zone {}:", cf.name));
+        for subdomain in &cf.subdomains {
+            let name = record_name(&subdomain.name, &cf.name);
+            if !subdomain.enabled {
+                lines.push(format!("  {} (disabled)", name));
+                continue;
+            }
+            lines.push(match subdomain.record_type {
+                CfRecordType::A => format!("  {} ({})", name, subdomain.ip_version()),
+                CfRecordType::Cname => format!(
+                    "  {} (CNAME -> {})",
+                    name,
+                    subdomain.target.as_deref().unwrap_or(&cf.name)
+                ),
+            });
+        }
+    }
+
+    for arvan in settings.arvancloud.iter().filter(|arvan| arvan.enabled) {
+        lines.push(format!("ArvanCloud zone {}:", arvan.name));
+        for subdomain in &arvan.subdomains {
+            let name = record_name(&subdomain.name, &arvan.name);
+            lines.push(format!(
+                "  {} ({}, TTL {})",
+                name,
+                subdomain.ip_version,
+                subdomain.ttl()
+            ));
+        }
+    }
+
+    for duckdns in settings.duckdns.iter().filter(|duckdns| duckdns.enabled) {
+        lines.push("DuckDNS:".to_string());
+        for domain in duckdns.domain_list().split(',') {
+            lines.push(format!("  {}.duckdns.org ({})", domain, duckdns.ip_version));
+        }
+    }
+
+    for route53 in settings.route53.iter().filter(|route53| route53.enabled) {
+        lines.push(format!(
+            "Route53 zone {} ({}):",
+            route53.name,
+            route53.zone_id()
+        ));
+        for subdomain in &route53.subdomains {
+            let name = record_name(&subdomain.name, &route53.name);
+            lines.push(format!(
+                "  {} ({}, TTL {})",
+                name,
+                subdomain.ip_version,
+                subdomain.ttl()
+            ));
+        }
+    }
+
+    for porkbun in settings.porkbun.iter().filter(|porkbun| porkbun.enabled) {
+        lines.push(format!("Porkbun domain {}:", porkbun.domain_name()));
+        for subdomain in &porkbun.subdomains {
+            lines.push(format!(
+                "  {} ({}, TTL {})",
+                porkbun.fqdn(subdomain.name.trim()),
+                subdomain.ip_version,
+                subdomain.ttl()
+            ));
+        }
+    }
+
+    #[cfg(feature = "mock-provider")]
+    for mock in settings.mock.iter().filter(|mock| mock.enabled) {
+        lines.push(format!("Mock provider {} ({})", mock.name, mock.ip_version));
+    }
+
+    lines
+}
+
+/// Compares the records every provider currently serves with the detected IPs.
+/// Only reads are made; nothing is updated. Returns the process exit code,
+/// which is non-zero if any record or provider could not be checked.
+pub async fn status(config_path: Option<PathBuf>) -> i32 {
+    let Some(report) = fetch_record_statuses(config_path).await else {
+        return 1;
+    };
+
+    print_status_table(&report.statuses);
+    for (provider, reason) in &report.unknown {
+        eprintln!("Skipping {}: {}", provider, reason);
+    }
+    for provider in &report.unreadable {
+        println!("Skipping {}: its records can't be read back", provider);
+    }
+    for provider in &report.providers {
+        if let Some(rate_limit) = provider.rate_limit_status().await {
+            println!(
+                "{}: {}/{} API requests available this window",
+                provider.get_name(),
+                rate_limit.available,
+                rate_limit.max_requests
+            );
+        }
+    }
+    if !report.unknown.is_empty() || report.statuses.iter().any(|status| status.current.is_err()) {
+        1
+    } else {
+        0
+    }
+}
+
+/// Reports which managed records are already correct, which are stale and
+/// which are missing, using the same comparison as `status`. Nothing is
+/// changed. Returns the process exit code, which is non-zero if any record
+/// or provider could not be checked.
+pub async fn reconcile(config_path: Option<PathBuf>) -> i32 {
+    let Some(report) = fetch_record_statuses(config_path).await else {
+        return 1;
+    };
+
+    let mut correct: Vec<String> = Vec::new();
+    let mut stale: Vec<String> = Vec::new();
+    let mut missing: Vec<String> = Vec::new();
+    let mut unknown: Vec<String> = report
+        .unknown
+        .iter()
+        .map(|(provider, reason)| format!("{} ({})", provider, reason))
+        .chain(
+            report
+                .unreadable
+                .iter()
+                .map(|provider| format!("{} (records can't be read back)", provider)),
+        )
+        .collect();
+    for status in &report.statuses {
+        let record = format!("{} {}", status.domain, status.record_type);
+        match (&status.current, &status.expected) {
+            (Err(e), _) => unknown.push(format!("{} (fetch failed: {})", record, e)),
+            (Ok(_), None) => unknown.push(format!("{} (address not detected)", record)),
+            (Ok(current), Some(expected)) if current.is_empty() => {
+                missing.push(format!("{} -> {}", record, expected))
+            }
+            (Ok(current), Some(expected)) if record_matches(current, expected) => {
+                correct.push(format!("{} = {}", record, expected))
+            }
+            (Ok(current), Some(expected)) => {
+                stale.push(format!("{} {} -> {}", record, current.join(", "), expected))
+            }
+        }
+    }
+
+    for (title, records) in [
+        ("Correct", &correct),
+        ("Stale", &stale),
+        ("Missing", &missing),
+        ("Unknown", &unknown),
+    ] {
+        println!("{} ({}):", title, records.len());
+        for record in records {
+            println!("  {}", record);
+        }
+    }
+    if stale.is_empty() && missing.is_empty() {
+        println!("No drift found.");
+    } else {
+        println!(
+            "{} record(s) differ from the detected addresses.",
+            stale.len() + missing.len()
+        );
+    }
+
+    if !report.unknown.is_empty() || report.statuses.iter().any(|status| status.current.is_err()) {
+        1
+    } else {
+        0
+    }
+}
+
+/// Detects the current IPs and reads back every address record the enabled
+/// providers manage, next to the address it is expected to hold.
+/// Providers that failed to set up, or whose IP version could not be
+/// detected, are listed as unknown. Providers that can't read their records
+/// back are listed separately, as skipping them is not a failure.
+/// Returns `None` after printing the error if the configuration can't be used.
+async fn fetch_record_statuses(config_path: Option<PathBuf>) -> Option<RecordReport> {
+    let config: ConfigManager = match ConfigManager::load_existing(config_path).await {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Invalid configuration: {}", e);
+            return None;
+        }
+    };
+    let config = Arc::new(config);
+    let settings = config.settings.read().await.clone();
+
+    let providers: Vec<Box<dyn AnyDnsProvider>> =
+        match get_providers(Arc::clone(&config), Arc::new(MetricsManager::new())).await {
+            Ok(providers) => providers,
+            Err(e) => {
+                eprintln!("Failed to set up the providers: {}", e);
+                return None;
+            }
+        };
+    let providers: Vec<Box<dyn AnyDnsProvider>> = providers
+        .into_iter()
+        .filter(|provider| provider.is_enabled())
+        .collect();
+
+    // Configurations that failed to set up are skipped by `get_providers`;
+    // they are reported as unknown instead of dropping out of the report
+    let mut unknown: Vec<(String, String)> = Vec::new();
+    let mut set_up: Vec<&str> = providers
+        .iter()
+        .map(|provider| provider.get_name())
+        .collect();
+    for name in settings.enabled_provider_names() {
+        match set_up.iter().position(|set_up| *set_up == name) {
+            Some(position) => {
+                set_up.swap_remove(position);
+            }
+            None => unknown.push((name, "could not be set up".to_string())),
+        }
+    }
+
+    let ip_detector = IpDetector::new(
+        settings.ip_detection.clone(),
+        &settings.http_client_options(),
+    );
+    let mut detected: Vec<(IpVersion, &str, Option<IpAddr>)> = Vec::new();
+    for (version, label) in [(IpVersion::V4, "IPv4"), (IpVersion::V6, "IPv6")] {
+        if providers
+            .iter()
+            .any(|provider| provider.needs_ip_version(version))
+        {
+            match ip_detector.detect_ip(version).await {
+                Ok(ip) => {
+                    println!("Detected {} address: {}", label, ip);
+                    detected.push((version, label, Some(ip)));
+                }
+                Err(e) => {
+                    eprintln!("Failed to detect {} address: {}", label, e);
+                    detected.push((version, label, None));
+                }
+            }
+        }
+    }
+
+    let mut statuses: Vec<RecordStatus> = Vec::new();
+    let mut unreadable: Vec<String> = Vec::new();
+    for provider in &providers {
+        let name = provider.get_name().to_string();
+
+        // The records an update would write, from the same provider logic
+        let mut expected: Vec<(String, IpAddr)> = Vec::new();
+        for (version, label, ip) in &detected {
+            if !provider.needs_ip_version(*version) {
+                continue;
+            }
+            match ip {
+                Some(ip) => expected.extend(provider.expected_records(ip)),
+                None => unknown.push((name.clone(), format!("{} address not detected", label))),
+            }
+        }
+        if expected.is_empty() {
+            if detected
+                .iter()
+                .any(|(version, _, ip)| ip.is_some() && provider.needs_ip_version(*version))
+            {
+                unreadable.push(name);
+            }
+            continue;
+        }
+
+        let current = provider
+            .get_current_records()
+            .await
+            .map_err(|e| e.to_string());
+        for (domain, address) in expected {
+            let current = current.as_ref().map_err(Clone::clone).map(|records| {
+                records
+                    .iter()
+                    .filter(|(record, ip)| {
+                        record.eq_ignore_ascii_case(&domain) && ip.is_ipv4() == address.is_ipv4()
+                    })
+                    .map(|(_, ip)| ip.to_string())
+                    .collect()
+            });
+            statuses.push(RecordStatus {
+                domain,
+                record_type: if address.is_ipv4() { "A" } else { "AAAA" },
+                current,
+                expected: Some(address.to_string()),
+            });
+        }
+    }
+
+    Some(RecordReport {
+        statuses,
+        unknown,
+        unreadable,
+        providers,
+    })
+}
+
+/// Checks whether a record serves the expected content, with the same
+/// comparison the update path uses to decide a record needs no change.
+fn record_matches(current: &[String], expected: &str) -> bool {
+    !current.is_empty()
+        && current
+            .iter()
+            .all(|current| record_content_matches(current, expected))
+}
+
+/// Prints the record statuses as an aligned table, followed by the errors
+/// of records that could not be fetched.
+fn print_status_table(statuses: &[RecordStatus]) {
+    let rows: Vec<[String; 5]> = statuses
+        .iter()
+        .map(|status| {
+            let current = match &status.current {
+                Ok(contents) if contents.is_empty() => "-".to_string(),
+                Ok(contents) => contents.join(", "),
+                Err(_) => "error".to_string(),
+            };
+            let matches = match (&status.current, &status.expected) {
+                (Ok(contents), Some(expected)) => {
+                    if record_matches(contents, expected) {
+                        "yes"
+                    } else {
+                        "no"
+                    }
+                }
+                _ => "?",
+            };
+            [
+                status.domain.clone(),
+                status.record_type.to_string(),
+                current,
+                status.expected.clone().unwrap_or_else(|| "-".to_string()),
+                matches.to_string(),
+            ]
+        })
+        .collect();
+
+    let header = ["DOMAIN", "TYPE", "CURRENT", "EXPECTED", "MATCH"].map(str::to_string);
+    let mut widths = header.clone().map(|column| column.len());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    for row in std::iter::once(&header).chain(&rows) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        println!("{}", line.join("  ").trim_end());
+    }
+
+    for status in statuses {
+        if let Err(e) = &status.current {
+            eprintln!(
+                "Failed to fetch {} record for {}: {}",
+                status.record_type, status.domain, e
+            );
+        }
+    }
+}
+
+/// Sends a test notification through every configured notification backend.
+/// Each backend's result is printed. Returns the process exit code, which is
+/// non-zero if no backend is configured or any of them failed.
+pub async fn test_notification(config_path: Option<PathBuf>) -> i32 {
+    let config: Arc<ConfigManager> = match ConfigManager::load_existing(config_path).await {
+        Ok(config) => Arc::new(config),
+        Err(e) => {
+            eprintln!("Invalid configuration: {}", e);
+            return 1;
+        }
+    };
+
+    let notifiers = get_notifiers(config).await;
+    if notifiers.is_empty() {
+        eprintln!("No notification backends are configured");
+        return 1;
+    }
+
+    let notification = TestNotification::new();
+    let mut failed = 0;
+    for notifier in &notifiers {
+        match notifier.notify_test(&notification).await {
+            Ok(()) => println!("{}: test notification sent", notifier.get_name()),
+            Err(e) => {
+                eprintln!(
+                    "{}: failed to send test notification: {}",
+                    notifier.get_name(),
+                    e
+                );
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        eprintln!("{} of {} backends failed", failed, notifiers.len());
+        1
+    } else {
+        0
+    }
+}
+
+/// Lists the Cloudflare A and AAAA records tagged with the configured
+/// `record_comment` whose name no longer belongs to a configured subdomain.
+/// With `confirm` the listed records are deleted, otherwise nothing changes.
+/// Returns the process exit code, which is non-zero if any zone failed.
+pub async fn cleanup(config_path: Option<PathBuf>, confirm: bool) -> i32 {
+    let config: ConfigManager = match ConfigManager::load_existing(config_path).await {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Invalid configuration: {}", e);
+            return 1;
+        }
+    };
+    let settings = config.settings.read().await.clone();
+
+    // Without the comment there is no telling our records from anyone else's
+    let comment = settings.update.record_comment.trim();
+    if comment.is_empty() {
+        eprintln!("Cleanup needs `record_comment` to be set to recognize managed records");
+        return 1;
+    }
+
+    let http_options = settings.http_client_options();
+    let mut failed = false;
+    let mut stale_count = 0;
+    for cf_config in settings.cloudflare.iter().filter(|cf| cf.enabled) {
+        let cloudflare = match Cloudflare::new(cf_config.clone())
+            .and_then(|cloudflare| cloudflare.with_http_options(&http_options))
+        {
+            Ok(cloudflare) => cloudflare.with_record_comment(comment),
+            Err(e) => {
+                eprintln!("Failed to set up zone {}: {}", cf_config.name, e);
+                failed = true;
+                continue;
+            }
+        };
+
+        // Disabled subdomains are still configured, so their records are kept
+        let configured: HashSet<String> = cf_config
+            .subdomains
+            .iter()
+            .map(|subdomain| subdomain.fqdn(&cf_config.name).to_ascii_lowercase())
+            .collect();
+
+        for record_type in ["A", "AAAA"] {
+            let records = match fetch_zone_records(&cloudflare, record_type).await {
+                Ok(records) => records,
+                Err(e) => {
+                    eprintln!(
+                        "Failed to fetch {} records of zone {}: {}",
+                        record_type, cf_config.name, e
+                    );
+                    failed = true;
+                    continue;
+                }
+            };
+
+            let mut stale: Vec<_> = records
+                .into_iter()
+                .filter(|(name, _)| !configured.contains(name))
+                .flat_map(|(_, records)| records)
+                .filter(|record| record.comment.as_deref().map(str::trim) == Some(comment))
+                .collect();
+            stale.sort_by(|a, b| a.name.cmp(&b.name));
+
+            for record in stale {
+                stale_count += 1;
+                if !confirm {
+                    println!(
+                        "Would delete {} record {} -> {}",
+                        record_type, record.name, record.content
+                    );
+                    continue;
+                }
+                match cloudflare
+                    .with_rate_limit(delete_dns_record(&cloudflare, &record.id))
+                    .await
+                {
+                    Ok(()) => println!(
+                        "Deleted {} record {} -> {}",
+                        record_type, record.name, record.content
+                    ),
+                    Err(e) => {
+                        eprintln!(
+                            "Failed to delete {} record {}: {}",
+                            record_type, record.name, e
+                        );
+                        failed = true;
+                    }
+                }
+            }
+        }
+    }
+
+    if stale_count == 0 {
+        println!("No stale records found.");
+    } else if !confirm {
+        println!("Run again with --confirm to delete these records.");
+    }
+
+    if failed {
+        1
+    } else {
+        0
+    }
+}
@@ -0,0 +1,112 @@
+// Standard library
+use std::path::PathBuf;
+
+// 3rd party crates
+use clap::{Parser, Subcommand, ValueEnum};
+
+// Project imports
+use crate::providers::AnyDnsProvider;
+
+/// Command-line arguments for the DDNS client.
+#[derive(Debug, Parser)]
+#[command(name = "fariba-ddns", version, about = "Fariba Dynamic DNS client")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Path to the configuration file.
+    /// Takes precedence over the FDDNS_CONFIG_PATH environment variable.
+    #[arg(long, value_name = "PATH", global = true)]
+    pub config: Option<PathBuf>,
+
+    /// Run a single detect-and-update pass, then exit.
+    /// The exit code is non-zero if any DNS update failed.
+    #[arg(long)]
+    pub once: bool,
+
+    /// Detect IPs and compare records, but only log the DNS changes
+    /// that would be made. Same as `[update] dry_run = true`.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Never modify Cloudflare records whose comment names another owner.
+    /// Same as `[update] respect_foreign_records = true`.
+    #[arg(long)]
+    pub respect_foreign_records: bool,
+
+    /// Log at debug level, or at trace level when repeated (`-vv`),
+    /// instead of the configured `[log] level`.
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+}
+
+/// Subcommands; without one the client runs as a daemon.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Check the configuration and print the records it manages, then exit.
+    /// No DNS API is contacted and a missing file is not created.
+    Validate,
+    /// Detect the current IPs and compare them with the records the
+    /// providers serve, without changing anything. Providers that failed to
+    /// set up are listed as unknown; ones that can't read their records
+    /// back, such as DuckDNS, are skipped.
+    Status,
+    /// Send a test notification through every configured notification
+    /// backend and report which of them succeeded.
+    TestNotification,
+    /// Report which managed records are correct, stale or missing, as a
+    /// one-shot preview of what the next update would change. Records are
+    /// read the same way as by `status`.
+    Reconcile,
+    /// Print the path of the configuration file and whether it exists, then exit.
+    ConfigPath,
+    /// Print the default configuration to stdout, e.g. to redirect it into a
+    /// file to edit. Nothing is written to disk.
+    ExportConfig {
+        /// Format of the printed configuration; only TOML keeps the comments.
+        #[arg(long, value_enum, default_value_t = ConfigFormat::Toml)]
+        format: ConfigFormat,
+    },
+    /// List Cloudflare A and AAAA records carrying the `record_comment` whose
+    /// name is no longer configured. They are only deleted with `--confirm`.
+    Cleanup {
+        /// Delete the listed records instead of only printing them.
+        #[arg(long)]
+        confirm: bool,
+    },
+}
+
+/// Configuration file formats the `export-config` command can print.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum ConfigFormat {
+    Toml,
+    Yaml,
+    Json,
+}
+
+/// Live state of one managed record, as shown by the `status` and
+/// `reconcile` commands.
+#[derive(Debug)]
+pub struct RecordStatus {
+    /// Fully qualified record name
+    pub domain: String,
+    /// "A" or "AAAA"
+    pub record_type: &'static str,
+    /// Contents currently served, or why they could not be fetched
+    pub current: Result<Vec<String>, String>,
+    /// Content the record should have; `None` if the IP could not be detected
+    pub expected: Option<String>,
+}
+
+/// Everything the `status` and `reconcile` commands read back.
+pub struct RecordReport {
+    /// Every managed address record of the enabled providers
+    pub statuses: Vec<RecordStatus>,
+    /// Providers whose records could not be compared, with the reason
+    pub unknown: Vec<(String, String)>,
+    /// Providers that have no way to read their records back, e.g. DuckDNS;
+    /// skipping them is expected and not a failure
+    pub unreadable: Vec<String>,
+    /// The enabled providers the records were read from
+    pub providers: Vec<Box<dyn AnyDnsProvider>>,
+}
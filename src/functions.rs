@@ -1,24 +1,40 @@
 // Standard library
 use std::error::Error;
-use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::net::IpAddr;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
 // 3rd party crates
 use tokio::sync::broadcast;
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, field, info, instrument, warn, Span};
 
 // Project imports
+use crate::ipc::{
+    functions::publish_status,
+    types::{EventKind, EventLog, SharedStatus},
+};
+use crate::metrics::types::{IpVersionMetrics, MetricsManager};
+use crate::notifications::{
+    functions::{get_notifiers, notify_ip_change, notify_update_failure},
+    traits::Notifier,
+    types::IpChangeEvent,
+};
 use crate::providers::{
-    self,
-    cloudflare::{
-        functions::{get_cloudflares, process_updates},
-        types::Cloudflare,
-    },
-    DnsProvider,
+    errors::ProviderError,
+    functions::{get_providers, process_updates, sync_static_records},
+    types::{PushedIps, UpdateLimits, UpdateSchedule},
+    AnyDnsProvider,
 };
 use crate::settings::types::ConfigManager;
+use crate::types::UpdateContext;
+use crate::utility::ip_detector::constants::NETWORK_RETRY_BASE_SECS;
+use crate::utility::ip_detector::errors::IpDetectionError;
 use crate::utility::ip_detector::types::{IpDetector, IpVersion};
+use crate::utility::ip_state::types::{IpState, StateStore};
+
+/// Id of the next detect-and-update cycle, attached to its tracing span
+static NEXT_CYCLE_ID: AtomicU64 = AtomicU64::new(1);
 
 /// Main application loop that handles IP monitoring and DNS updates.
 ///
@@ -28,69 +44,98 @@ use crate::utility::ip_detector::types::{IpDetector, IpVersion};
 /// - Updates DNS records when changes occur
 /// - Handles network connectivity issues
 /// - Respects configured update intervals and rate limits
+/// - Picks up reloaded configuration without restarting
 /// - Implements graceful shutdown on signal
+///
+/// With `once` set, only the initial detect-and-update pass runs and an
+/// error is returned if any DNS update in it failed.
+/// `status` is refreshed after every cycle for the status socket.
 pub async fn run(
     config: Arc<ConfigManager>,
     mut shutdown_rx: broadcast::Receiver<()>,
+    once: bool,
+    status: SharedStatus,
 ) -> Result<(), Box<dyn Error>> {
-    let settings = config.settings.read().await;
-    let update_interval: u64 = settings.update.interval;
-    info!("🕰️ Updating DNS records every {} seconds", update_interval);
-
-    // Initialize IP detector with configuration
-    let ip_detector = IpDetector::new(settings.ip_detection.clone());
-
-    // Fetch settings and create Cloudflare instances
-    let cloudflares: Vec<Cloudflare> = get_cloudflares(Arc::clone(&config)).await?;
-
-    // Determine which IP versions we need to detect based on subdomain configurations
-    let mut need_ipv4 = false;
-    let mut need_ipv6 = false;
-    for cf in &cloudflares {
-        if !cf.is_enabled() {
-            continue;
-        }
-        for subdomain in &cf.config.subdomains {
-            match subdomain.ip_version {
-                providers::cloudflare::types::IpVersion::V4 => need_ipv4 = true,
-                providers::cloudflare::types::IpVersion::V6 => need_ipv6 = true,
-                providers::cloudflare::types::IpVersion::Both => {
-                    need_ipv4 = true;
-                    need_ipv6 = true;
-                }
-            }
-            if need_ipv4 && need_ipv6 {
-                break;
-            }
-        }
-        if need_ipv4 && need_ipv6 {
-            break;
-        }
+    if !config.settings.read().await.has_enabled_provider() {
+        warn!(
+            "No providers enabled, edit your configuration at {} and start again",
+            config.config_path.display()
+        );
+        return Ok(());
     }
 
-    info!(
-        "IP detection configuration - IPv4: {}, IPv6: {}",
-        need_ipv4, need_ipv6
-    );
+    log_startup_summary(&config).await;
 
-    // Drop the settings lock
-    drop(settings);
+    let metrics = Arc::new(MetricsManager::new());
+    let events = Arc::new(EventLog::new(
+        config.settings.read().await.ipc.event_log_size,
+    ));
+    let mut reload_rx = config.subscribe_reloads();
+    let mut context: UpdateContext = build_update_context(&config, &metrics, &events).await?;
 
-    let mut previous_ipv4: Option<Ipv4Addr> = None;
-    let mut previous_ipv6: Option<Ipv6Addr> = None;
+    // Seed the previous IPs from the last run so unchanged records aren't rewritten
+    let state_store = StateStore::new(&config.config_path);
+    let mut state: IpState = state_store.load();
 
-    // Run the first update immediately
-    detect_and_update_ips(
-        &ip_detector,
-        &cloudflares,
-        need_ipv4,
-        need_ipv6,
-        &mut previous_ipv4,
-        &mut previous_ipv6,
-        None,
-        None,
+    // Run the first update immediately; a single pass fails fast instead of
+    // waiting for the network
+    if !once && !wait_for_network(&context, &mut shutdown_rx).await {
+        info!("Shutdown complete.");
+        return Ok(());
+    }
+    let updates_succeeded = detect_and_update_ips(
+        &context,
+        &mut state,
+        &state_store,
+        Some(shutdown_rx.resubscribe()),
+        Some(shutdown_rx.resubscribe()),
     )
     .await;
+    report_metrics(&metrics, &context).await;
+    publish_status(&status, &state, &metrics, &events).await;
+
+    if once {
+        return if updates_succeeded {
+            Ok(())
+        } else {
+            Err(Box::new(ProviderError::UpdatesFailed))
+        };
+    }
+
+    // Right after boot the network may answer before it is fully usable, so a
+    // failed first detection is retried soon instead of after a full interval
+    let mut delay = context.startup_retry_delay;
+    for attempt in 1..=context.startup_retries {
+        if !context.detection_failed.load(Ordering::Relaxed) {
+            break;
+        }
+        warn!(
+            "First IP detection failed, retrying in {} seconds ({}/{})",
+            delay.as_secs(),
+            attempt,
+            context.startup_retries
+        );
+        tokio::select! {
+            Ok(()) = shutdown_rx.recv() => {
+                info!("Received shutdown signal, stopping...");
+                info!("Shutdown complete.");
+                return Ok(());
+            }
+            _ = tokio::time::sleep(delay) => {}
+        }
+        delay *= 2;
+        context.schedule.reset();
+        detect_and_update_ips(
+            &context,
+            &mut state,
+            &state_store,
+            Some(shutdown_rx.resubscribe()),
+            Some(shutdown_rx.resubscribe()),
+        )
+        .await;
+        report_metrics(&metrics, &context).await;
+        publish_status(&status, &state, &metrics, &events).await;
+    }
 
     loop {
         // Create subscriptions for DNS updates before entering select!
@@ -98,26 +143,54 @@ pub async fn run(
         let ipv6_shutdown = shutdown_rx.resubscribe();
 
         tokio::select! {
-            // Handle shutdown signal
+            // Handle shutdown signal; in-flight updates have already been
+            // given their grace period by `process_updates`
             Ok(_) = shutdown_rx.recv() => {
-                info!("Received shutdown signal, waiting for in-progress updates...");
-                // Allow a short time for in-progress updates to complete
-                tokio::time::sleep(Duration::from_secs(5)).await;
+                info!("Received shutdown signal, stopping...");
                 break;
             }
 
+            // Rebuild everything derived from the settings after a reload
+            Ok(()) = reload_rx.changed() => {
+                match build_update_context(&config, &metrics, &events).await {
+                    Ok(new_context) => context = new_context,
+                    Err(e) => {
+                        error!("Failed to apply reloaded configuration, keeping the previous one: {}", e);
+                        continue;
+                    }
+                }
+
+                // New providers or subdomains may not have the current IP yet,
+                // so forget the known IPs and push them again right away.
+                state = IpState::default();
+                if !wait_for_network(&context, &mut shutdown_rx).await {
+                    break;
+                }
+                detect_and_update_ips(
+                    &context,
+                    &mut state,
+                    &state_store,
+                    Some(ipv4_shutdown),
+                    Some(ipv6_shutdown),
+                ).await;
+                report_metrics(&metrics, &context).await;
+                publish_status(&status, &state, &metrics, &events).await;
+            }
+
             // Wait for the update interval
-            _ = tokio::time::sleep(Duration::from_secs(update_interval)) => {
+            _ = tokio::time::sleep(next_update_delay(&context)) => {
+                if !wait_for_network(&context, &mut shutdown_rx).await {
+                    break;
+                }
                 detect_and_update_ips(
-                    &ip_detector,
-                    &cloudflares,
-                    need_ipv4,
-                    need_ipv6,
-                    &mut previous_ipv4,
-                    &mut previous_ipv6,
+                    &context,
+                    &mut state,
+                    &state_store,
                     Some(ipv4_shutdown),
                     Some(ipv6_shutdown),
                 ).await;
+                report_metrics(&metrics, &context).await;
+                publish_status(&status, &state, &metrics, &events).await;
             }
         }
     }
@@ -126,71 +199,571 @@ pub async fn run(
     Ok(())
 }
 
-/// Performs a single IP detection cycle for both IPv4 and IPv6 if needed
+/// Logs what the client was started with: the configuration file, the
+/// enabled providers with their record counts and the IP detection settings.
+/// Meant to make support reports self-explanatory.
+async fn log_startup_summary(config: &ConfigManager) {
+    let settings = config.settings.read().await;
+
+    let mut providers: Vec<(String, usize)> = Vec::new();
+    for cf in settings.cloudflare.iter().filter(|cf| cf.enabled) {
+        providers.push((format!("cloudflare:{}", cf.name), cf.subdomains.len()));
+    }
+    for arvan in settings.arvancloud.iter().filter(|arvan| arvan.enabled) {
+        providers.push((format!("arvancloud:{}", arvan.name), arvan.subdomains.len()));
+    }
+    for duckdns in settings.duckdns.iter().filter(|duckdns| duckdns.enabled) {
+        providers.push(("duckdns".to_string(), duckdns.domains.len()));
+    }
+    for route53 in settings.route53.iter().filter(|route53| route53.enabled) {
+        providers.push((
+            format!("route53:{}", route53.name),
+            route53.subdomains.len(),
+        ));
+    }
+    for porkbun in settings.porkbun.iter().filter(|porkbun| porkbun.enabled) {
+        providers.push((
+            format!("porkbun:{}", porkbun.domain_name()),
+            porkbun.subdomains.len(),
+        ));
+    }
+    #[cfg(feature = "mock-provider")]
+    for mock in settings.mock.iter().filter(|mock| mock.enabled) {
+        providers.push((format!("mock:{}", mock.name), 1));
+    }
+
+    info!("⚙️ Configuration file: {}", config.config_path.display());
+    info!(
+        "⚙️ {} provider(s) enabled: {}",
+        providers.len(),
+        providers
+            .iter()
+            .map(|(name, records)| format!("{} ({} records)", name, records))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    let ip_detection = &settings.ip_detection;
+    info!(
+        "⚙️ IP detection: method {:?}, min_consensus {}, max_requests_per_hour {}",
+        ip_detection.method, ip_detection.min_consensus, ip_detection.max_requests_per_hour
+    );
+}
+
+/// Builds the update context from the current settings.
+/// This creates the IP detector, every enabled provider and every notifier,
+/// and works out which IP versions need to be detected.
+async fn build_update_context(
+    config: &Arc<ConfigManager>,
+    metrics: &Arc<MetricsManager>,
+    events: &Arc<EventLog>,
+) -> Result<UpdateContext, Box<dyn Error>> {
+    let settings = config.settings.read().await;
+    let update_interval: u64 = settings.update.interval;
+    let jitter_secs: u64 = settings.update.jitter_secs;
+    let dry_run: bool = settings.update.dry_run;
+    let update_timeout_secs = settings.update.update_timeout_secs;
+    let limits = UpdateLimits {
+        max_concurrent: settings.update.max_concurrent_updates,
+        update_timeout: Duration::from_secs(update_timeout_secs),
+        provider_timeout: Duration::from_secs(
+            settings
+                .update
+                .provider_timeout_secs
+                .unwrap_or(update_timeout_secs),
+        ),
+        shutdown_grace: Duration::from_secs(settings.update.shutdown_grace_secs),
+    };
+    let metrics_json_path = settings.metrics.json_path.clone();
+    let startup_retries = settings.update.startup_retries;
+    let startup_retry_delay = Duration::from_secs(settings.update.startup_retry_delay_secs);
+    if jitter_secs > 0 {
+        info!(
+            "🕰️ Updating DNS records every {} seconds (+ up to {} seconds of jitter)",
+            update_interval, jitter_secs
+        );
+    } else {
+        info!("🕰️ Updating DNS records every {} seconds", update_interval);
+    }
+    if dry_run {
+        info!("🧪 Dry-run mode: DNS changes will be logged but not applied");
+    }
+    for (domain, configs) in settings.overlapping_domains() {
+        warn!(
+            "{} is managed by more than one provider configuration ({}), which usually means a misconfiguration",
+            domain,
+            configs.join(", ")
+        );
+    }
+
+    // Initialize IP detector with configuration
+    let ip_detector = IpDetector::new(
+        settings.ip_detection.clone(),
+        &settings.http_client_options(),
+    );
+
+    // Drop the settings lock, the providers and notifiers take their own
+    drop(settings);
+
+    // Fetch settings and create instances of every enabled provider
+    let providers: Vec<Box<dyn AnyDnsProvider>> =
+        get_providers(Arc::clone(config), Arc::clone(metrics)).await?;
+    let notifiers: Vec<Box<dyn Notifier>> = get_notifiers(Arc::clone(config)).await;
+
+    // Determine which IP versions we need to detect based on provider configurations
+    let need_ipv4 = providers
+        .iter()
+        .any(|provider| provider.is_enabled() && provider.needs_ip_version(IpVersion::V4));
+    let need_ipv6 = providers
+        .iter()
+        .any(|provider| provider.is_enabled() && provider.needs_ip_version(IpVersion::V6));
+
+    info!(
+        "IP detection configuration - IPv4: {}, IPv6: {}",
+        need_ipv4, need_ipv6
+    );
+
+    // Providers without their own interval follow the global one
+    let schedule = UpdateSchedule::new(
+        providers
+            .iter()
+            .map(|provider| match provider.update_interval() {
+                Some(interval) => {
+                    info!(
+                        provider = %provider.get_name(),
+                        "🕰️ Updating this provider every {} seconds",
+                        interval.as_secs()
+                    );
+                    interval
+                }
+                None => Duration::from_secs(update_interval),
+            })
+            .collect(),
+    );
+
+    Ok(UpdateContext {
+        schedule,
+        jitter_secs,
+        dry_run,
+        limits,
+        metrics_json_path,
+        ip_detector,
+        providers,
+        pushed_ips: PushedIps::default(),
+        notifiers,
+        events: Arc::clone(events),
+        need_ipv4,
+        need_ipv6,
+        startup_retries,
+        startup_retry_delay,
+        detection_failed: AtomicBool::new(false),
+        ipv6_unavailable_logged: AtomicBool::new(false),
+    })
+}
+
+/// Returns how long to wait before the next detection cycle.
+/// A random jitter in `[0, jitter_secs]` is added to the time until the
+/// next provider is due.
+fn next_update_delay(context: &UpdateContext) -> Duration {
+    let jitter_ms = fastrand::u64(0..=context.jitter_secs.saturating_mul(1000));
+    context.schedule.next_delay() + Duration::from_millis(jitter_ms)
+}
+
+/// Waits until the IP detection services can be reached, checking again
+/// with exponential backoff capped at `network_retry_interval`.
+/// Returns `false` if a shutdown was requested while waiting.
+async fn wait_for_network(
+    context: &UpdateContext,
+    shutdown_rx: &mut broadcast::Receiver<()>,
+) -> bool {
+    let max_delay = Duration::from_secs(context.ip_detector.config.network_retry_interval);
+    let mut delay = Duration::from_secs(NETWORK_RETRY_BASE_SECS).min(max_delay);
+    let mut was_down = false;
+    while !context
+        .ip_detector
+        .check_network(context.need_ipv4, context.need_ipv6)
+        .await
+    {
+        warn!(
+            "🌐 Network unreachable, checking again in {} seconds",
+            delay.as_secs()
+        );
+        was_down = true;
+        tokio::select! {
+            Ok(()) = shutdown_rx.recv() => {
+                info!("Received shutdown signal, stopping...");
+                return false;
+            }
+            _ = tokio::time::sleep(delay) => {}
+        }
+        delay = (delay * 2).min(max_delay);
+    }
+    if was_down {
+        info!("🌐 Network is reachable again");
+    }
+    true
+}
+
+/// Performs a single IP detection cycle for both IPv4 and IPv6 if needed.
+/// The two versions use independent services, so they are detected and
+/// pushed concurrently, each with its own shutdown receiver.
+/// Notifiers are told about every change once it has been pushed to the providers,
+/// and about every failed push.
+/// Records that don't depend on the IP are synced afterwards on every cycle.
+/// Only providers due by their interval are updated; they are rescheduled
+/// once the cycle is done.
+/// Returns `false` if any DNS update attempted during the cycle failed.
+/// The cycle runs in a `cycle` span carrying its id and the detected IPs.
+#[instrument(
+    name = "cycle",
+    skip_all,
+    fields(id = NEXT_CYCLE_ID.fetch_add(1, Ordering::Relaxed), ipv4 = field::Empty, ipv6 = field::Empty)
+)]
 async fn detect_and_update_ips(
-    ip_detector: &IpDetector,
-    cloudflares: &[Cloudflare],
-    need_ipv4: bool,
-    need_ipv6: bool,
-    previous_ipv4: &mut Option<Ipv4Addr>,
-    previous_ipv6: &mut Option<Ipv6Addr>,
+    context: &UpdateContext,
+    state: &mut IpState,
+    state_store: &StateStore,
     ipv4_shutdown: Option<broadcast::Receiver<()>>,
     ipv6_shutdown: Option<broadcast::Receiver<()>>,
-) {
+) -> bool {
     debug!("Starting IP detection cycle");
-    // Get the public IPv4 address with consensus if needed
-    if need_ipv4 {
-        debug!("Detecting IPv4 address");
-        match ip_detector.detect_ip(IpVersion::V4).await {
-            Ok(ip) => {
-                if let IpAddr::V4(ipv4) = ip {
-                    if Some(ipv4) != *previous_ipv4 {
-                        info!("Public 🧩 IPv4 detected with consensus: {}", ipv4);
-                        *previous_ipv4 = Some(ipv4);
-
-                        // Process updates with pre-created subscription
-                        if let Err(e) = process_updates(cloudflares, &ip, ipv4_shutdown).await {
-                            error!("Error updating IPv4 records: {}", e);
-                        }
-                    } else {
-                        debug!("🧩 IPv4 address unchanged");
-                    }
-                }
+    context.detection_failed.store(false, Ordering::Relaxed);
+    let due = context.schedule.due();
+    let ((ipv4, ipv4_succeeded), (ipv6, ipv6_succeeded)) = tokio::join!(
+        detect_and_update_version(
+            context,
+            IpVersion::V4,
+            state.ipv4.map(IpAddr::V4),
+            ipv4_shutdown,
+            &due
+        ),
+        detect_and_update_version(
+            context,
+            IpVersion::V6,
+            state.ipv6.map(IpAddr::V6),
+            ipv6_shutdown,
+            &due
+        ),
+    );
+
+    if let Some(IpAddr::V4(ipv4)) = ipv4 {
+        state.ipv4 = Some(ipv4);
+    }
+    if let Some(IpAddr::V6(ipv6)) = ipv6 {
+        state.ipv6 = Some(ipv6);
+    }
+    // A dry run applies nothing, so it must not record the IPs as pushed either
+    if (ipv4.is_some() || ipv6.is_some()) && !context.dry_run {
+        state_store.save(state);
+    }
+
+    let static_succeeded = sync_static_records(&context.providers, &due).await;
+    context.schedule.reschedule(&due);
+
+    ipv4_succeeded && ipv6_succeeded && static_succeeded
+}
+
+/// Detects the address of one IP version and pushes it to the providers if
+/// it differs from `known`.
+/// Only a successful update becomes the new known IP, so failed updates are
+/// retried on the next cycle.
+/// Only providers marked in `due` are updated. Providers that were not due
+/// when the IP last changed are caught up once they are, even if the IP is
+/// unchanged by then.
+/// Returns the newly pushed address, if any, and `false` if the update failed.
+async fn detect_and_update_version(
+    context: &UpdateContext,
+    version: IpVersion,
+    known: Option<IpAddr>,
+    mut shutdown_rx: Option<broadcast::Receiver<()>>,
+    due: &[bool],
+) -> (Option<IpAddr>, bool) {
+    let UpdateContext {
+        dry_run,
+        limits,
+        ip_detector,
+        providers,
+        pushed_ips,
+        notifiers,
+        events,
+        need_ipv4,
+        need_ipv6,
+        ..
+    } = context;
+    let (label, span_field, needed) = match version {
+        IpVersion::V4 => ("IPv4", "ipv4", *need_ipv4),
+        IpVersion::V6 => ("IPv6", "ipv6", *need_ipv6),
+    };
+
+    if !needed {
+        debug!("Skipping {} detection - not needed by any provider", label);
+        return (None, true);
+    }
+
+    // Whether the provider at `position` is due and manages this version
+    let due_for_version = |position: usize| {
+        let provider = &providers[position];
+        due[position] && provider.is_enabled() && provider.needs_ip_version(version)
+    };
+    if !(0..providers.len()).any(due_for_version) {
+        debug!("Skipping {} detection - no provider using it is due", label);
+        return (None, true);
+    }
+
+    // Get the public address with consensus
+    debug!("Detecting {} address", label);
+    let ip = match ip_detector.detect_ip(version).await {
+        Ok(ip) => ip,
+        Err(IpDetectionError::Ipv6Unavailable) => {
+            if !context
+                .ipv6_unavailable_logged
+                .swap(true, Ordering::Relaxed)
+            {
+                info!("🧩 IPv6 is not available on this host, skipping IPv6 records");
             }
-            Err(e) => {
-                // Log IPv4 errors as warnings since IPv4 is critical
-                warn!("🧩 IPv4 detection failed: {}", e);
+            return (None, true);
+        }
+        Err(e) => {
+            events.record(
+                EventKind::DetectionFailed,
+                format!("{} detection failed: {}", label, e),
+            );
+            // Only the primary version is critical, the other one is optional
+            if version == ip_detector.config.primary_version {
+                context.detection_failed.store(true, Ordering::Relaxed);
+                warn!("🧩 {} detection failed: {}", label, e);
+            } else {
+                debug!("🧩 {} detection failed: {}", label, e);
             }
+            return (None, true);
+        }
+    };
+    Span::current().record(span_field, field::display(ip));
+
+    // Providers that pushed nothing in this run still hold the known address
+    if let (Some(known), false) = (known, *dry_run) {
+        pushed_ips.assume(providers.len(), known);
+    }
+
+    let changed = Some(ip) != known;
+    if changed {
+        // A change must hold for `stable_cycles` cycles; the first push isn't delayed
+        if known.is_some() && !ip_detector.observe_candidate(version, ip).await {
+            return (None, true);
         }
+        info!("Public 🧩 {} detected with consensus: {}", label, ip);
     } else {
-        debug!("Skipping IPv4 detection - not needed by any subdomain");
-    }
-
-    // Get the public IPv6 address with consensus if needed
-    if need_ipv6 {
-        debug!("Detecting IPv6 address");
-        match ip_detector.detect_ip(IpVersion::V6).await {
-            Ok(ip) => {
-                if let IpAddr::V6(ipv6) = ip {
-                    if Some(ipv6) != *previous_ipv6 {
-                        info!("Public 🧩 IPv6 detected with consensus: {}", ipv6);
-                        *previous_ipv6 = Some(ipv6);
-
-                        // Process updates with pre-created subscription
-                        if let Err(e) = process_updates(cloudflares, &ip, ipv6_shutdown).await {
-                            error!("Error updating IPv6 records: {}", e);
-                        }
-                    } else {
-                        debug!("🧩 IPv6 address unchanged");
-                    }
-                }
-            }
-            Err(e) => {
-                // Log IPv6 errors as debug since IPv6 is optional
-                debug!("🧩 IPv6 detection failed: {}", e);
-            }
+        ip_detector.clear_candidate(version).await;
+        // Providers with a longer interval may have missed the last change
+        let behind = !dry_run
+            && (0..providers.len()).any(|position| {
+                due_for_version(position) && pushed_ips.get(position, &ip) != Some(ip)
+            });
+        if !behind {
+            debug!("🧩 {} address unchanged", label);
+            return (None, true);
+        }
+        info!(
+            "🧩 {} address unchanged, updating providers that are behind",
+            label
+        );
+    }
+
+    // Don't start pushing once a shutdown has been requested during detection
+    if let Some(Ok(())) = shutdown_rx.as_mut().map(|rx| rx.try_recv()) {
+        debug!("Shutdown requested, skipping {} record updates", label);
+        return (None, false);
+    }
+
+    let pushed_ips: Option<&PushedIps> = (!dry_run).then_some(pushed_ips);
+    let summary = process_updates(providers, &ip, shutdown_rx, *limits, pushed_ips, due).await;
+    for update in &summary.results {
+        match &update.result {
+            Ok(()) => events.record(
+                EventKind::UpdateSucceeded,
+                format!("{} updated to {}", update.provider, ip),
+            ),
+            Err(e) => events.record(
+                EventKind::UpdateFailed,
+                format!("{} failed to update to {}: {}", update.provider, ip, e),
+            ),
+        }
+    }
+    if summary.succeeded() && changed {
+        match known {
+            Some(known) => events.record(
+                EventKind::IpChanged,
+                format!("{} changed from {} to {}", label, known, ip),
+            ),
+            None => events.record(EventKind::IpChanged, format!("{} is {}", label, ip)),
         }
+        let event = IpChangeEvent::new(known, ip);
+        notify_ip_change(notifiers, &event).await;
+        (Some(ip), true)
+    } else if summary.succeeded() {
+        (None, true)
+    } else if summary.cancelled() {
+        warn!("{} record updates cancelled by shutdown", label);
+        (None, false)
     } else {
-        debug!("Skipping IPv6 detection - not needed by any subdomain");
+        let message = summary.failure_message();
+        error!(
+            "{} records failed to update for {} of {} providers: {}",
+            label,
+            summary.failures().count(),
+            summary.results.len(),
+            message
+        );
+        notify_update_failure(notifiers, &ip, &message).await;
+        (None, false)
+    }
+}
+
+/// Logs the accumulated DNS update metrics at debug level, and writes them
+/// to the configured JSON file if there is one.
+async fn report_metrics(metrics: &MetricsManager, context: &UpdateContext) {
+    if let Some(path) = &context.metrics_json_path {
+        metrics.write_json(path).await;
+    }
+
+    let snapshot = metrics.get_snapshot().await;
+    for (version, counters) in [("IPv4", &snapshot.ipv4), ("IPv6", &snapshot.ipv6)] {
+        let IpVersionMetrics {
+            successful_updates,
+            failed_updates,
+            already_up_to_date,
+            rate_limit_hits,
+            last_success,
+            last_failure,
+        } = counters;
+        debug!(
+            successful = successful_updates,
+            failed = failed_updates,
+            up_to_date = already_up_to_date,
+            rate_limited = rate_limit_hits,
+            last_success_secs_ago = ?last_success.map(|t| t.elapsed().as_secs()),
+            last_failure_secs_ago = ?last_failure.map(|t| t.elapsed().as_secs()),
+            "{} DNS update metrics",
+            version
+        );
+    }
+}
+
+#[cfg(all(test, feature = "mock-provider"))]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use super::*;
+    use crate::providers::cloudflare::types::IpVersion as RecordIpVersion;
+    use crate::providers::mock::types::{MockConfig, MockFailure, MockProvider};
+    use crate::settings::types::HttpClientOptions;
+    use crate::utility::ip_detector::types::IpDetection;
+
+    fn mock_provider() -> MockProvider {
+        MockProvider::new(MockConfig {
+            enabled: true,
+            interval: None,
+            name: "mock".to_string(),
+            ip_version: RecordIpVersion::V4,
+            fail_with: None,
+            delay_ms: 0,
+        })
+    }
+
+    /// Builds a context around `provider` whose IPv4 detection is pinned to `ipv4`.
+    fn pinned_context(provider: MockProvider, ipv4: Ipv4Addr) -> UpdateContext {
+        let ip_detection = IpDetection {
+            override_ipv4: Some(ipv4),
+            ..IpDetection::default()
+        };
+        let http_options = HttpClientOptions {
+            user_agent: "fariba-ddns-test".to_string(),
+            proxy: None,
+        };
+
+        UpdateContext {
+            schedule: UpdateSchedule::new(vec![Duration::from_secs(300)]),
+            jitter_secs: 0,
+            dry_run: false,
+            limits: UpdateLimits {
+                max_concurrent: 1,
+                update_timeout: Duration::from_secs(120),
+                provider_timeout: Duration::from_secs(120),
+                shutdown_grace: Duration::from_secs(1),
+            },
+            metrics_json_path: None,
+            ip_detector: IpDetector::new(ip_detection, &http_options),
+            providers: vec![Box::new(provider)],
+            pushed_ips: PushedIps::default(),
+            notifiers: Vec::new(),
+            events: Arc::new(EventLog::new(16)),
+            need_ipv4: true,
+            need_ipv6: false,
+            startup_retries: 0,
+            startup_retry_delay: Duration::ZERO,
+            detection_failed: AtomicBool::new(false),
+            ipv6_unavailable_logged: AtomicBool::new(false),
+        }
+    }
+
+    #[tokio::test]
+    async fn ip_change_triggers_exactly_one_update() {
+        let first = IpAddr::V4(Ipv4Addr::new(203, 0, 113, 1));
+        let second = IpAddr::V4(Ipv4Addr::new(203, 0, 113, 2));
+        let provider = mock_provider();
+
+        let context = pinned_context(provider.clone(), Ipv4Addr::new(203, 0, 113, 1));
+        let pushed = detect_and_update_version(&context, IpVersion::V4, None, None, &[true]).await;
+        assert_eq!(pushed, (Some(first), true));
+        let pushed =
+            detect_and_update_version(&context, IpVersion::V4, Some(first), None, &[true]).await;
+        assert_eq!(pushed, (None, true));
+        assert_eq!(provider.calls(), vec![first]);
+
+        let context = pinned_context(provider.clone(), Ipv4Addr::new(203, 0, 113, 2));
+        let pushed =
+            detect_and_update_version(&context, IpVersion::V4, Some(first), None, &[true]).await;
+        assert_eq!(pushed, (Some(second), true));
+        let pushed =
+            detect_and_update_version(&context, IpVersion::V4, Some(second), None, &[true]).await;
+        assert_eq!(pushed, (None, true));
+        assert_eq!(provider.calls(), vec![first, second]);
+    }
+
+    #[tokio::test]
+    async fn failed_update_is_retried_next_cycle() {
+        let ip = IpAddr::V4(Ipv4Addr::new(203, 0, 113, 1));
+        let provider = mock_provider().with_failure(MockFailure::RateLimited);
+        let context = pinned_context(provider.clone(), Ipv4Addr::new(203, 0, 113, 1));
+
+        for _ in 0..2 {
+            let pushed =
+                detect_and_update_version(&context, IpVersion::V4, None, None, &[true]).await;
+            assert_eq!(pushed, (None, false));
+        }
+        assert_eq!(provider.calls(), vec![ip, ip]);
+        assert_eq!(context.pushed_ips.get(0, &ip), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn shutdown_aborts_in_flight_updates() {
+        let ip = IpAddr::V4(Ipv4Addr::new(203, 0, 113, 1));
+        let provider = mock_provider().with_delay(Duration::from_secs(60));
+        let context = pinned_context(provider.clone(), Ipv4Addr::new(203, 0, 113, 1));
+        let (shutdown_tx, shutdown_rx) = broadcast::channel(1);
+        let started = tokio::time::Instant::now();
+
+        let update =
+            detect_and_update_version(&context, IpVersion::V4, None, Some(shutdown_rx), &[true]);
+        let shutdown = async {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            shutdown_tx.send(()).unwrap();
+        };
+        let (pushed, ()) = tokio::join!(update, shutdown);
+
+        assert_eq!(pushed, (None, false));
+        // The update started, but was cut off after the grace period
+        assert_eq!(provider.calls(), vec![ip]);
+        assert!(started.elapsed() < Duration::from_secs(60));
+        assert_eq!(context.pushed_ips.get(0, &ip), None);
     }
 }
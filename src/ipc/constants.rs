@@ -0,0 +1,6 @@
+/// Recent events kept for the status socket unless `[ipc] event_log_size` overrides it
+pub const DEFAULT_EVENT_LOG_SIZE: usize = 100;
+
+pub fn default_event_log_size() -> usize {
+    DEFAULT_EVENT_LOG_SIZE
+}
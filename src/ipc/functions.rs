@@ -0,0 +1,114 @@
+// Standard library
+#[cfg(unix)]
+use std::os::unix::fs::FileTypeExt;
+#[cfg(unix)]
+use std::path::PathBuf;
+#[cfg(unix)]
+use std::sync::Arc;
+
+// 3rd party crates
+#[cfg(unix)]
+use serde_json::{json, Value};
+#[cfg(unix)]
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+#[cfg(unix)]
+use tokio::net::{UnixListener, UnixStream};
+#[cfg(unix)]
+use tokio::sync::broadcast;
+use tokio::time::Instant;
+#[cfg(unix)]
+use tracing::{debug, error, info, warn};
+
+// Project imports
+use crate::metrics::types::MetricsManager;
+use crate::utility::ip_state::types::IpState;
+
+// Current module imports
+use super::types::{EventLog, SharedStatus, StatusSnapshot};
+
+/// Refreshes the shared status after a detection cycle.
+pub async fn publish_status(
+    status: &SharedStatus,
+    state: &IpState,
+    metrics: &MetricsManager,
+    events: &EventLog,
+) {
+    let metrics = metrics.get_snapshot().await;
+    *status.write().await = StatusSnapshot {
+        ipv4: state.ipv4,
+        ipv6: state.ipv6,
+        last_cycle: Some(Instant::now()),
+        metrics,
+        events: events.snapshot(),
+    };
+}
+
+/// Answers one line command with a JSON value.
+/// Commands are `get-ip v4`, `get-ip v6` and `get-status`.
+#[cfg(unix)]
+fn respond(command: &str, status: &StatusSnapshot) -> Value {
+    let mut words = command.split_whitespace();
+    match (words.next(), words.next(), words.next()) {
+        (Some("get-ip"), Some("v4"), None) => json!({ "ip": status.ipv4 }),
+        (Some("get-ip"), Some("v6"), None) => json!({ "ip": status.ipv6 }),
+        (Some("get-status"), None, None) => {
+            serde_json::to_value(status).unwrap_or_else(|e| json!({ "error": e.to_string() }))
+        }
+        _ => json!({ "error": format!("unknown command '{}'", command) }),
+    }
+}
+
+/// Serves status queries on a Unix domain socket until the shutdown signal.
+/// A socket file left behind by an unclean exit is replaced, and the socket
+/// is removed again on shutdown.
+#[cfg(unix)]
+pub async fn serve_status_socket(
+    path: PathBuf,
+    status: SharedStatus,
+    mut shutdown_rx: broadcast::Receiver<()>,
+) {
+    if std::fs::symlink_metadata(&path).is_ok_and(|metadata| metadata.file_type().is_socket()) {
+        if let Err(e) = std::fs::remove_file(&path) {
+            warn!("Failed to remove stale status socket {:?}: {}", path, e);
+        }
+    }
+
+    let listener = match UnixListener::bind(&path) {
+        Ok(listener) => listener,
+        Err(e) => {
+            error!("Failed to bind status socket {:?}: {}", path, e);
+            return;
+        }
+    };
+    info!("📡 Serving status on {:?}", path);
+
+    loop {
+        tokio::select! {
+            _ = shutdown_rx.recv() => break,
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    tokio::spawn(handle_client(stream, Arc::clone(&status)));
+                }
+                Err(e) => warn!("Failed to accept status connection: {}", e),
+            },
+        }
+    }
+
+    if let Err(e) = std::fs::remove_file(&path) {
+        warn!("Failed to remove status socket {:?}: {}", path, e);
+    }
+}
+
+/// Answers every line a client sends until it disconnects.
+#[cfg(unix)]
+async fn handle_client(stream: UnixStream, status: SharedStatus) {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        let response = respond(line.trim(), &*status.read().await);
+        if let Err(e) = writer.write_all(format!("{}\n", response).as_bytes()).await {
+            debug!("Status client went away: {}", e);
+            break;
+        }
+    }
+}
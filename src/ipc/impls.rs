@@ -0,0 +1,56 @@
+// Standard library
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Current module imports
+use super::constants::DEFAULT_EVENT_LOG_SIZE;
+use super::types::{Event, EventKind, EventLog, Ipc};
+
+impl Default for Ipc {
+    fn default() -> Self {
+        Self {
+            socket: None,
+            event_log_size: DEFAULT_EVENT_LOG_SIZE,
+        }
+    }
+}
+
+impl EventLog {
+    /// Creates an empty log that keeps at most `capacity` events.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            events: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Appends an event, dropping the oldest one once the log is full.
+    pub fn record(&self, kind: EventKind, message: impl Into<String>) {
+        if self.capacity == 0 {
+            return;
+        }
+        let timestamp_unix_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis() as u64)
+            .unwrap_or_default();
+        if let Ok(mut events) = self.events.lock() {
+            if events.len() == self.capacity {
+                events.pop_front();
+            }
+            events.push_back(Event {
+                timestamp_unix_ms,
+                kind,
+                message: message.into(),
+            });
+        }
+    }
+
+    /// Returns the recorded events, oldest first.
+    pub fn snapshot(&self) -> Vec<Event> {
+        self.events
+            .lock()
+            .map(|events| events.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,4 @@
+pub mod constants;
+pub mod functions;
+pub mod impls;
+pub mod types;
@@ -0,0 +1,82 @@
+// Standard library
+use std::collections::VecDeque;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+// 3rd party crates
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use tokio::time::Instant;
+
+// Project imports
+use crate::metrics::functions::serialize_instant_as_unix_ms;
+use crate::metrics::types::DnsMetrics;
+
+// Current module imports
+use super::constants::default_event_log_size;
+
+/// Local status interface configuration
+#[derive(Debug, Deserialize, Clone)]
+pub struct Ipc {
+    /// Unix domain socket answering status queries; read at startup only
+    #[serde(default)]
+    pub socket: Option<PathBuf>,
+    /// Recent events reported by `get-status`, 0 to keep none; read at startup only
+    #[serde(default = "default_event_log_size")]
+    pub event_log_size: usize,
+}
+
+/// What the status socket reports, refreshed after every detection cycle.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct StatusSnapshot {
+    /// Last IPv4 address pushed to the providers
+    pub ipv4: Option<Ipv4Addr>,
+    /// Last IPv6 address pushed to the providers
+    pub ipv6: Option<Ipv6Addr>,
+    /// When the last detection cycle finished
+    #[serde(
+        rename = "last_cycle_unix_ms",
+        serialize_with = "serialize_instant_as_unix_ms"
+    )]
+    pub last_cycle: Option<Instant>,
+    /// DNS update counters
+    pub metrics: DnsMetrics,
+    /// Recent events, oldest first
+    pub events: Vec<Event>,
+}
+
+/// What happened in a recorded event
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventKind {
+    /// A new address was pushed to every provider
+    IpChanged,
+    /// The public address could not be detected
+    DetectionFailed,
+    /// A provider took the new address
+    UpdateSucceeded,
+    /// A provider failed to take the new address
+    UpdateFailed,
+}
+
+/// One entry of the event log
+#[derive(Debug, Clone, Serialize)]
+pub struct Event {
+    /// When the event happened, in milliseconds since the Unix epoch
+    pub timestamp_unix_ms: u64,
+    pub kind: EventKind,
+    /// Human-readable details, e.g. the addresses or the error
+    pub message: String,
+}
+
+/// The most recent events, kept in memory for troubleshooting.
+/// Once `capacity` events are held, the oldest is dropped for each new one.
+#[derive(Debug)]
+pub struct EventLog {
+    pub capacity: usize,
+    pub events: Mutex<VecDeque<Event>>,
+}
+
+/// Status shared between the main loop and the socket server.
+pub type SharedStatus = Arc<RwLock<StatusSnapshot>>;
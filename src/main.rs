@@ -1,5 +1,5 @@
 //! Fariba DDNS Client
-//! 
+//!
 //! A flexible Dynamic DNS client that supports multiple DNS providers.
 //! This client automatically updates DNS records when your IP address changes,
 //! making it ideal for homelab and self-hosted services.
@@ -16,7 +16,7 @@
 //! ```no_run
 //! # async fn run() {
 //! use fariba_ddns::ConfigManager;
-//! 
+//!
 //! let config = ConfigManager::from_file(".settings.toml").await?;
 //! fariba_ddns::run(config).await?;
 //! # }
@@ -26,20 +26,34 @@
 use std::sync::Arc;
 
 // 3rd party crates
+use clap::Parser;
 use tokio::signal::ctrl_c;
+#[cfg(unix)]
+use tokio::signal::unix::{signal, SignalKind};
 use tokio::sync::broadcast;
 use tracing::{error, info};
 use tracing_subscriber::{filter::LevelFilter, EnvFilter};
 
 // Project modules
+mod cli;
 mod functions;
+mod ipc;
+mod metrics;
+mod notifications;
 mod providers;
 mod settings;
+mod types;
 mod utility;
 
 // Project imports
+use crate::cli::functions::{
+    cleanup, config_path, export_config, reconcile, status, test_notification, validate,
+};
+use crate::cli::types::{Cli, Command};
 use crate::functions::run;
-use crate::settings::types::ConfigManager;
+use crate::ipc::types::SharedStatus;
+use crate::settings::errors::ConfigSetupError;
+use crate::settings::types::{ConfigManager, LogFormat};
 
 /// Main entry point for the DDNS client.
 /// This application monitors public IP addresses and updates DNS records
@@ -55,18 +69,51 @@ use crate::settings::types::ConfigManager;
 /// - Detailed logging
 #[tokio::main]
 async fn main() {
+    let cli: Cli = Cli::parse();
+
     // loads the .env file from the current directory or parents.
     dotenvy::dotenv_override().ok();
 
+    match cli.command {
+        Some(Command::Validate) => std::process::exit(validate(cli.config).await),
+        Some(Command::Status) => std::process::exit(status(cli.config).await),
+        Some(Command::Reconcile) => std::process::exit(reconcile(cli.config).await),
+        Some(Command::TestNotification) => std::process::exit(test_notification(cli.config).await),
+        Some(Command::ConfigPath) => std::process::exit(config_path(cli.config)),
+        Some(Command::ExportConfig { format }) => std::process::exit(export_config(format)),
+        Some(Command::Cleanup { confirm }) => {
+            std::process::exit(cleanup(cli.config, confirm).await)
+        }
+        None => {}
+    }
+
     // Create ConfigManager and wrap it in Arc
-    let config: Arc<ConfigManager> = Arc::new(
-        ConfigManager::new()
-            .await
-            .expect("Failed to initialize configuration"),
-    );
+    let mut config_manager: ConfigManager = match ConfigManager::new(cli.config).await {
+        Ok(config_manager) => config_manager,
+        // A fresh default configuration has nothing to update yet
+        Err(e) if e.downcast_ref::<ConfigSetupError>().is_some() => {
+            println!("{}", e);
+            std::process::exit(0);
+        }
+        Err(e) => {
+            eprintln!("Failed to initialize configuration: {}", e);
+            std::process::exit(1);
+        }
+    };
+    if cli.dry_run {
+        config_manager.force_dry_run().await;
+    }
+    if cli.respect_foreign_records {
+        config_manager.force_respect_foreign_records().await;
+    }
+    let config: Arc<ConfigManager> = Arc::new(config_manager);
 
-    // setup logging.
-    let log_level: String = config.get_log_level().await;
+    // setup logging, -v and -vv override the configured level.
+    let log_level: String = match cli.verbose {
+        0 => config.get_log_level().await,
+        1 => "debug".to_string(),
+        _ => "trace".to_string(),
+    };
 
     let filter: EnvFilter = EnvFilter::builder()
         .with_default_directive(LevelFilter::ERROR.into())
@@ -74,13 +121,18 @@ async fn main() {
         .add_directive("hyper_util=error".parse().unwrap())
         .add_directive("reqwest=error".parse().unwrap())
         .add_directive("trust_dns_proto=error".parse().unwrap())
+        .add_directive("hickory_proto=error".parse().unwrap())
+        .add_directive("hickory_resolver=error".parse().unwrap())
         .add_directive("hyper_system_resolver=error".parse().unwrap())
         .add_directive("hyper=error".parse().unwrap());
 
-    tracing_subscriber::fmt()
+    let subscriber = tracing_subscriber::fmt()
         .with_env_filter(filter)
-        .with_level(true)
-        .init();
+        .with_level(true);
+    match config.get_log_format().await {
+        LogFormat::Text => subscriber.init(),
+        LogFormat::Json => subscriber.json().init(),
+    }
 
     info!("⚙️ Settings have been loaded.");
 
@@ -98,9 +150,59 @@ async fn main() {
         let _ = shutdown_tx_clone.send(());
     });
 
+    // Reload the configuration on SIGHUP; the main loop picks up the new settings
+    #[cfg(unix)]
+    {
+        let config = Arc::clone(&config);
+        tokio::spawn(async move {
+            let mut hangup = match signal(SignalKind::hangup()) {
+                Ok(hangup) => hangup,
+                Err(e) => {
+                    error!("Failed to listen for SIGHUP: {}", e);
+                    return;
+                }
+            };
+            while hangup.recv().await.is_some() {
+                info!("Received SIGHUP, reloading configuration...");
+                if let Err(e) = config.reload().await {
+                    error!(
+                        "Failed to reload configuration, keeping the current one: {}",
+                        e
+                    );
+                }
+            }
+        });
+    }
+
+    // Reload the configuration when its file changes
+    if !cli.once {
+        tokio::spawn(settings::functions::watch_config_file(
+            Arc::clone(&config),
+            shutdown_tx.subscribe(),
+        ));
+    }
+
+    // Serve the status socket, if configured, until shutdown
+    let status = SharedStatus::default();
+    let socket = config.settings.read().await.ipc.socket.clone();
+    if let (Some(path), false) = (socket, cli.once) {
+        #[cfg(unix)]
+        tokio::spawn(ipc::functions::serve_status_socket(
+            path,
+            Arc::clone(&status),
+            shutdown_tx.subscribe(),
+        ));
+        #[cfg(not(unix))]
+        tracing::warn!(
+            "Status socket {:?} ignored, Unix sockets are not supported on this platform",
+            path
+        );
+    }
+
     // Run the main application logic with shutdown signal
-    if let Err(e) = run(config, shutdown_tx.subscribe()).await {
+    if let Err(e) = run(config, shutdown_tx.subscribe(), cli.once, status).await {
         error!("Application error: {}", e);
+        std::process::exit(1);
     }
 
     info!("Shutdown complete.");
@@ -0,0 +1,26 @@
+// Standard library
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// 3rd party crates
+use serde::Serializer;
+use tokio::time::Instant;
+
+/// Serializes an `Instant` as milliseconds since the Unix epoch.
+/// `Instant` has no fixed origin, so the wall-clock time is derived from how
+/// long ago it was.
+pub fn serialize_instant_as_unix_ms<S>(
+    instant: &Option<Instant>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let unix_ms = instant.and_then(|instant| {
+        let at = SystemTime::now().checked_sub(instant.elapsed())?;
+        Some(at.duration_since(UNIX_EPOCH).ok()?.as_millis() as u64)
+    });
+    match unix_ms {
+        Some(unix_ms) => serializer.serialize_u64(unix_ms),
+        None => serializer.serialize_none(),
+    }
+}
@@ -0,0 +1,85 @@
+// Standard library
+use std::fs;
+use std::net::IpAddr;
+use std::path::Path;
+
+// 3rd party crates
+use tokio::time::Instant;
+use tracing::{debug, warn};
+
+// Current module imports
+use super::types::{DnsMetrics, IpVersionMetrics, MetricsManager};
+
+impl DnsMetrics {
+    /// Returns the counters for the version of the given address.
+    fn for_ip(&mut self, ip: &IpAddr) -> &mut IpVersionMetrics {
+        match ip {
+            IpAddr::V4(_) => &mut self.ipv4,
+            IpAddr::V6(_) => &mut self.ipv6,
+        }
+    }
+}
+
+impl MetricsManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a record that was created or updated successfully.
+    pub async fn record_success(&self, ip: &IpAddr) {
+        let mut metrics = self.metrics.write().await;
+        let version = metrics.for_ip(ip);
+        version.successful_updates += 1;
+        version.last_success = Some(Instant::now());
+    }
+
+    /// Records a record that could not be updated.
+    pub async fn record_failure(&self, ip: &IpAddr) {
+        let mut metrics = self.metrics.write().await;
+        let version = metrics.for_ip(ip);
+        version.failed_updates += 1;
+        version.last_failure = Some(Instant::now());
+    }
+
+    /// Records a record that already pointed at the current IP.
+    pub async fn record_already_up_to_date(&self, ip: &IpAddr) {
+        self.metrics.write().await.for_ip(ip).already_up_to_date += 1;
+    }
+
+    /// Records an API call rejected by the rate limiter.
+    pub async fn record_rate_limit(&self, ip: &IpAddr) {
+        self.metrics.write().await.for_ip(ip).rate_limit_hits += 1;
+    }
+
+    /// Returns a copy of the current metrics.
+    pub async fn get_snapshot(&self) -> DnsMetrics {
+        self.metrics.read().await.clone()
+    }
+
+    /// Writes the current metrics as JSON to `path`.
+    /// The file is replaced atomically, so readers never see a partial write.
+    /// Failures are logged and otherwise ignored.
+    pub async fn write_json(&self, path: &Path) {
+        let content = match serde_json::to_string_pretty(&self.get_snapshot().await) {
+            Ok(content) => content,
+            Err(e) => {
+                warn!("Failed to serialize metrics: {}", e);
+                return;
+            }
+        };
+
+        if let Some(parent_dir) = path.parent() {
+            if let Err(e) = fs::create_dir_all(parent_dir) {
+                warn!("Failed to create metrics directory {:?}: {}", parent_dir, e);
+                return;
+            }
+        }
+
+        let mut temp_path = path.as_os_str().to_owned();
+        temp_path.push(".tmp");
+        match fs::write(&temp_path, content).and_then(|()| fs::rename(&temp_path, path)) {
+            Ok(()) => debug!("Wrote metrics to {:?}", path),
+            Err(e) => warn!("Failed to write metrics to {:?}: {}", path, e),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod functions;
+pub mod impls;
+pub mod types;
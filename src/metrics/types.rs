@@ -0,0 +1,46 @@
+// 3rd party crates
+use serde::Serialize;
+use tokio::sync::RwLock;
+use tokio::time::Instant;
+
+// Current module imports
+use super::functions::serialize_instant_as_unix_ms;
+
+/// Counters for DNS updates of a single IP version.
+/// Timestamps are serialized as milliseconds since the Unix epoch.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct IpVersionMetrics {
+    /// Records created or updated successfully
+    pub successful_updates: u64,
+    /// Records that failed to update after all retries
+    pub failed_updates: u64,
+    /// Records that already pointed at the current IP
+    pub already_up_to_date: u64,
+    /// API calls rejected by the local rate limiter
+    pub rate_limit_hits: u64,
+    /// When a record was last created or updated successfully
+    #[serde(
+        rename = "last_success_unix_ms",
+        serialize_with = "serialize_instant_as_unix_ms"
+    )]
+    pub last_success: Option<Instant>,
+    /// When a record last failed to update
+    #[serde(
+        rename = "last_failure_unix_ms",
+        serialize_with = "serialize_instant_as_unix_ms"
+    )]
+    pub last_failure: Option<Instant>,
+}
+
+/// Snapshot of all DNS update metrics.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct DnsMetrics {
+    pub ipv4: IpVersionMetrics,
+    pub ipv6: IpVersionMetrics,
+}
+
+/// Collects DNS update metrics shared between all providers.
+#[derive(Debug, Default)]
+pub struct MetricsManager {
+    pub metrics: RwLock<DnsMetrics>,
+}
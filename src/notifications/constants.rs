@@ -0,0 +1,5 @@
+/// Timeout for a single notification request, so a hung endpoint can't stall the update loop
+pub const NOTIFICATION_TIMEOUT_SECS: u64 = 10;
+
+/// Text of the notification sent by the `test-notification` command
+pub const TEST_NOTIFICATION_MESSAGE: &str = "This is a test notification from fariba-ddns";
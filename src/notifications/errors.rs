@@ -0,0 +1,27 @@
+// 3rd party crates
+use thiserror::Error;
+
+/// Represents errors that can occur while sending a notification
+#[derive(Debug, Error)]
+pub enum NotificationError {
+    #[error("HTTP client error: {0}")]
+    HttpClientBuild(#[source] reqwest::Error),
+
+    #[error("Failed to send notification: {0}")]
+    SendFailed(#[source] reqwest::Error),
+
+    #[error("Notification endpoint returned HTTP {0}")]
+    UnexpectedStatus(reqwest::StatusCode),
+}
+
+#[derive(Debug, Error)]
+pub enum NotificationValidationError {
+    #[error("Missing or empty webhook URL")]
+    MissingWebhookUrl,
+    #[error("Invalid webhook URL '{0}': must start with http:// or https://")]
+    InvalidWebhookUrl(String),
+    #[error("Missing or empty Telegram bot token")]
+    MissingTelegramBotToken,
+    #[error("Missing or empty Telegram chat ID")]
+    MissingTelegramChatId,
+}
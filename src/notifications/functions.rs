@@ -0,0 +1,73 @@
+// Standard library
+use std::net::IpAddr;
+use std::sync::Arc;
+
+// 3rd party crates
+use tokio::sync::RwLockReadGuard;
+use tracing::{debug, error, warn};
+
+// Project modules
+use crate::settings::types::{ConfigManager, Settings};
+
+// Current module imports
+use super::telegram::types::Telegram;
+use super::traits::Notifier;
+use super::types::IpChangeEvent;
+use super::webhook::types::Webhook;
+
+/// Gets every configured notification backend.
+/// A backend that fails to initialize is logged and left out, since
+/// notifications must never keep DNS updates from running.
+pub async fn get_notifiers(config: Arc<ConfigManager>) -> Vec<Box<dyn Notifier>> {
+    let settings: RwLockReadGuard<Settings> = config.settings.read().await;
+    let http_options = settings.http_client_options();
+
+    let mut notifiers: Vec<Box<dyn Notifier>> = Vec::new();
+    if let Some(webhook_config) = &settings.notifications.webhook {
+        match Webhook::new(webhook_config.clone(), &http_options) {
+            Ok(webhook) => notifiers.push(Box::new(webhook)),
+            Err(e) => error!("Failed to create webhook notifier: {}", e),
+        }
+    }
+
+    if let Some(telegram_config) = &settings.notifications.telegram {
+        match Telegram::new(telegram_config.clone(), &http_options) {
+            Ok(telegram) => notifiers.push(Box::new(telegram)),
+            Err(e) => error!("Failed to create Telegram notifier: {}", e),
+        }
+    }
+
+    notifiers
+}
+
+/// Sends an IP change event to every notifier.
+/// Failures are logged as warnings and otherwise ignored.
+pub async fn notify_ip_change(notifiers: &[Box<dyn Notifier>], event: &IpChangeEvent) {
+    for notifier in notifiers {
+        match notifier.notify_ip_change(event).await {
+            Ok(()) => debug!(
+                notifier = %notifier.get_name(),
+                "Sent IP change notification"
+            ),
+            Err(e) => warn!(
+                notifier = %notifier.get_name(),
+                "Failed to send IP change notification: {}",
+                e
+            ),
+        }
+    }
+}
+
+/// Tells every notifier that pushing `ip` to the DNS providers failed.
+/// Failures to notify are logged as warnings and otherwise ignored.
+pub async fn notify_update_failure(notifiers: &[Box<dyn Notifier>], ip: &IpAddr, error: &str) {
+    for notifier in notifiers {
+        if let Err(e) = notifier.notify_update_failure(ip, error).await {
+            warn!(
+                notifier = %notifier.get_name(),
+                "Failed to send update failure notification: {}",
+                e
+            );
+        }
+    }
+}
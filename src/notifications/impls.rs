@@ -0,0 +1,63 @@
+// Standard library
+use std::net::IpAddr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Current module imports
+use super::constants::TEST_NOTIFICATION_MESSAGE;
+use super::errors::NotificationValidationError;
+use super::types::{IpChangeEvent, Notifications, TestNotification};
+
+impl Notifications {
+    pub fn validate(&self) -> Result<(), NotificationValidationError> {
+        if let Some(webhook) = &self.webhook {
+            webhook.validate()?;
+        }
+
+        if let Some(telegram) = &self.telegram {
+            telegram.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl IpChangeEvent {
+    /// Creates an event for a change from `old` to `new`, stamped with the current time.
+    pub fn new(old: Option<IpAddr>, new: IpAddr) -> Self {
+        let version = match new {
+            IpAddr::V4(_) => "v4",
+            IpAddr::V6(_) => "v6",
+        };
+        Self {
+            version,
+            old,
+            new,
+            timestamp: unix_timestamp(),
+        }
+    }
+}
+
+impl TestNotification {
+    /// Creates a test notification stamped with the current time.
+    pub fn new() -> Self {
+        Self {
+            test: true,
+            message: TEST_NOTIFICATION_MESSAGE,
+            timestamp: unix_timestamp(),
+        }
+    }
+}
+
+impl Default for TestNotification {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Current Unix time in seconds.
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
@@ -0,0 +1,8 @@
+pub mod constants;
+pub mod errors;
+pub mod functions;
+pub mod impls;
+pub mod telegram;
+pub mod traits;
+pub mod types;
+pub mod webhook;
@@ -0,0 +1,2 @@
+/// Base URL of the Telegram Bot API
+pub const TELEGRAM_API_BASE: &str = "https://api.telegram.org";
@@ -0,0 +1,125 @@
+// Standard library
+use std::fmt;
+use std::net::IpAddr;
+use std::time::Duration;
+
+// 3rd party crates
+use async_trait::async_trait;
+use reqwest::Client;
+
+// Project modules
+use crate::notifications::constants::NOTIFICATION_TIMEOUT_SECS;
+use crate::notifications::errors::{NotificationError, NotificationValidationError};
+use crate::notifications::traits::Notifier;
+use crate::notifications::types::{IpChangeEvent, TestNotification};
+use crate::settings::types::HttpClientOptions;
+
+// Current module imports
+use super::constants::TELEGRAM_API_BASE;
+use super::types::{Telegram, TelegramConfig};
+
+// Manual Debug implementations so the bot token never ends up in logs
+impl fmt::Debug for Telegram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Telegram")
+            .field("config", &self.config)
+            .field("client", &self.client)
+            .finish()
+    }
+}
+
+impl fmt::Debug for TelegramConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TelegramConfig")
+            .field("bot_token", &"<redacted>")
+            .field("chat_id", &self.chat_id)
+            .finish()
+    }
+}
+
+impl Telegram {
+    /// Creates a new Telegram notifier with the provided configuration.
+    pub fn new(
+        config: TelegramConfig,
+        http_options: &HttpClientOptions,
+    ) -> Result<Self, NotificationError> {
+        let client = http_options
+            .apply(Client::builder())
+            .timeout(Duration::from_secs(NOTIFICATION_TIMEOUT_SECS))
+            .build()
+            .map_err(NotificationError::HttpClientBuild)?;
+
+        Ok(Self { config, client })
+    }
+
+    /// Sends a plain text message to the configured chat.
+    async fn send_message(&self, text: &str) -> Result<(), NotificationError> {
+        let url = format!(
+            "{}/bot{}/sendMessage",
+            TELEGRAM_API_BASE,
+            self.config.bot_token.trim()
+        );
+
+        let response = self
+            .client
+            .post(&url)
+            .form(&[("chat_id", self.config.chat_id.trim()), ("text", text)])
+            .send()
+            .await
+            // The URL carries the bot token, so keep it out of the error
+            .map_err(|e| NotificationError::SendFailed(e.without_url()))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(NotificationError::UnexpectedStatus(status));
+        }
+
+        Ok(())
+    }
+}
+
+impl TelegramConfig {
+    pub fn validate(&self) -> Result<(), NotificationValidationError> {
+        if self.bot_token.trim().is_empty() {
+            return Err(NotificationValidationError::MissingTelegramBotToken);
+        }
+
+        if self.chat_id.trim().is_empty() {
+            return Err(NotificationValidationError::MissingTelegramChatId);
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Notifier for Telegram {
+    async fn notify_ip_change(&self, event: &IpChangeEvent) -> Result<(), NotificationError> {
+        let text = match event.old {
+            Some(old) => format!(
+                "🌐 Public IP{} changed from {} to {}",
+                event.version, old, event.new
+            ),
+            None => format!("🌐 Public IP{} is now {}", event.version, event.new),
+        };
+        self.send_message(&text).await
+    }
+
+    async fn notify_update_failure(
+        &self,
+        ip: &IpAddr,
+        error: &str,
+    ) -> Result<(), NotificationError> {
+        let text = format!("⚠️ Failed to update DNS records to {}: {}", ip, error);
+        self.send_message(&text).await
+    }
+
+    async fn notify_test(&self, notification: &TestNotification) -> Result<(), NotificationError> {
+        self.send_message(&format!("🧪 {}", notification.message))
+            .await
+    }
+
+    fn get_name(&self) -> &str {
+        "telegram"
+    }
+}
@@ -0,0 +1,3 @@
+pub mod constants;
+pub mod impls;
+pub mod types;
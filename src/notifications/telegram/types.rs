@@ -0,0 +1,19 @@
+// 3rd party crates
+use reqwest::Client;
+use serde::Deserialize;
+
+/// Sends IP changes and update failures as Telegram messages through a bot.
+#[derive(Clone)]
+pub struct Telegram {
+    pub config: TelegramConfig,
+    pub client: Client,
+}
+
+/// Configuration for the Telegram notifier.
+#[derive(Deserialize, Clone)]
+pub struct TelegramConfig {
+    /// The bot token issued by @BotFather
+    pub bot_token: String,
+    /// The chat, group or channel to send messages to
+    pub chat_id: String,
+}
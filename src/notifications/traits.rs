@@ -0,0 +1,33 @@
+// Standard library
+use std::net::IpAddr;
+
+// 3rd party crates
+use async_trait::async_trait;
+
+// Current module imports
+use super::errors::NotificationError;
+use super::types::{IpChangeEvent, TestNotification};
+
+/// A backend that tells the user about public IP changes.
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    /// Sends a notification for a single IP change.
+    async fn notify_ip_change(&self, event: &IpChangeEvent) -> Result<(), NotificationError>;
+
+    /// Sends a notification that pushing `ip` to the DNS providers failed.
+    /// Backends that only report IP changes can rely on this no-op default.
+    async fn notify_update_failure(
+        &self,
+        _ip: &IpAddr,
+        _error: &str,
+    ) -> Result<(), NotificationError> {
+        Ok(())
+    }
+
+    /// Sends a synthetic notification, to check the backend is reachable
+    /// and its credentials are accepted.
+    async fn notify_test(&self, notification: &TestNotification) -> Result<(), NotificationError>;
+
+    /// Gets the name of this notification backend.
+    fn get_name(&self) -> &str;
+}
@@ -0,0 +1,42 @@
+// Standard library
+use std::net::IpAddr;
+
+// 3rd party crates
+use serde::{Deserialize, Serialize};
+
+// Current module imports
+use super::telegram::types::TelegramConfig;
+use super::webhook::types::WebhookConfig;
+
+/// Configuration for all notification backends.
+/// Every backend is optional; none are configured by default.
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct Notifications {
+    /// POSTs a JSON body to a URL on every IP change
+    pub webhook: Option<WebhookConfig>,
+    /// Sends a Telegram message on IP changes and update failures
+    pub telegram: Option<TelegramConfig>,
+}
+
+/// A public IP change that has been pushed to the DNS providers.
+#[derive(Debug, Serialize, Clone)]
+pub struct IpChangeEvent {
+    /// "v4" or "v6"
+    pub version: &'static str,
+    /// The previous address, if one was known
+    pub old: Option<IpAddr>,
+    /// The new address
+    pub new: IpAddr,
+    /// Unix timestamp (seconds) of the change
+    pub timestamp: u64,
+}
+
+/// A synthetic notification that checks a backend is wired up correctly.
+#[derive(Debug, Serialize, Clone)]
+pub struct TestNotification {
+    /// Always `true`, so receivers can tell it apart from a real change
+    pub test: bool,
+    pub message: &'static str,
+    /// Unix timestamp (seconds) the test was sent at
+    pub timestamp: u64,
+}
@@ -0,0 +1,83 @@
+// Standard library
+use std::time::Duration;
+
+// 3rd party crates
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Serialize;
+
+// Project modules
+use crate::notifications::constants::NOTIFICATION_TIMEOUT_SECS;
+use crate::notifications::errors::{NotificationError, NotificationValidationError};
+use crate::notifications::traits::Notifier;
+use crate::notifications::types::{IpChangeEvent, TestNotification};
+use crate::settings::types::HttpClientOptions;
+
+// Current module imports
+use super::types::{Webhook, WebhookConfig};
+
+impl Webhook {
+    /// POSTs `body` as JSON to the configured URL.
+    async fn post<T: Serialize + Sync>(&self, body: &T) -> Result<(), NotificationError> {
+        let response = self
+            .client
+            .post(self.config.url.trim())
+            .json(body)
+            .send()
+            .await
+            .map_err(NotificationError::SendFailed)?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(NotificationError::UnexpectedStatus(status));
+        }
+
+        Ok(())
+    }
+
+    /// Creates a new webhook notifier with the provided configuration.
+    pub fn new(
+        config: WebhookConfig,
+        http_options: &HttpClientOptions,
+    ) -> Result<Self, NotificationError> {
+        let client = http_options
+            .apply(Client::builder())
+            .timeout(Duration::from_secs(NOTIFICATION_TIMEOUT_SECS))
+            .build()
+            .map_err(NotificationError::HttpClientBuild)?;
+
+        Ok(Self { config, client })
+    }
+}
+
+impl WebhookConfig {
+    pub fn validate(&self) -> Result<(), NotificationValidationError> {
+        let url = self.url.trim();
+        if url.is_empty() {
+            return Err(NotificationValidationError::MissingWebhookUrl);
+        }
+
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            return Err(NotificationValidationError::InvalidWebhookUrl(
+                self.url.clone(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Notifier for Webhook {
+    async fn notify_ip_change(&self, event: &IpChangeEvent) -> Result<(), NotificationError> {
+        self.post(event).await
+    }
+
+    async fn notify_test(&self, notification: &TestNotification) -> Result<(), NotificationError> {
+        self.post(notification).await
+    }
+
+    fn get_name(&self) -> &str {
+        "webhook"
+    }
+}
@@ -0,0 +1,17 @@
+// 3rd party crates
+use reqwest::Client;
+use serde::Deserialize;
+
+/// Sends IP change events as JSON to an HTTP endpoint.
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    pub config: WebhookConfig,
+    pub client: Client,
+}
+
+/// Configuration for the webhook notifier.
+#[derive(Debug, Deserialize, Clone)]
+pub struct WebhookConfig {
+    /// The URL that receives the POST request
+    pub url: String,
+}
@@ -0,0 +1,8 @@
+pub const ARVANCLOUD_API_BASE: &str = "https://napi.arvancloud.ir/cdn/4.0";
+
+/// TTL used when a subdomain does not set one
+pub const ARVANCLOUD_DEFAULT_TTL: u32 = 120;
+/// Smallest TTL ArvanCloud accepts
+pub const ARVANCLOUD_MIN_TTL: u32 = 120;
+/// Largest TTL ArvanCloud accepts
+pub const ARVANCLOUD_MAX_TTL: u32 = 86400;
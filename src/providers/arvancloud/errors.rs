@@ -0,0 +1,59 @@
+// 3rd party crates
+use thiserror::Error;
+
+/// Represents errors that can occur during ArvanCloud API operations
+/// The variants mirror `CloudflareError` so both providers surface
+/// failures in the same shape to the update loop.
+#[derive(Debug, Error)]
+pub enum ArvanError {
+    #[error("Invalid API key for domain '{0}'")]
+    InvalidApiKey(String),
+
+    #[error("No subdomains configured for domain '{0}'")]
+    NoSubdomains(String),
+
+    #[error("HTTP client error: {0}")]
+    HttpClientBuild(#[from] reqwest::Error),
+
+    #[error("Invalid header value: {0}")]
+    InvalidHeaderValue(#[from] reqwest::header::InvalidHeaderValue),
+
+    #[error("Failed to update DNS records for domain '{domain}': {message}")]
+    UpdateFailed { domain: String, message: String },
+
+    #[error("Failed to fetch DNS records for domain '{domain}': {message}")]
+    FetchFailed { domain: String, message: String },
+
+    #[error("Failed to create DNS record '{record}' in domain '{domain}': {message}")]
+    CreateFailed {
+        domain: String,
+        record: String,
+        message: String,
+    },
+
+    #[error("Rate limit exceeded for domain '{0}'")]
+    RateLimited(String),
+
+    #[error("Invalid rate limit configuration for domain '{domain}': {reason}")]
+    InvalidRateLimit { domain: String, reason: String },
+
+    #[error("Operation timed out for domain '{domain}': {message}")]
+    Timeout { domain: String, message: String },
+
+    #[error("Validation error: {0}")]
+    Validation(#[from] ArvanValidationError),
+}
+
+#[derive(Debug, Error)]
+pub enum ArvanValidationError {
+    #[error("Missing or empty api_key, set api_key or api_key_file")]
+    MissingApiKey,
+    #[error("Missing or empty name")]
+    MissingName,
+    #[error("No subdomains configured")]
+    NoSubdomains,
+    #[error("Invalid rate limit: {0}")]
+    InvalidRateLimit(String),
+    #[error("Invalid TTL for subdomain '{subdomain}': {reason}")]
+    InvalidTtl { subdomain: String, reason: String },
+}
@@ -0,0 +1,576 @@
+// Standard library
+use std::error::Error;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+// 3rd party crates
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::{header, Client, StatusCode};
+use tokio::sync::RwLockReadGuard;
+use tracing::{debug, error, info, warn};
+
+// Project modules
+use crate::providers::cloudflare::types::IpVersion;
+use crate::providers::functions::record_content_matches;
+use crate::providers::types::RetryPolicy;
+use crate::settings::types::{ConfigManager, HttpClientOptions, Settings};
+use crate::utility::ip_detector::types::IpVersion as DetectedIpVersion;
+
+// Current module imports
+use super::constants::ARVANCLOUD_API_BASE;
+use super::errors::ArvanError;
+use super::types::{
+    ArvanCloud, ArvanConfig, ArvanDnsRecord, ArvanDnsResponse, ArvanIpFilterMode, ArvanIpRecord,
+    ArvanIpValue, ArvanRecordType,
+};
+
+/// Creates a reqwest client with the appropriate headers for ArvanCloud API.
+/// ArvanCloud expects the key in the `Authorization` header prefixed with `Apikey`.
+pub fn create_reqwest_client(
+    arvan: &ArvanConfig,
+    options: &HttpClientOptions,
+) -> Result<Client, ArvanError> {
+    if arvan.api_key.is_empty() || arvan.api_key == "your_api_key_here" {
+        error!(
+            domain = %arvan.name,
+            "API key is not set or invalid for '{}'",
+            arvan.name
+        );
+        return Err(ArvanError::InvalidApiKey(arvan.name.clone()));
+    }
+
+    // Create headers.
+    let mut headers: HeaderMap = HeaderMap::new();
+
+    // Accept keys copied from the panel both with and without the prefix.
+    let api_key: String = if arvan.api_key.starts_with("Apikey ") {
+        arvan.api_key.clone()
+    } else {
+        format!("Apikey {}", arvan.api_key)
+    };
+
+    // Mark security-sensitive headers with `set_sensitive`.
+    let mut auth_value: HeaderValue = HeaderValue::from_str(&api_key).map_err(|e| {
+        error!(
+            domain = %arvan.name,
+            "Invalid API key format: {}",
+            e
+        );
+        ArvanError::InvalidHeaderValue(e)
+    })?;
+    auth_value.set_sensitive(true);
+    headers.insert(header::AUTHORIZATION, auth_value);
+
+    // Build the client.
+    let client: Client = options
+        .apply(Client::builder())
+        .default_headers(headers)
+        .build()
+        .map_err(|e| {
+            error!(
+                domain = %arvan.name,
+                "Failed to build HTTP client: {}",
+                e
+            );
+            ArvanError::HttpClientBuild(e)
+        })?;
+
+    Ok(client)
+}
+
+/// Gets all enabled ArvanCloud instances from the configuration.
+/// This function creates ArvanCloud clients for each enabled configuration,
+/// initializing them with the appropriate settings.
+pub async fn get_arvanclouds(
+    config: Arc<ConfigManager>,
+) -> Result<Vec<ArvanCloud>, Box<dyn Error>> {
+    let settings: RwLockReadGuard<Settings> = config.settings.read().await;
+    let http_options = settings.http_client_options();
+
+    let mut arvanclouds = Vec::new();
+    for arvan_config in settings.arvancloud.iter() {
+        if arvan_config.enabled {
+            match ArvanCloud::new(arvan_config.clone())
+                .and_then(|arvancloud| arvancloud.with_http_options(&http_options))
+            {
+                Ok(arvancloud) => arvanclouds.push(
+                    arvancloud
+                        .with_dry_run(settings.update.dry_run)
+                        .with_retry_policy(RetryPolicy::new(
+                            settings.update.max_retries,
+                            settings.update.retry_base_delay_secs,
+                        )),
+                ),
+                Err(e) => error!("Failed to create ArvanCloud instance: {}", e),
+            }
+        }
+    }
+    Ok(arvanclouds)
+}
+
+/// Fetches the DNS records of the given type matching a record name.
+/// ArvanCloud's `search` parameter is a substring match, so the result is
+/// filtered down to records whose name matches exactly.
+async fn fetch_dns_records(
+    arvancloud: &ArvanCloud,
+    record_name: &str,
+    record_type: ArvanRecordType,
+) -> Result<Vec<ArvanDnsRecord>, ArvanError> {
+    let url = format!(
+        "{}/domains/{}/dns-records?type={}&search={}",
+        ARVANCLOUD_API_BASE,
+        arvancloud.config.name,
+        record_type.as_str(),
+        record_name
+    );
+
+    debug!(
+        domain = %arvancloud.config.name,
+        record = %record_name,
+        url = %url,
+        "Sending DNS records request"
+    );
+
+    let response =
+        tokio::time::timeout(Duration::from_secs(10), arvancloud.client.get(&url).send())
+            .await
+            .map_err(|_| ArvanError::Timeout {
+                domain: arvancloud.config.name.clone(),
+                message: "DNS record fetch request timed out".to_string(),
+            })??;
+
+    let status = response.status();
+    match status {
+        StatusCode::OK => {
+            let response_text = response.text().await.map_err(|e| ArvanError::FetchFailed {
+                domain: arvancloud.config.name.clone(),
+                message: format!("Failed to read response body: {}", e),
+            })?;
+
+            debug!(
+                domain = %arvancloud.config.name,
+                record = %record_name,
+                response = %response_text,
+                "Received DNS records response"
+            );
+
+            let records: ArvanDnsResponse =
+                serde_json::from_str(&response_text).map_err(|e| ArvanError::FetchFailed {
+                    domain: arvancloud.config.name.clone(),
+                    message: format!("Failed to parse response: {}", e),
+                })?;
+
+            Ok(records
+                .data
+                .into_iter()
+                .filter(|record| record.name == record_name)
+                .collect())
+        }
+        StatusCode::UNAUTHORIZED => Err(ArvanError::InvalidApiKey(arvancloud.config.name.clone())),
+        _ => Err(ArvanError::FetchFailed {
+            domain: arvancloud.config.name.clone(),
+            message: format!("HTTP {}", status),
+        }),
+    }
+}
+
+/// Fetches the address every managed record currently holds.
+/// Only the first address of a multi-IP record is reported, as it is the
+/// only one updates manage.
+pub async fn fetch_current_records(
+    arvancloud: &ArvanCloud,
+) -> Result<Vec<(String, IpAddr)>, ArvanError> {
+    let mut current = Vec::new();
+    for subdomain in &arvancloud.config.subdomains {
+        // ArvanCloud addresses the root domain as "@"
+        let record_name = if subdomain.name.is_empty() {
+            "@"
+        } else {
+            subdomain.name.as_str()
+        };
+        let fqdn = arvancloud.config.fqdn(&subdomain.name);
+
+        for (version, record_type) in [
+            (DetectedIpVersion::V4, ArvanRecordType::A),
+            (DetectedIpVersion::V6, ArvanRecordType::Aaaa),
+        ] {
+            if !subdomain.ip_version.includes(version) {
+                continue;
+            }
+            let records = arvancloud
+                .with_rate_limit(fetch_dns_records(arvancloud, record_name, record_type))
+                .await?;
+            for value in records.iter().filter_map(|record| record.value.first()) {
+                match value.ip.trim().parse::<IpAddr>() {
+                    Ok(ip) => current.push((fqdn.clone(), ip)),
+                    Err(_) => warn!(
+                        domain = %arvancloud.config.name,
+                        record = %record_name,
+                        "Ignoring {} record with non-IP content {}",
+                        record_type.as_str(),
+                        value.ip
+                    ),
+                }
+            }
+        }
+    }
+    Ok(current)
+}
+
+/// Updates DNS records for all configured subdomains.
+/// This function:
+/// - Processes each subdomain
+/// - Handles retries on failure
+/// - Provides detailed logging of the update process
+pub async fn update_dns_records(arvancloud: &ArvanCloud, ip: &IpAddr) -> Result<(), ArvanError> {
+    let mut last_error: Option<ArvanError> = None;
+    let mut update_count = 0;
+    let retry_policy = arvancloud.retry_policy;
+
+    let record_type = ArvanRecordType::for_ip(ip);
+
+    for subdomain in &arvancloud.config.subdomains {
+        // Skip if this IP version is not enabled for this subdomain
+        match (ip, &subdomain.ip_version) {
+            (IpAddr::V4(_), IpVersion::V6) | (IpAddr::V6(_), IpVersion::V4) => {
+                debug!(
+                    domain = %arvancloud.config.name,
+                    subdomain = %subdomain.name,
+                    ip_type = %record_type.as_str(),
+                    "Skipping DNS update - IP version not enabled for subdomain"
+                );
+                continue;
+            }
+            _ => {}
+        }
+
+        // ArvanCloud addresses the root domain as "@"
+        let record_name = if subdomain.name.is_empty() {
+            "@".to_string()
+        } else {
+            subdomain.name.clone()
+        };
+
+        info!(
+            domain = %arvancloud.config.name,
+            record = %record_name,
+            "Processing DNS records"
+        );
+
+        // Each subdomain gets its own retry budget
+        let mut retry_count = 0;
+        'retry: loop {
+            match process_domain_record(arvancloud, &record_name, ip, record_type, subdomain.ttl())
+                .await
+            {
+                Ok(_) => {
+                    update_count += 1;
+                    break 'retry;
+                }
+                Err(e) => {
+                    if retry_count < retry_policy.max_retries {
+                        retry_count += 1;
+                        let delay = retry_policy.backoff(retry_count);
+                        warn!(
+                            domain = %arvancloud.config.name,
+                            record = %record_name,
+                            error = %e,
+                            retry = retry_count,
+                            "Retrying in {:?} after error",
+                            delay
+                        );
+                        tokio::time::sleep(delay).await;
+                        continue;
+                    }
+                    error!(
+                        domain = %arvancloud.config.name,
+                        record = %record_name,
+                        error = %e,
+                        "Failed after {} retries",
+                        retry_count
+                    );
+                    last_error = Some(e);
+                    break 'retry;
+                }
+            }
+        }
+    }
+
+    // Log summary
+    if update_count > 0 {
+        info!(
+            domain = %arvancloud.config.name,
+            count = update_count,
+            "Successfully processed {} DNS records",
+            update_count
+        );
+    }
+
+    if let Some(error) = last_error {
+        Err(error)
+    } else {
+        Ok(())
+    }
+}
+
+/// Process a single A or AAAA record - fetch, create if missing, or update if needed.
+/// Rate limiting is applied to every API call through `with_rate_limit`.
+/// In dry-run mode the fetch still happens, but changes are only logged.
+async fn process_domain_record(
+    arvancloud: &ArvanCloud,
+    record_name: &str,
+    ip: &IpAddr,
+    record_type: ArvanRecordType,
+    ttl: u32,
+) -> Result<(), ArvanError> {
+    let records = arvancloud
+        .with_rate_limit(fetch_dns_records(arvancloud, record_name, record_type))
+        .await?;
+
+    if records.is_empty() {
+        if arvancloud.dry_run {
+            info!(
+                domain = %arvancloud.config.name,
+                record = %record_name,
+                "Would create {} record with {}",
+                record_type.as_str(),
+                ip
+            );
+            return Ok(());
+        }
+
+        warn!(
+            domain = %arvancloud.config.name,
+            record = %record_name,
+            "No DNS records found, attempting to create"
+        );
+        return arvancloud
+            .with_rate_limit(create_dns_record(
+                arvancloud,
+                record_name,
+                ip,
+                record_type,
+                ttl,
+            ))
+            .await;
+    }
+
+    // Only the first address of a multi-IP record is managed, the others are kept
+    let ip_string = ip.to_string();
+    for record in records {
+        if !record
+            .value
+            .first()
+            .is_some_and(|value| record_content_matches(&value.ip, &ip_string))
+        {
+            let current: Vec<&str> = record.value.iter().map(|v| v.ip.as_str()).collect();
+            if arvancloud.dry_run {
+                info!(
+                    domain = %arvancloud.config.name,
+                    record = %record_name,
+                    "Would update {} from {:?} to {}",
+                    record_name,
+                    current,
+                    ip
+                );
+                continue;
+            }
+
+            info!(
+                domain = %arvancloud.config.name,
+                record = %record_name,
+                "Updating DNS record from {:?} to {}",
+                current,
+                ip
+            );
+
+            match arvancloud
+                .with_rate_limit(update_record(
+                    arvancloud,
+                    &record,
+                    record_name,
+                    ip,
+                    record_type,
+                    ttl,
+                ))
+                .await
+            {
+                Ok(_) => {
+                    info!(
+                        domain = %arvancloud.config.name,
+                        record = %record_name,
+                        "Successfully updated DNS record to {}",
+                        ip
+                    );
+                }
+                Err(e) => {
+                    error!(
+                        domain = %arvancloud.config.name,
+                        record = %record_name,
+                        "Failed to update DNS record: {}",
+                        e
+                    );
+                    return Err(e);
+                }
+            }
+        } else {
+            debug!(
+                domain = %arvancloud.config.name,
+                record = %record_name,
+                "DNS record already set to {}",
+                ip
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Builds the request body for an A or AAAA record pointing at a single IP.
+fn build_record(
+    record_name: &str,
+    ip: &IpAddr,
+    record_type: ArvanRecordType,
+    ttl: u32,
+) -> ArvanIpRecord {
+    ArvanIpRecord {
+        record_type,
+        name: record_name.to_string(),
+        value: vec![ArvanIpValue {
+            ip: ip.to_string(),
+            port: None,
+            weight: Some(100),
+            country: Some(String::new()),
+        }],
+        ttl,
+        cloud: false,
+        upstream_https: "default".to_string(),
+        ip_filter_mode: ArvanIpFilterMode::default(),
+    }
+}
+
+/// Builds the request body that points an existing record at `ip`.
+/// Only the first value's address changes; the other values, their weights
+/// and the record's CDN and load-balancing settings are kept as they are.
+fn build_update(
+    record: &ArvanDnsRecord,
+    record_name: &str,
+    ip: &IpAddr,
+    record_type: ArvanRecordType,
+    ttl: u32,
+) -> ArvanIpRecord {
+    let mut value = record.value.clone();
+    match value.first_mut() {
+        Some(first) => first.ip = ip.to_string(),
+        None => value = build_record(record_name, ip, record_type, ttl).value,
+    }
+
+    ArvanIpRecord {
+        record_type,
+        name: record_name.to_string(),
+        value,
+        ttl,
+        cloud: record.cloud,
+        upstream_https: record.upstream_https.clone(),
+        ip_filter_mode: record.ip_filter_mode.clone(),
+    }
+}
+
+/// Creates a new DNS record with the specified IP address.
+async fn create_dns_record(
+    arvancloud: &ArvanCloud,
+    record_name: &str,
+    ip: &IpAddr,
+    record_type: ArvanRecordType,
+    ttl: u32,
+) -> Result<(), ArvanError> {
+    info!(
+        domain = %arvancloud.config.name,
+        record = %record_name,
+        "Creating new DNS record with IP {}",
+        ip
+    );
+
+    let url = format!(
+        "{}/domains/{}/dns-records",
+        ARVANCLOUD_API_BASE, arvancloud.config.name
+    );
+
+    let response = arvancloud
+        .client
+        .post(&url)
+        .json(&build_record(record_name, ip, record_type, ttl))
+        .send()
+        .await
+        .map_err(|e| ArvanError::CreateFailed {
+            domain: arvancloud.config.name.clone(),
+            record: record_name.to_string(),
+            message: format!("Failed to send create request: {}", e),
+        })?;
+
+    let status = response.status();
+    if status == StatusCode::UNAUTHORIZED {
+        return Err(ArvanError::InvalidApiKey(arvancloud.config.name.clone()));
+    }
+
+    if !status.is_success() {
+        let error_body = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Unknown error".to_string());
+        return Err(ArvanError::CreateFailed {
+            domain: arvancloud.config.name.clone(),
+            record: record_name.to_string(),
+            message: format!("HTTP {} - {}", status, error_body),
+        });
+    }
+
+    info!(
+        domain = %arvancloud.config.name,
+        record = %record_name,
+        "Successfully created DNS record"
+    );
+    Ok(())
+}
+
+/// Updates a specific DNS record with a new IP address.
+/// The record's other settings are preserved, see [`build_update`].
+async fn update_record(
+    arvancloud: &ArvanCloud,
+    record: &ArvanDnsRecord,
+    record_name: &str,
+    ip: &IpAddr,
+    record_type: ArvanRecordType,
+    ttl: u32,
+) -> Result<(), ArvanError> {
+    let url = format!(
+        "{}/domains/{}/dns-records/{}",
+        ARVANCLOUD_API_BASE, arvancloud.config.name, record.id
+    );
+
+    let response = arvancloud
+        .client
+        .put(&url)
+        .json(&build_update(record, record_name, ip, record_type, ttl))
+        .send()
+        .await
+        .map_err(|e| ArvanError::UpdateFailed {
+            domain: arvancloud.config.name.clone(),
+            message: format!("Failed to send update request: {}", e),
+        })?;
+
+    let status = response.status();
+    if status == StatusCode::UNAUTHORIZED {
+        return Err(ArvanError::InvalidApiKey(arvancloud.config.name.clone()));
+    }
+
+    if !status.is_success() {
+        return Err(ArvanError::UpdateFailed {
+            domain: arvancloud.config.name.clone(),
+            message: format!("HTTP {}", status),
+        });
+    }
+
+    Ok(())
+}
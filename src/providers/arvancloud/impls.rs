@@ -0,0 +1,271 @@
+// Standard library
+use std::fmt;
+use std::future::Future;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::Arc;
+use std::time::Duration;
+
+// 3rd party crates
+use async_trait::async_trait;
+
+// Project modules
+use crate::providers::traits::DnsProvider;
+use crate::providers::types::RetryPolicy;
+use crate::settings::types::HttpClientOptions;
+use crate::utility::ip_detector::types::IpVersion;
+use crate::utility::rate_limiter::types::{RateLimitStatus, TokenBucketRateLimiter};
+
+// Current module imports
+use super::constants::{ARVANCLOUD_DEFAULT_TTL, ARVANCLOUD_MAX_TTL, ARVANCLOUD_MIN_TTL};
+use super::errors::{ArvanError, ArvanValidationError};
+use super::functions::{create_reqwest_client, fetch_current_records, update_dns_records};
+use super::types::{ArvanCloud, ArvanConfig, ArvanIpFilterMode, ArvanRecordType, ArvanSubDomain};
+
+// Manual Debug implementation for ArvanCloud
+impl fmt::Debug for ArvanCloud {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArvanCloud")
+            .field("config", &self.config)
+            .field("client", &self.client)
+            .field("rate_limiter", &"<rate limiter>")
+            .field("dry_run", &self.dry_run)
+            .field("retry_policy", &self.retry_policy)
+            .finish()
+    }
+}
+
+// Manual Clone implementation for ArvanCloud
+impl Clone for ArvanCloud {
+    fn clone(&self) -> Self {
+        Self {
+            config: self.config.clone(),
+            client: self.client.clone(),
+            rate_limiter: Arc::clone(&self.rate_limiter),
+            dry_run: self.dry_run,
+            retry_policy: self.retry_policy,
+        }
+    }
+}
+
+impl ArvanCloud {
+    /// Creates a new ArvanCloud instance with the provided configuration.
+    /// This will initialize the HTTP client and rate limiter.
+    pub fn new(config: ArvanConfig) -> Result<Self, ArvanError> {
+        let client = create_reqwest_client(&config, &HttpClientOptions::default())?;
+        let rate_limiter = Arc::new(TokenBucketRateLimiter::new(config.rate_limit.clone()));
+
+        Ok(Self {
+            config,
+            client,
+            rate_limiter,
+            dry_run: false,
+            retry_policy: RetryPolicy::default(),
+        })
+    }
+
+    /// Rebuilds the HTTP client with the given User-Agent and proxy.
+    pub fn with_http_options(mut self, options: &HttpClientOptions) -> Result<Self, ArvanError> {
+        self.client = create_reqwest_client(&self.config, options)?;
+        Ok(self)
+    }
+
+    /// Enables or disables dry-run mode.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Sets how failed record updates are retried.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Acquires a rate limit permit before making an API call.
+    /// This ensures we respect ArvanCloud's API rate limits.
+    pub async fn with_rate_limit<F, T, E>(&self, f: F) -> Result<T, E>
+    where
+        F: Future<Output = Result<T, E>>,
+        E: From<ArvanError>,
+    {
+        if !self.rate_limiter.acquire().await {
+            return Err(ArvanError::RateLimited(self.config.name.clone()).into());
+        }
+
+        f.await
+    }
+}
+
+impl Default for ArvanIpFilterMode {
+    /// A single address, without ordering or geo filtering.
+    fn default() -> Self {
+        Self {
+            count: "single".to_string(),
+            order: "none".to_string(),
+            geo_filter: "none".to_string(),
+        }
+    }
+}
+
+impl ArvanRecordType {
+    /// Returns the record type matching the given IP address family.
+    pub fn for_ip(ip: &IpAddr) -> Self {
+        match ip {
+            IpAddr::V4(_) => Self::A,
+            IpAddr::V6(_) => Self::Aaaa,
+        }
+    }
+
+    /// Returns the lowercase name used by the ArvanCloud API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::A => "a",
+            Self::Aaaa => "aaaa",
+        }
+    }
+}
+
+impl ArvanConfig {
+    /// Returns the fully qualified record name, used in reports.
+    pub fn fqdn(&self, subdomain: &str) -> String {
+        if subdomain.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}.{}", subdomain, self.name)
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ArvanValidationError> {
+        if self.api_key.trim().is_empty() {
+            return Err(ArvanValidationError::MissingApiKey);
+        }
+
+        if self.name.trim().is_empty() {
+            return Err(ArvanValidationError::MissingName);
+        }
+
+        if self.subdomains.is_empty() {
+            return Err(ArvanValidationError::NoSubdomains);
+        }
+
+        // Validate rate limit configuration
+        if self.rate_limit.max_requests == 0 {
+            return Err(ArvanValidationError::InvalidRateLimit(
+                "max_requests must be greater than 0".into(),
+            ));
+        }
+
+        if self.rate_limit.window_secs == 0 {
+            return Err(ArvanValidationError::InvalidRateLimit(
+                "window_secs must be greater than 0".into(),
+            ));
+        }
+
+        for subdomain in &self.subdomains {
+            if !(ARVANCLOUD_MIN_TTL..=ARVANCLOUD_MAX_TTL).contains(&subdomain.ttl()) {
+                return Err(ArvanValidationError::InvalidTtl {
+                    subdomain: subdomain.name.clone(),
+                    reason: format!(
+                        "must be between {} and {}, got {}",
+                        ARVANCLOUD_MIN_TTL,
+                        ARVANCLOUD_MAX_TTL,
+                        subdomain.ttl()
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ArvanSubDomain {
+    /// Returns the record TTL, falling back to the default.
+    pub fn ttl(&self) -> u32 {
+        self.ttl.unwrap_or(ARVANCLOUD_DEFAULT_TTL)
+    }
+}
+
+#[async_trait]
+impl DnsProvider for ArvanCloud {
+    type Config = ArvanConfig;
+    type Error = ArvanError;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Self::new(config)
+    }
+
+    async fn update_dns_records_v4(&self, ip: &Ipv4Addr) -> Result<(), Self::Error> {
+        update_dns_records(self, &IpAddr::V4(*ip)).await
+    }
+
+    async fn update_dns_records_v6(&self, ip: &Ipv6Addr) -> Result<(), Self::Error> {
+        // Check if any subdomain needs IPv6
+        if !self.needs_ip_version(IpVersion::V6) {
+            return Ok(());
+        }
+        update_dns_records(self, &IpAddr::V6(*ip)).await
+    }
+
+    fn needs_ip_version(&self, version: IpVersion) -> bool {
+        self.config
+            .subdomains
+            .iter()
+            .any(|subdomain| subdomain.ip_version.includes(version))
+    }
+
+    async fn get_current_records(&self) -> Result<Vec<(String, IpAddr)>, Self::Error> {
+        fetch_current_records(self).await
+    }
+
+    fn expected_records(&self, ip: &IpAddr) -> Vec<(String, IpAddr)> {
+        self.config
+            .subdomains
+            .iter()
+            .filter(|subdomain| subdomain.ip_version.includes(IpVersion::from(ip)))
+            .map(|subdomain| (self.config.fqdn(&subdomain.name), *ip))
+            .collect()
+    }
+
+    fn validate_config(&self) -> Result<(), Self::Error> {
+        // Basic validation
+        if self.config.api_key.is_empty() || self.config.api_key == "your_api_key_here" {
+            return Err(ArvanError::InvalidApiKey(self.config.name.clone()));
+        }
+        if self.config.subdomains.is_empty() {
+            return Err(ArvanError::NoSubdomains(self.config.name.clone()));
+        }
+
+        // Rate limit validation
+        if self.config.rate_limit.max_requests == 0 {
+            return Err(ArvanError::InvalidRateLimit {
+                domain: self.config.name.clone(),
+                reason: "max_requests must be greater than 0".to_string(),
+            });
+        }
+        if self.config.rate_limit.window_secs == 0 {
+            return Err(ArvanError::InvalidRateLimit {
+                domain: self.config.name.clone(),
+                reason: "window_secs must be greater than 0".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn update_interval(&self) -> Option<Duration> {
+        self.config.interval.map(Duration::from_secs)
+    }
+
+    async fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        Some(self.rate_limiter.status().await)
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    fn get_name(&self) -> &str {
+        &self.config.name
+    }
+}
@@ -0,0 +1,152 @@
+// Standard library
+use std::path::PathBuf;
+use std::sync::Arc;
+
+// 3rd party crates
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+// Project modules
+use crate::providers::cloudflare::types::IpVersion;
+use crate::providers::types::RetryPolicy;
+use crate::utility::rate_limiter::traits::RateLimiter;
+use crate::utility::rate_limiter::types::RateLimitConfig;
+
+/// Represents a client for interacting with the ArvanCloud CDN API.
+/// This client handles DNS record management operations including:
+/// - Creating DNS records
+/// - Updating DNS records
+/// - Fetching DNS records
+///
+/// The client includes built-in rate limiting to respect ArvanCloud's API limits.
+/// In dry-run mode records are still fetched, but never created or changed.
+pub struct ArvanCloud {
+    pub config: ArvanConfig,
+    pub client: Client,
+    pub rate_limiter: Arc<dyn RateLimiter>,
+    pub dry_run: bool,
+    /// How failed record updates are retried
+    pub retry_policy: RetryPolicy,
+}
+
+/// Configuration for ArvanCloud API interactions.
+/// This struct holds all necessary settings for connecting to and managing
+/// DNS records through the ArvanCloud API.
+#[derive(Debug, Deserialize, Clone)]
+pub struct ArvanConfig {
+    /// Whether this ArvanCloud configuration is enabled
+    pub enabled: bool,
+    /// Seconds between updates of this provider; defaults to `[update] interval`
+    #[serde(default)]
+    pub interval: Option<u64>,
+    /// The domain name (e.g., "example.ir")
+    pub name: String,
+    /// The ArvanCloud API key (with or without the "Apikey " prefix)
+    #[serde(default)]
+    pub api_key: String,
+    /// File holding the API key, e.g. a Docker secret; replaces `api_key`
+    #[serde(default)]
+    pub api_key_file: Option<PathBuf>,
+    /// Rate limiting configuration to respect ArvanCloud's API limits
+    #[serde(default = "default_rate_limit_config")]
+    pub rate_limit: RateLimitConfig,
+    /// List of subdomains to manage
+    pub subdomains: Vec<ArvanSubDomain>,
+}
+
+fn default_rate_limit_config() -> RateLimitConfig {
+    RateLimitConfig {
+        max_requests: 60, // ArvanCloud allows 120 requests per minute
+        window_secs: 60,  // 1-minute window
+    }
+}
+
+/// Represents a subdomain configuration in ArvanCloud.
+/// An empty name represents the root domain.
+#[derive(Debug, Deserialize, Clone)]
+pub struct ArvanSubDomain {
+    /// The subdomain name (e.g., "www" for www.example.ir)
+    /// Leave empty for root domain
+    #[serde(default)]
+    pub name: String,
+    /// Which IP versions to use for this subdomain
+    #[serde(default)]
+    pub ip_version: IpVersion,
+    /// Record TTL in seconds; defaults to 120
+    #[serde(default)]
+    pub ttl: Option<u32>,
+}
+
+/// Represents the response from a DNS records list request.
+#[derive(Debug, Deserialize)]
+pub struct ArvanDnsResponse {
+    pub data: Vec<ArvanDnsRecord>,
+}
+
+/// A DNS record as returned by the ArvanCloud API.
+#[derive(Debug, Deserialize)]
+pub struct ArvanDnsRecord {
+    /// The record ID
+    pub id: String,
+    /// The record name relative to the domain ("@" for the root)
+    pub name: String,
+    /// The record values (one entry per IP)
+    #[serde(default)]
+    pub value: Vec<ArvanIpValue>,
+    /// Whether traffic is proxied through the ArvanCloud CDN
+    #[serde(default)]
+    pub cloud: bool,
+    /// Protocol used towards the origin when proxied
+    #[serde(default = "default_upstream_https")]
+    pub upstream_https: String,
+    /// Load-balancing behaviour across the values
+    #[serde(default)]
+    pub ip_filter_mode: ArvanIpFilterMode,
+}
+
+fn default_upstream_https() -> String {
+    "default".to_string()
+}
+
+/// A single IP entry inside an A or AAAA record's value list.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ArvanIpValue {
+    pub ip: String,
+    #[serde(default)]
+    pub port: Option<u16>,
+    #[serde(default)]
+    pub weight: Option<u32>,
+    #[serde(default)]
+    pub country: Option<String>,
+}
+
+/// The address record types managed on ArvanCloud.
+#[derive(Debug, Serialize, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ArvanRecordType {
+    /// IPv4 address record
+    A,
+    /// IPv6 address record
+    Aaaa,
+}
+
+/// Request body for creating or updating an A or AAAA record.
+#[derive(Debug, Serialize)]
+pub struct ArvanIpRecord {
+    #[serde(rename = "type")]
+    pub record_type: ArvanRecordType,
+    pub name: String,
+    pub value: Vec<ArvanIpValue>,
+    pub ttl: u32,
+    pub cloud: bool,
+    pub upstream_https: String,
+    pub ip_filter_mode: ArvanIpFilterMode,
+}
+
+/// Load-balancing behaviour of a multi-IP record.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ArvanIpFilterMode {
+    pub count: String,
+    pub order: String,
+    pub geo_filter: String,
+}
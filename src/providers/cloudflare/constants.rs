@@ -1 +1,25 @@
 pub const CLOUDFLARE_API_BASE: &str = "https://api.cloudflare.com/client/v4";
+
+/// Records requested per page when listing a zone's DNS records
+pub const CLOUDFLARE_RECORDS_PER_PAGE: u32 = 100;
+
+/// Subdomains of one zone updated at the same time
+pub const MAX_CONCURRENT_SUBDOMAIN_UPDATES: usize = 4;
+
+/// Comment attached to the records this client creates or updates
+pub const DEFAULT_RECORD_COMMENT: &str = "managed by fariba-ddns";
+
+/// TTL value Cloudflare interprets as "automatic"
+pub const CLOUDFLARE_AUTO_TTL: u32 = 1;
+/// API error code for creating a record identical to an existing one
+pub const CLOUDFLARE_RECORD_EXISTS_CODE: u32 = 81057;
+/// Smallest explicit TTL Cloudflare accepts (enterprise zones)
+pub const CLOUDFLARE_MIN_TTL: u32 = 30;
+/// Largest TTL Cloudflare accepts
+pub const CLOUDFLARE_MAX_TTL: u32 = 86400;
+
+/// Longest TXT record content Cloudflare accepts
+pub const CLOUDFLARE_MAX_TXT_LENGTH: usize = 2048;
+
+/// Length of the delegated IPv6 prefix kept when an `ipv6_suffix` is set
+pub const DEFAULT_IPV6_PREFIX_LENGTH: u8 = 64;
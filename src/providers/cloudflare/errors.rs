@@ -11,8 +11,8 @@ pub enum CloudflareError {
     #[error("Invalid API token for zone '{0}'")]
     InvalidApiToken(String),
 
-    #[error("Invalid zone ID for zone '{0}'")]
-    InvalidZoneId(String),
+    #[error("No zone named '{0}' is accessible with the configured credentials")]
+    ZoneNotFound(String),
 
     #[error("No subdomains configured for zone '{0}'")]
     NoSubdomains(String),
@@ -29,6 +29,9 @@ pub enum CloudflareError {
     #[error("Failed to fetch DNS records for zone '{zone}': {message}")]
     FetchFailed { zone: String, message: String },
 
+    #[error("Failed to delete DNS record for zone '{zone}': {message}")]
+    DeleteFailed { zone: String, message: String },
+
     #[error("Failed to create DNS record for domain '{domain}' in zone '{zone}': {message}")]
     CreateFailed {
         zone: String,
@@ -36,37 +39,64 @@ pub enum CloudflareError {
         message: String,
     },
 
+    #[error("A {record_type} record for '{domain}' already exists in zone '{zone}'")]
+    RecordExists {
+        zone: String,
+        domain: String,
+        record_type: String,
+    },
+
     #[error("Rate limit exceeded for zone '{0}'")]
     RateLimited(String),
 
+    #[error("Cloudflare API rate limit hit for zone '{zone}' (HTTP 429)")]
+    RateLimited429 {
+        zone: String,
+        retry_after_secs: Option<u64>,
+    },
+
     #[error("Invalid rate limit configuration for zone '{zone}': {reason}")]
     InvalidRateLimit { zone: String, reason: String },
 
     #[error("Zone '{0}' is not active (status: {1})")]
     InactiveZone(String, String),
 
+    #[error("Zone ID for '{zone}' belongs to zone '{actual}'; check the configured zone_id")]
+    ZoneNameMismatch { zone: String, actual: String },
+
     #[error("Operation timed out for zone '{zone}': {message}")]
     Timeout { zone: String, message: String },
 
-    #[error("DNS update operation timed out")]
-    UpdateTimeout,
-
     #[error("Validation error: {0}")]
     Validation(#[from] CloudflareValidationError),
 }
 
 #[derive(Debug, Error)]
 pub enum CloudflareValidationError {
-    #[error("Missing or empty zone_id")]
-    MissingZoneId,
-    #[error("Missing or empty api_token")]
+    #[error("Missing credentials: set api_token or api_token_file, or api_key with account_email")]
     MissingApiToken,
+    #[error("Both api_token and api_key are set; use only one authentication method")]
+    ConflictingAuth,
+    #[error("api_key requires account_email")]
+    MissingAccountEmail,
     #[error("Missing or empty name")]
     MissingName,
+    #[error("Invalid api_base '{0}': must be an http or https URL")]
+    InvalidApiBase(String),
     #[error("No subdomains configured")]
     NoSubdomains,
     #[error("Invalid rate limit: {0}")]
     InvalidRateLimit(String),
     #[error("Invalid IP version configuration: {0}")]
     InvalidIpVersion(String),
+    #[error("Invalid name for subdomain '{subdomain}': {reason}")]
+    InvalidSubdomainName { subdomain: String, reason: String },
+    #[error("Invalid CNAME for subdomain '{subdomain}': {reason}")]
+    InvalidCname { subdomain: String, reason: String },
+    #[error("Invalid IPv6 suffix for subdomain '{subdomain}': {reason}")]
+    InvalidIpv6Suffix { subdomain: String, reason: String },
+    #[error("Invalid TTL for subdomain '{subdomain}': {reason}")]
+    InvalidTtl { subdomain: String, reason: String },
+    #[error("Invalid TXT record '{name}': {reason}")]
+    InvalidTxtRecord { name: String, reason: String },
 }
@@ -1,4 +1,5 @@
 // Standard library
+use std::collections::HashMap;
 use std::error::Error;
 use std::net::IpAddr;
 use std::sync::Arc;
@@ -7,51 +8,86 @@ use std::time::Duration;
 // 3rd party crates
 use futures::{stream::FuturesUnordered, StreamExt};
 use reqwest::header::{HeaderMap, HeaderValue};
-use reqwest::{header, Client, StatusCode};
-use serde_json::json;
-use tokio::sync::{broadcast, RwLockReadGuard};
-use tokio::time::timeout;
-use tracing::{debug, error, info, warn};
+use reqwest::{header, Client, Response, StatusCode};
+use serde_json::{json, Value};
+use tokio::sync::RwLockReadGuard;
+use tokio::time::Instant;
+use tracing::{debug, error, info, instrument, warn};
 
 // Project modules
-use crate::providers::DnsProvider;
-use crate::settings::types::{ConfigManager, Settings};
+use crate::metrics::types::MetricsManager;
+use crate::providers::functions::record_content_matches;
+use crate::providers::types::RetryPolicy;
+use crate::settings::types::{ConfigManager, HttpClientOptions, Settings};
+use crate::utility::ip_detector::types::IpVersion as DetectedIpVersion;
 
 // Current module imports
-use super::constants::CLOUDFLARE_API_BASE;
+use super::constants::{
+    CLOUDFLARE_AUTO_TTL, CLOUDFLARE_RECORDS_PER_PAGE, CLOUDFLARE_RECORD_EXISTS_CODE,
+    MAX_CONCURRENT_SUBDOMAIN_UPDATES,
+};
 use super::errors::CloudflareError;
-use super::types::{CfConfig, Cloudflare, DnsResponse, ZoneResponse};
+use super::types::{
+    ApiErrorResponse, CfAuth, CfConfig, CfManageMode, CfRecordType, CfSubDomain, Cloudflare,
+    CreateRecordResponse, DnsResponse, DnsResponseResult, TxtSync, ZoneListResponse, ZoneResponse,
+};
 
 /// Creates a reqwest client with the appropriate headers for Cloudflare API.
 /// This includes setting up authentication headers and other necessary configuration.
-pub fn create_reqwest_client(cloudflare: &CfConfig) -> Result<Client, CloudflareError> {
-    if cloudflare.api_token.is_empty() || cloudflare.api_token == "your_api_token_here" {
-        error!(
-            zone = %cloudflare.name,
-            "API token is not set or invalid for '{}'",
-            cloudflare.name
-        );
-        return Err(CloudflareError::InvalidApiToken(cloudflare.name.clone()));
-    }
+/// An API token is sent as a bearer token; the legacy Global API Key as
+/// `X-Auth-Key` and `X-Auth-Email`.
+pub fn create_reqwest_client(
+    cloudflare: &CfConfig,
+    options: &HttpClientOptions,
+) -> Result<Client, CloudflareError> {
+    let auth: CfAuth = match cloudflare.auth() {
+        Ok(CfAuth::ApiToken("your_api_token_here")) | Err(_) => {
+            error!(
+                zone = %cloudflare.name,
+                "API credentials are not set or invalid for '{}'",
+                cloudflare.name
+            );
+            return Err(CloudflareError::InvalidApiToken(cloudflare.name.clone()));
+        }
+        Ok(auth) => auth,
+    };
 
     // Create headers.
     let mut headers: HeaderMap = HeaderMap::new();
 
     // Mark security-sensitive headers with `set_sensitive`.
-    let bearer_token: String = format!("Bearer {}", &cloudflare.api_token);
-    let mut auth_value: HeaderValue = HeaderValue::from_str(&bearer_token).map_err(|e| {
-        error!(
-            zone = %cloudflare.name,
-            "Invalid API token format: {}",
-            e
-        );
-        CloudflareError::InvalidHeaderValue(e)
-    })?;
-    auth_value.set_sensitive(true);
-    headers.insert(header::AUTHORIZATION, auth_value);
+    let sensitive_header = |value: &str| {
+        HeaderValue::from_str(value)
+            .map(|mut value| {
+                value.set_sensitive(true);
+                value
+            })
+            .map_err(|e| {
+                error!(
+                    zone = %cloudflare.name,
+                    "Invalid API credential format: {}",
+                    e
+                );
+                CloudflareError::InvalidHeaderValue(e)
+            })
+    };
+    match auth {
+        CfAuth::ApiToken(api_token) => {
+            let bearer_token: String = format!("Bearer {}", api_token);
+            headers.insert(header::AUTHORIZATION, sensitive_header(&bearer_token)?);
+        }
+        CfAuth::GlobalApiKey {
+            api_key,
+            account_email,
+        } => {
+            headers.insert("X-Auth-Key", sensitive_header(api_key)?);
+            headers.insert("X-Auth-Email", sensitive_header(account_email)?);
+        }
+    }
 
     // Build the client.
-    let client: Client = Client::builder()
+    let client: Client = options
+        .apply(Client::builder())
         .default_headers(headers)
         .build()
         .map_err(|e| {
@@ -66,19 +102,61 @@ pub fn create_reqwest_client(cloudflare: &CfConfig) -> Result<Client, Cloudflare
     Ok(client)
 }
 
+/// Builds the error for an HTTP 429 response, keeping its `Retry-After` delay.
+/// Only the delay-seconds form of the header is understood. The zone's rate
+/// limiter is paused for that delay, so no other request goes out meanwhile.
+async fn rate_limited_error(cloudflare: &Cloudflare, response: &Response) -> CloudflareError {
+    let retry_after_secs = response
+        .headers()
+        .get(header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok());
+
+    warn!(
+        zone = %cloudflare.config.name,
+        retry_after_secs = ?retry_after_secs,
+        "Cloudflare API rate limit hit"
+    );
+    if let Some(secs) = retry_after_secs {
+        cloudflare
+            .rate_limiter
+            .pause_until(Instant::now() + Duration::from_secs(secs))
+            .await;
+    }
+
+    CloudflareError::RateLimited429 {
+        zone: cloudflare.config.name.clone(),
+        retry_after_secs,
+    }
+}
+
 /// Gets all enabled Cloudflare instances from the configuration.
 /// This function creates Cloudflare clients for each enabled configuration,
-/// initializing them with the appropriate settings.
+/// initializing them with the appropriate settings and the shared metrics.
 pub async fn get_cloudflares(
     config: Arc<ConfigManager>,
+    metrics: Arc<MetricsManager>,
 ) -> Result<Vec<Cloudflare>, Box<dyn Error>> {
     let settings: RwLockReadGuard<Settings> = config.settings.read().await;
+    let http_options = settings.http_client_options();
 
     let mut cloudflares = Vec::new();
     for cf_config in settings.cloudflare.iter() {
         if cf_config.enabled {
-            match Cloudflare::new(cf_config.clone()) {
-                Ok(cloudflare) => cloudflares.push(cloudflare),
+            match Cloudflare::new(cf_config.clone())
+                .and_then(|cloudflare| cloudflare.with_http_options(&http_options))
+            {
+                Ok(cloudflare) => cloudflares.push(
+                    cloudflare
+                        .with_metrics(Arc::clone(&metrics))
+                        .with_dry_run(settings.update.dry_run)
+                        .with_retry_policy(RetryPolicy::new(
+                            settings.update.max_retries,
+                            settings.update.retry_base_delay_secs,
+                        ))
+                        .with_record_comment(&settings.update.record_comment)
+                        .with_respect_foreign_records(settings.update.respect_foreign_records),
+                ),
                 Err(e) => error!("Failed to create Cloudflare instance: {}", e),
             }
         }
@@ -86,136 +164,117 @@ pub async fn get_cloudflares(
     Ok(cloudflares)
 }
 
-/// Processes updates concurrently for multiple Cloudflare instances.
-/// This function handles updating DNS records for multiple domains in parallel,
-/// using a FuturesUnordered to manage concurrent updates efficiently.
-/// Now includes graceful shutdown handling.
-pub async fn process_updates(
-    cloudflares: &[Cloudflare],
-    ip: &IpAddr,
-    shutdown_rx: Option<broadcast::Receiver<()>>,
-) -> Result<(), Box<dyn Error>> {
-    // Create a FuturesUnordered to hold our concurrent tasks.
-    let futures = FuturesUnordered::new();
-
-    // For each Cloudflare instance, spawn an async task to update DNS records.
-    for cloudflare in cloudflares {
-        info!(
-            zone = %cloudflare.config.name,
-            "Starting DNS update process"
-        );
-        // Push the future into the FuturesUnordered stream.
-        let cloudflare = cloudflare.clone();
-        let ip = *ip;
-        futures.push(async move {
-            // Call the method to update DNS records.
-            cloudflare.update_dns_records_ip(&ip).await
-        });
-    }
+/// Fetches DNS records for a specific domain.
+/// This function retrieves the current A or AAAA records for a domain from Cloudflare's API.
+/// It includes error handling for various API response scenarios.
+pub async fn fetch_dns_records(
+    cloudflare: &Cloudflare,
+    domain: &str,
+    record_type: &str,
+) -> Result<DnsResponse, CloudflareError> {
+    fetch_records_page(cloudflare, &format!("type={}&name={}", record_type, domain)).await
+}
 
-    // Set a timeout for the entire update process
-    let update_timeout = Duration::from_secs(30);
+/// Fetches the addresses currently held by the A and AAAA records of every
+/// subdomain, as `(domain, address)` pairs. CNAME and disabled subdomains are skipped.
+pub async fn fetch_current_records(
+    cloudflare: &Cloudflare,
+) -> Result<Vec<(String, IpAddr)>, CloudflareError> {
+    let mut current = Vec::new();
+    for subdomain in &cloudflare.config.subdomains {
+        if !subdomain.enabled || subdomain.record_type != CfRecordType::A {
+            continue;
+        }
+        let full_domain = subdomain.fqdn(&cloudflare.config.name);
 
-    // Process updates with timeout and shutdown handling
-    match timeout(
-        update_timeout,
-        process_updates_with_shutdown(futures, shutdown_rx),
-    )
-    .await
-    {
-        Ok(result) => result,
-        Err(_) => {
-            error!(
-                "DNS updates timed out after {} seconds",
-                update_timeout.as_secs()
-            );
-            Err(Box::new(CloudflareError::UpdateTimeout))
+        for (version, record_type) in [
+            (DetectedIpVersion::V4, "A"),
+            (DetectedIpVersion::V6, "AAAA"),
+        ] {
+            if !subdomain.ip_version().includes(version) {
+                continue;
+            }
+            let records = cloudflare
+                .with_rate_limit(fetch_dns_records(cloudflare, &full_domain, record_type))
+                .await?;
+            for record in records.result {
+                match record.content.parse::<IpAddr>() {
+                    Ok(ip) => current.push((full_domain.clone(), ip)),
+                    Err(_) => warn!(
+                        zone = %cloudflare.config.name,
+                        domain = %full_domain,
+                        "Ignoring {} record with non-IP content {}",
+                        record_type,
+                        record.content
+                    ),
+                }
+            }
         }
     }
+    Ok(current)
 }
 
-/// Helper function to process updates with shutdown handling
-async fn process_updates_with_shutdown(
-    mut futures: FuturesUnordered<impl std::future::Future<Output = Result<(), CloudflareError>>>,
-    mut shutdown_rx: Option<broadcast::Receiver<()>>,
-) -> Result<(), Box<dyn Error>> {
-    let mut update_count = 0;
-    let mut last_error = None;
+/// Fetches every record of one type in the zone, following pagination.
+/// Records are grouped by lowercased name so subdomains can be matched in memory.
+/// Each page request goes through the rate limiter.
+pub async fn fetch_zone_records(
+    cloudflare: &Cloudflare,
+    record_type: &str,
+) -> Result<HashMap<String, Vec<DnsResponseResult>>, CloudflareError> {
+    let mut records: HashMap<String, Vec<DnsResponseResult>> = HashMap::new();
+    let mut page = 1;
 
     loop {
-        tokio::select! {
-            // Handle shutdown signal if provided
-            Some(shutdown_result) = async {
-                if let Some(rx) = &mut shutdown_rx {
-                    Some(rx.recv().await)
-                } else {
-                    None
-                }
-            } => {
-                match shutdown_result {
-                    Ok(()) => {
-                        info!("Received shutdown signal during DNS updates, waiting for in-progress updates...");
-                        // Allow a short time for in-progress updates to complete
-                        tokio::time::sleep(Duration::from_secs(5)).await;
-                        break;
-                    }
-                    Err(e) => {
-                        warn!("Shutdown receiver error: {}", e);
-                        // Continue processing if there's a receiver error
-                        continue;
-                    }
-                }
-            }
-            // Process next update
-            Some(result) = futures.next() => {
-                match result {
-                    Ok(_) => {
-                        update_count += 1;
-                        debug!("Successfully completed DNS update {}", update_count);
-                    }
-                    Err(e) => {
-                        error!("Error updating DNS records: {}", e);
-                        last_error = Some(e);
-                    }
-                }
+        let query = format!(
+            "type={}&per_page={}&page={}",
+            record_type, CLOUDFLARE_RECORDS_PER_PAGE, page
+        );
+        let response = cloudflare
+            .with_rate_limit(fetch_records_page(cloudflare, &query))
+            .await?;
 
-                // Check if all updates are complete
-                if futures.is_empty() {
-                    break;
-                }
-            }
-            // All futures completed
-            else => break,
+        let total_pages = response
+            .result_info
+            .as_ref()
+            .map_or(1, |info| info.total_pages);
+        for record in response.result {
+            records
+                .entry(record.name.to_ascii_lowercase())
+                .or_default()
+                .push(record);
         }
-    }
 
-    // Report results
-    if update_count > 0 {
-        info!("Completed {} DNS updates", update_count);
-        Ok(())
-    } else if let Some(e) = last_error {
-        Err(Box::new(e))
-    } else {
-        Ok(())
+        if page >= total_pages {
+            break;
+        }
+        page += 1;
     }
+
+    debug!(
+        zone = %cloudflare.config.name,
+        record_type = %record_type,
+        pages = page,
+        names = records.len(),
+        "Fetched zone DNS records"
+    );
+    Ok(records)
 }
 
-/// Fetches DNS records for a specific domain.
-/// This function retrieves the current A or AAAA records for a domain from Cloudflare's API.
-/// It includes error handling for various API response scenarios.
-async fn fetch_dns_records(
+/// Sends one DNS record listing request with the given query string.
+async fn fetch_records_page(
     cloudflare: &Cloudflare,
-    domain: &str,
-    record_type: &str,
+    query: &str,
 ) -> Result<DnsResponse, CloudflareError> {
+    let zone_id = cloudflare.zone_id().await?;
     let url = format!(
-        "{}/zones/{}/dns_records?type={}&name={}",
-        CLOUDFLARE_API_BASE, cloudflare.config.zone_id, record_type, domain
+        "{}/zones/{}/dns_records?{}",
+        cloudflare.config.api_base(),
+        zone_id,
+        query
     );
 
     debug!(
         zone = %cloudflare.config.name,
-        domain = %domain,
         url = %url,
         "Sending DNS records request"
     );
@@ -242,7 +301,6 @@ async fn fetch_dns_records(
 
             debug!(
                 zone = %cloudflare.config.name,
-                domain = %domain,
                 response = %response_text,
                 "Received DNS records response"
             );
@@ -255,6 +313,7 @@ async fn fetch_dns_records(
         StatusCode::UNAUTHORIZED => Err(CloudflareError::InvalidApiToken(
             cloudflare.config.name.clone(),
         )),
+        StatusCode::TOO_MANY_REQUESTS => Err(rate_limited_error(cloudflare, &response).await),
         _ => Err(CloudflareError::FetchFailed {
             zone: cloudflare.config.name.clone(),
             message: format!("HTTP {}", status),
@@ -262,94 +321,161 @@ async fn fetch_dns_records(
     }
 }
 
+/// Returns how long to wait before retrying after `error`, or `None` once
+/// retries are exhausted. Rate limit hits are recorded in the metrics.
+/// A Retry-After beyond our longest backoff is left to the next cycle.
+async fn retry_delay(
+    cloudflare: &Cloudflare,
+    error: &CloudflareError,
+    retry_count: u32,
+    ip: &IpAddr,
+) -> Option<Duration> {
+    let retry_policy = cloudflare.retry_policy;
+    let retry_after = match error {
+        CloudflareError::RateLimited(_) => {
+            cloudflare.metrics.record_rate_limit(ip).await;
+            None
+        }
+        CloudflareError::RateLimited429 {
+            retry_after_secs, ..
+        } => {
+            cloudflare.metrics.record_rate_limit(ip).await;
+            retry_after_secs.map(Duration::from_secs)
+        }
+        _ => None,
+    };
+
+    let retry_allowed = retry_count < retry_policy.max_retries
+        && retry_after.is_none_or(|delay| delay <= retry_policy.max_delay);
+    retry_allowed.then(|| retry_after.unwrap_or_else(|| retry_policy.backoff(retry_count + 1)))
+}
+
+/// Fetches the zone's records of one type, retrying like a record update.
+async fn load_zone_records(
+    cloudflare: &Cloudflare,
+    record_type: &str,
+    ip: &IpAddr,
+) -> Result<HashMap<String, Vec<DnsResponseResult>>, CloudflareError> {
+    let mut retry_count = 0;
+    loop {
+        match fetch_zone_records(cloudflare, record_type).await {
+            Ok(records) => return Ok(records),
+            Err(e) => match retry_delay(cloudflare, &e, retry_count, ip).await {
+                Some(delay) => {
+                    retry_count += 1;
+                    warn!(
+                        zone = %cloudflare.config.name,
+                        record_type = %record_type,
+                        error = %e,
+                        retry = retry_count,
+                        delay_ms = delay.as_millis() as u64,
+                        "Retrying zone record fetch after error"
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                None => {
+                    error!(
+                        zone = %cloudflare.config.name,
+                        record_type = %record_type,
+                        error = %e,
+                        "Failed to fetch zone records after {} retries",
+                        retry_count
+                    );
+                    cloudflare.metrics.record_failure(ip).await;
+                    return Err(e);
+                }
+            },
+        }
+    }
+}
+
 /// Updates DNS records for all configured subdomains.
 /// This function:
-/// - Verifies the zone is active
-/// - Processes each subdomain
+/// - Verifies the zone is active and its name matches the configured one;
+///   with `verify_zone` off only before the first update
+/// - Fetches the zone's records once and diffs each subdomain against them
+/// - Processes the subdomains concurrently, at most
+///   `MAX_CONCURRENT_SUBDOMAIN_UPDATES` at a time
 /// - Handles retries on failure
 /// - Provides detailed logging of the update process
+///
+/// The update runs in a `zone` span, nested in the cycle's span.
+#[instrument(name = "zone", skip_all, fields(zone = %cloudflare.config.name))]
 pub async fn update_dns_records(
     cloudflare: &Cloudflare,
     ip: &IpAddr,
 ) -> Result<(), CloudflareError> {
-    // First verify the zone is active
-    let zone_status = verify_zone_status(cloudflare).await?;
-    if !zone_status.result.status.eq_ignore_ascii_case("active") {
-        return Err(CloudflareError::InactiveZone(
-            cloudflare.config.name.clone(),
-            zone_status.result.status,
-        ));
+    // First verify the zone, or only once if the configuration opts out
+    // of checking before every update
+    if cloudflare.config.verify_zone {
+        verify_zone(cloudflare).await?;
+    } else {
+        cloudflare
+            .zone_verified
+            .get_or_try_init(|| verify_zone(cloudflare))
+            .await?;
     }
 
     let mut last_error: Option<CloudflareError> = None;
     let mut update_count = 0;
-    let mut retry_count = 0;
-    const MAX_RETRIES: u32 = 3;
 
-    let record_type = match ip {
+    let address_record_type = match ip {
         IpAddr::V4(_) => "A",
         IpAddr::V6(_) => "AAAA",
     };
 
-    for subdomain in &cloudflare.config.subdomains {
-        // Skip if this IP version is not enabled for this subdomain
-        match (ip, &subdomain.ip_version) {
-            (IpAddr::V4(_), super::types::IpVersion::V6)
-            | (IpAddr::V6(_), super::types::IpVersion::V4) => {
+    // One listing replaces a lookup per subdomain. CNAMEs don't depend on
+    // the IP and are synced with the static records instead
+    let subdomains: Vec<&CfSubDomain> = cloudflare
+        .config
+        .subdomains
+        .iter()
+        .filter(|subdomain| subdomain.record_type == CfRecordType::A)
+        .filter(|subdomain| {
+            if !subdomain.enabled {
                 debug!(
                     zone = %cloudflare.config.name,
                     subdomain = %subdomain.name,
-                    ip_type = %record_type,
-                    "Skipping DNS update - IP version not enabled for subdomain"
+                    "Skipping disabled subdomain"
                 );
-                continue;
             }
-            _ => {}
-        }
-
-        // Construct the full domain name for logging
-        let full_domain = if subdomain.name.is_empty() {
-            cloudflare.config.name.clone()
-        } else {
-            format!("{}.{}", subdomain.name, cloudflare.config.name)
-        };
-
-        info!(
-            zone = %cloudflare.config.name,
-            domain = %full_domain,
-            "Processing DNS records"
-        );
+            subdomain.enabled
+        })
+        .collect();
+    let address_records = if subdomains
+        .iter()
+        .any(|subdomain| subdomain.ip_version().includes(DetectedIpVersion::from(ip)))
+    {
+        load_zone_records(cloudflare, address_record_type, ip).await?
+    } else {
+        HashMap::new()
+    };
 
-        'retry: loop {
-            match process_domain_record(cloudflare, &full_domain, ip, record_type).await {
-                Ok(_) => {
-                    update_count += 1;
-                    break 'retry;
-                }
-                Err(e) => {
-                    if retry_count < MAX_RETRIES {
-                        retry_count += 1;
-                        warn!(
-                            zone = %cloudflare.config.name,
-                            domain = %full_domain,
-                            error = %e,
-                            retry = retry_count,
-                            "Retrying after error"
-                        );
-                        tokio::time::sleep(Duration::from_secs(2)).await;
-                        continue;
-                    }
-                    error!(
-                        zone = %cloudflare.config.name,
-                        domain = %full_domain,
-                        error = %e,
-                        "Failed after {} retries",
-                        MAX_RETRIES
-                    );
-                    last_error = Some(e);
-                    break 'retry;
-                }
-            }
+    // Subdomains are independent, so they are processed concurrently;
+    // the rate limiter remains the real throttle on API calls
+    let mut pending = subdomains.iter().copied();
+    let mut in_flight = FuturesUnordered::new();
+    for subdomain in pending.by_ref().take(MAX_CONCURRENT_SUBDOMAIN_UPDATES) {
+        in_flight.push(update_subdomain(
+            cloudflare,
+            subdomain,
+            ip,
+            &address_records,
+        ));
+    }
+    while let Some(result) = in_flight.next().await {
+        if let Some(subdomain) = pending.next() {
+            in_flight.push(update_subdomain(
+                cloudflare,
+                subdomain,
+                ip,
+                &address_records,
+            ));
+        }
+        match result {
+            Some(Ok(())) => update_count += 1,
+            Some(Err(e)) => last_error = Some(e),
+            None => {}
         }
     }
 
@@ -370,45 +496,437 @@ pub async fn update_dns_records(
     }
 }
 
-/// Process a single domain record - fetch, create if missing, or update if needed.
+/// Brings one subdomain's address record in line with `ip`, retrying on failure.
+/// `address_records` is the zone listing to diff against.
+/// Returns `None` if the subdomain does not use the IP version of `ip`.
+async fn update_subdomain(
+    cloudflare: &Cloudflare,
+    subdomain: &CfSubDomain,
+    ip: &IpAddr,
+    address_records: &HashMap<String, Vec<DnsResponseResult>>,
+) -> Option<Result<(), CloudflareError>> {
+    let record_type = match ip {
+        IpAddr::V4(_) => "A",
+        IpAddr::V6(_) => "AAAA",
+    };
+
+    // Construct the full domain name for logging
+    let full_domain = subdomain.fqdn(&cloudflare.config.name);
+
+    if !subdomain.ip_version().includes(DetectedIpVersion::from(ip)) {
+        debug!(
+            zone = %cloudflare.config.name,
+            subdomain = %subdomain.name,
+            ip_type = %record_type,
+            "Skipping DNS update - IP version not enabled for subdomain"
+        );
+        return None;
+    }
+    let content = subdomain.address_for(ip).to_string();
+
+    info!(
+        zone = %cloudflare.config.name,
+        domain = %full_domain,
+        record_type = %record_type,
+        "Processing DNS records"
+    );
+
+    let existing = address_records
+        .get(&full_domain.to_ascii_lowercase())
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    let mut retry_count = 0;
+    loop {
+        match process_domain_record(
+            cloudflare,
+            &full_domain,
+            existing,
+            &content,
+            record_type,
+            subdomain,
+            Some(ip),
+        )
+        .await
+        {
+            Ok(_) => return Some(Ok(())),
+            Err(e) => {
+                if let Some(delay) = retry_delay(cloudflare, &e, retry_count, ip).await {
+                    retry_count += 1;
+                    warn!(
+                        zone = %cloudflare.config.name,
+                        domain = %full_domain,
+                        error = %e,
+                        retry = retry_count,
+                        delay_ms = delay.as_millis() as u64,
+                        "Retrying after error"
+                    );
+                    tokio::time::sleep(delay).await;
+                    continue;
+                }
+                error!(
+                    zone = %cloudflare.config.name,
+                    domain = %full_domain,
+                    error = %e,
+                    "Failed after {} retries",
+                    retry_count
+                );
+                cloudflare.metrics.record_failure(ip).await;
+                return Some(Err(e));
+            }
+        }
+    }
+}
+
+/// Keeps every enabled CNAME subdomain pointing at its target.
+/// CNAMEs don't depend on the detected IP, so this runs once per cycle
+/// with the other static records, not once per IP version.
+/// In dry-run mode changes are only logged.
+#[instrument(name = "zone", skip_all, fields(zone = %cloudflare.config.name))]
+pub async fn sync_cname_records(cloudflare: &Cloudflare) -> Result<(), CloudflareError> {
+    let cnames: Vec<&CfSubDomain> = cloudflare
+        .config
+        .subdomains
+        .iter()
+        .filter(|subdomain| subdomain.enabled && subdomain.record_type == CfRecordType::Cname)
+        .collect();
+    if cnames.is_empty() {
+        return Ok(());
+    }
+
+    let cname_records = fetch_zone_records(cloudflare, "CNAME").await?;
+    let mut last_error: Option<CloudflareError> = None;
+    for subdomain in cnames {
+        let full_domain = subdomain.fqdn(&cloudflare.config.name);
+        let existing = cname_records
+            .get(&full_domain.to_ascii_lowercase())
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        if let Err(e) = process_domain_record(
+            cloudflare,
+            &full_domain,
+            existing,
+            subdomain.cname_target(&cloudflare.config.name),
+            "CNAME",
+            subdomain,
+            None,
+        )
+        .await
+        {
+            error!(
+                zone = %cloudflare.config.name,
+                domain = %full_domain,
+                error = %e,
+                "Failed to sync CNAME record"
+            );
+            last_error = Some(e);
+        }
+    }
+
+    match last_error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+/// Returns the fully qualified name of a record named `name` in `zone_name`.
+/// An empty name is the zone root.
+pub fn record_fqdn(name: &str, zone_name: &str) -> String {
+    if name.is_empty() {
+        zone_name.to_string()
+    } else {
+        format!("{}.{}", name, zone_name)
+    }
+}
+
+/// Decides how to bring `value` into the TXT records at one name.
+/// `configured` holds every value configured for that name, `value` included.
+/// Only records carrying `record_comment` are ever rewritten, and never one
+/// holding another configured value, so unrelated records such as SPF stay
+/// untouched and values sharing a name don't overwrite each other.
+fn plan_txt_sync<'a>(
+    existing: &'a [DnsResponseResult],
+    value: &str,
+    configured: &[&str],
+    record_comment: Option<&str>,
+) -> TxtSync<'a> {
+    // Cloudflare may return TXT content wrapped in quotes
+    let content = |record: &DnsResponseResult| record.content.trim_matches('"').to_string();
+    if existing.iter().any(|record| content(record) == value) {
+        return TxtSync::Present;
+    }
+
+    let ours = |record: &&DnsResponseResult| {
+        record_comment.is_some() && record.comment.as_deref().map(str::trim) == record_comment
+    };
+    existing
+        .iter()
+        .filter(ours)
+        .find(|record| !configured.contains(&content(record).as_str()))
+        .map_or(TxtSync::Create, TxtSync::Update)
+}
+
+/// Ensures every configured TXT record exists with its configured value.
+/// This runs every cycle, independent of IP changes:
+/// - Lists the zone's TXT records once
+/// - Leaves a name alone if one of its records already holds the value
+/// - Otherwise rewrites a record this client created that holds no
+///   configured value, e.g. after the value changed, or creates one
+///
+/// In dry-run mode changes are only logged.
+#[instrument(name = "zone", skip_all, fields(zone = %cloudflare.config.name))]
+pub async fn sync_txt_records(cloudflare: &Cloudflare) -> Result<(), CloudflareError> {
+    if cloudflare.config.txt_records.is_empty() {
+        return Ok(());
+    }
+
+    let mut txt_records = fetch_zone_records(cloudflare, "TXT").await?;
+    for txt_record in &cloudflare.config.txt_records {
+        let full_domain = txt_record.fqdn(&cloudflare.config.name);
+        let key = full_domain.to_ascii_lowercase();
+        let configured: Vec<&str> = cloudflare
+            .config
+            .txt_records
+            .iter()
+            .filter(|other| {
+                other
+                    .fqdn(&cloudflare.config.name)
+                    .eq_ignore_ascii_case(&key)
+            })
+            .map(|other| other.value.as_str())
+            .collect();
+        let existing = txt_records.get(&key).map(Vec::as_slice).unwrap_or_default();
+
+        let updated_id = match plan_txt_sync(
+            existing,
+            &txt_record.value,
+            &configured,
+            cloudflare.record_comment.as_deref(),
+        ) {
+            TxtSync::Present => {
+                debug!(
+                    zone = %cloudflare.config.name,
+                    domain = %full_domain,
+                    "TXT record already set"
+                );
+                continue;
+            }
+            TxtSync::Update(record) if cloudflare.dry_run => {
+                info!(
+                    zone = %cloudflare.config.name,
+                    domain = %full_domain,
+                    "Would update TXT record from {} to {}",
+                    record.content,
+                    txt_record.value
+                );
+                record.id.clone()
+            }
+            TxtSync::Update(record) => {
+                info!(
+                    zone = %cloudflare.config.name,
+                    domain = %full_domain,
+                    "Updating TXT record from {} to {}",
+                    record.content,
+                    txt_record.value
+                );
+                cloudflare
+                    .with_rate_limit(update_record(
+                        cloudflare,
+                        &record.id,
+                        &txt_record.value,
+                        "TXT",
+                        CLOUDFLARE_AUTO_TTL,
+                        false,
+                    ))
+                    .await?;
+                record.id.clone()
+            }
+            TxtSync::Create if cloudflare.dry_run => {
+                info!(
+                    zone = %cloudflare.config.name,
+                    domain = %full_domain,
+                    "Would create TXT record with {}",
+                    txt_record.value
+                );
+                continue;
+            }
+            TxtSync::Create => {
+                match cloudflare
+                    .with_rate_limit(create_dns_record(
+                        cloudflare,
+                        &full_domain,
+                        &txt_record.value,
+                        "TXT",
+                        CLOUDFLARE_AUTO_TTL,
+                        false,
+                    ))
+                    .await
+                {
+                    // An identical record was created concurrently
+                    Ok(_) | Err(CloudflareError::RecordExists { .. }) => {}
+                    Err(e) => return Err(e),
+                }
+                continue;
+            }
+        };
+
+        // Later values at this name must see the rewritten record as taken
+        if let Some(record) = txt_records
+            .get_mut(&key)
+            .and_then(|records| records.iter_mut().find(|record| record.id == updated_id))
+        {
+            record.content = txt_record.value.clone();
+        }
+    }
+
+    Ok(())
+}
+
+/// Process a single domain record - create if missing, or update if needed.
 /// This function handles the core logic for managing a single domain's DNS records:
-/// - Fetches current records
+/// - Compares against `existing`, the domain's records from the zone listing
 /// - Creates new records if none exist
-/// - Updates records if IP has changed
+/// - Falls back to updating when the record was created concurrently,
+///   e.g. by a second instance of this client
+/// - Updates records if their content has changed, unless the subdomain is
+///   create-only, in which case existing records are left untouched
 /// - Handles rate limiting through the with_rate_limit wrapper
+///
+/// `content` is the IP address or, for CNAME records, the target hostname.
+/// `ip` is the address that triggered the update and is used for metrics;
+/// CNAMEs, which no address triggers, pass `None`.
+/// In dry-run mode changes are only logged.
 async fn process_domain_record(
     cloudflare: &Cloudflare,
     full_domain: &str,
-    ip: &IpAddr,
+    existing: &[DnsResponseResult],
+    content: &str,
     record_type: &str,
+    subdomain: &CfSubDomain,
+    ip: Option<&IpAddr>,
 ) -> Result<(), CloudflareError> {
-    let records = cloudflare
-        .with_rate_limit(fetch_dns_records(cloudflare, full_domain, record_type))
-        .await?;
+    let ttl = subdomain.ttl();
+    let proxied = subdomain.proxied();
+    let create_only = subdomain.manage_mode == CfManageMode::CreateOnly;
+    let refetched;
+    let existing = if existing.is_empty() {
+        if cloudflare.dry_run {
+            info!(
+                zone = %cloudflare.config.name,
+                domain = %full_domain,
+                "Would create {} record with {}",
+                record_type,
+                content
+            );
+            return Ok(());
+        }
 
-    if records.result.is_empty() {
         warn!(
             zone = %cloudflare.config.name,
             domain = %full_domain,
             "No DNS records found, attempting to create"
         );
-        return cloudflare
-            .with_rate_limit(create_dns_record(cloudflare, full_domain, ip, record_type))
-            .await;
-    }
+        match cloudflare
+            .with_rate_limit(create_dns_record(
+                cloudflare,
+                full_domain,
+                content,
+                record_type,
+                ttl,
+                proxied,
+            ))
+            .await
+        {
+            Ok(_) => {
+                if let Some(ip) = ip {
+                    cloudflare.metrics.record_success(ip).await;
+                }
+                return Ok(());
+            }
+            Err(CloudflareError::RecordExists { .. }) if create_only => {
+                debug!(
+                    zone = %cloudflare.config.name,
+                    domain = %full_domain,
+                    "Record was created concurrently, leaving it untouched (create-only)"
+                );
+                return Ok(());
+            }
+            Err(e @ CloudflareError::RecordExists { .. }) => {
+                // Fall through to the update path with the record created meanwhile
+                warn!(
+                    zone = %cloudflare.config.name,
+                    domain = %full_domain,
+                    "Record was created concurrently, updating it instead"
+                );
+                let records = cloudflare
+                    .with_rate_limit(fetch_dns_records(cloudflare, full_domain, record_type))
+                    .await?
+                    .result;
+                if records.is_empty() {
+                    return Err(e);
+                }
+                refetched = records;
+                &refetched
+            }
+            Err(e) => return Err(e),
+        }
+    } else if create_only {
+        debug!(
+            zone = %cloudflare.config.name,
+            domain = %full_domain,
+            "{} record exists, leaving it untouched (create-only)",
+            record_type
+        );
+        return Ok(());
+    } else {
+        existing
+    };
+
+    for record in existing {
+        // Cloudflare may return hostnames with a trailing dot or different casing
+        if !record_content_matches(&record.content, content) {
+            if cloudflare.respect_foreign_records && cloudflare.is_foreign_record(record) {
+                warn!(
+                    zone = %cloudflare.config.name,
+                    domain = %full_domain,
+                    comment = record.comment.as_deref().unwrap_or_default(),
+                    "Leaving {} record managed by another owner at {}",
+                    record_type,
+                    record.content
+                );
+                continue;
+            }
+
+            if cloudflare.dry_run {
+                info!(
+                    zone = %cloudflare.config.name,
+                    domain = %full_domain,
+                    "Would update {} from {} to {}",
+                    full_domain,
+                    record.content,
+                    content
+                );
+                continue;
+            }
 
-    for record in records.result {
-        if record.content != ip.to_string() {
             info!(
                 zone = %cloudflare.config.name,
                 domain = %full_domain,
                 "Updating DNS record from {} to {}",
                 record.content,
-                ip
+                content
             );
 
             match cloudflare
-                .with_rate_limit(update_record(cloudflare, &record.id, ip, record_type))
+                .with_rate_limit(update_record(
+                    cloudflare,
+                    &record.id,
+                    content,
+                    record_type,
+                    ttl,
+                    proxied,
+                ))
                 .await
             {
                 Ok(_) => {
@@ -416,8 +934,11 @@ async fn process_domain_record(
                         zone = %cloudflare.config.name,
                         domain = %full_domain,
                         "Successfully updated DNS record to {}",
-                        ip
+                        content
                     );
+                    if let Some(ip) = ip {
+                        cloudflare.metrics.record_success(ip).await;
+                    }
                 }
                 Err(e) => {
                     error!(
@@ -434,45 +955,82 @@ async fn process_domain_record(
                 zone = %cloudflare.config.name,
                 domain = %full_domain,
                 "DNS record already set to {}",
-                ip
+                content
             );
+            if let Some(ip) = ip {
+                cloudflare.metrics.record_already_up_to_date(ip).await;
+            }
         }
     }
 
     Ok(())
 }
 
-/// Creates a new DNS record with the specified IP address.
-/// This function handles the creation of new A or AAAA records in Cloudflare,
+/// Whether records of this type can be sent through the Cloudflare proxy.
+/// TXT records cannot be proxied, whatever the configuration asks for.
+fn is_proxiable(record_type: &str) -> bool {
+    record_type != "TXT"
+}
+
+/// Whether an error response body reports that the record already exists.
+fn is_record_exists_error(error_body: &str) -> bool {
+    serde_json::from_str::<ApiErrorResponse>(error_body)
+        .map(|body| {
+            body.errors
+                .iter()
+                .any(|error| error.code == CLOUDFLARE_RECORD_EXISTS_CODE)
+        })
+        .unwrap_or(false)
+}
+
+/// Adds the configured record comment to a create or update request body.
+fn record_body(cloudflare: &Cloudflare, mut body: Value) -> Value {
+    if let Some(comment) = &cloudflare.record_comment {
+        body["comment"] = Value::from(comment.as_str());
+    }
+    body
+}
+
+/// Creates a new DNS record with the specified content.
+/// This function handles the creation of new A, AAAA, CNAME or TXT records in Cloudflare,
 /// including proper error handling and validation.
+/// Returns the ID Cloudflare assigned to the new record.
 async fn create_dns_record(
     cloudflare: &Cloudflare,
     domain: &str,
-    ip: &IpAddr,
+    content: &str,
     record_type: &str,
-) -> Result<(), CloudflareError> {
+    ttl: u32,
+    proxied: bool,
+) -> Result<String, CloudflareError> {
     info!(
         zone = %cloudflare.config.name,
         domain = %domain,
-        "Creating new DNS record with IP {}",
-        ip
+        "Creating new {} record with {}",
+        record_type,
+        content
     );
 
+    let zone_id = cloudflare.zone_id().await?;
     let url = format!(
         "{}/zones/{}/dns_records",
-        CLOUDFLARE_API_BASE, cloudflare.config.zone_id
+        cloudflare.config.api_base(),
+        zone_id
     );
 
     let response = cloudflare
         .client
         .post(&url)
-        .json(&json!({
-            "type": record_type,
-            "name": domain,
-            "content": ip.to_string(),
-            "proxied": true,
-            "ttl": 1, // Auto TTL
-        }))
+        .json(&record_body(
+            cloudflare,
+            json!({
+                "type": record_type,
+                "name": domain,
+                "content": content,
+                "proxied": proxied && is_proxiable(record_type),
+                "ttl": ttl,
+            }),
+        ))
         .send()
         .await
         .map_err(|e| CloudflareError::CreateFailed {
@@ -488,11 +1046,23 @@ async fn create_dns_record(
         ));
     }
 
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return Err(rate_limited_error(cloudflare, &response).await);
+    }
+
     if !status.is_success() {
         let error_body = response
             .text()
             .await
             .unwrap_or_else(|_| "Unknown error".to_string());
+        // Another instance or a manual edit created the record since it was fetched
+        if is_record_exists_error(&error_body) {
+            return Err(CloudflareError::RecordExists {
+                zone: cloudflare.config.name.clone(),
+                domain: domain.to_string(),
+                record_type: record_type.to_string(),
+            });
+        }
         return Err(CloudflareError::CreateFailed {
             zone: cloudflare.config.name.clone(),
             domain: domain.to_string(),
@@ -500,36 +1070,54 @@ async fn create_dns_record(
         });
     }
 
+    let created = response.json::<CreateRecordResponse>().await.map_err(|e| {
+        CloudflareError::CreateFailed {
+            zone: cloudflare.config.name.clone(),
+            domain: domain.to_string(),
+            message: format!("Failed to parse create response: {}", e),
+        }
+    })?;
+
     info!(
         zone = %cloudflare.config.name,
         domain = %domain,
+        record_id = %created.result.id,
         "Successfully created DNS record"
     );
-    Ok(())
+    Ok(created.result.id)
 }
 
-/// Updates a specific DNS record with a new IP address.
-/// This function updates an existing A or AAAA record with a new IP address,
-/// handling all necessary API interactions and error cases.
+/// Updates a specific DNS record with new content.
+/// This function updates an existing A, AAAA, CNAME or TXT record with a new IP address,
+/// target or text, handling all necessary API interactions and error cases.
 async fn update_record(
     cloudflare: &Cloudflare,
     record_id: &str,
-    ip: &IpAddr,
+    content: &str,
     record_type: &str,
+    ttl: u32,
+    proxied: bool,
 ) -> Result<(), CloudflareError> {
+    let zone_id = cloudflare.zone_id().await?;
     let url = format!(
         "{}/zones/{}/dns_records/{}",
-        CLOUDFLARE_API_BASE, cloudflare.config.zone_id, record_id
+        cloudflare.config.api_base(),
+        zone_id,
+        record_id
     );
 
     let response = cloudflare
         .client
         .patch(&url)
-        .json(&json!({
-            "type": record_type,
-            "content": ip.to_string(),
-            "proxied": true
-        }))
+        .json(&record_body(
+            cloudflare,
+            json!({
+                "type": record_type,
+                "content": content,
+                "proxied": proxied && is_proxiable(record_type),
+                "ttl": ttl,
+            }),
+        ))
         .send()
         .await
         .map_err(|e| CloudflareError::UpdateFailed {
@@ -544,6 +1132,10 @@ async fn update_record(
         ));
     }
 
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return Err(rate_limited_error(cloudflare, &response).await);
+    }
+
     if !status.is_success() {
         return Err(CloudflareError::UpdateFailed {
             zone: cloudflare.config.name.clone(),
@@ -554,14 +1146,131 @@ async fn update_record(
     Ok(())
 }
 
+/// Deletes a DNS record by its ID.
+/// Used by the `cleanup` command; the update loop never deletes records.
+pub async fn delete_dns_record(
+    cloudflare: &Cloudflare,
+    record_id: &str,
+) -> Result<(), CloudflareError> {
+    let zone_id = cloudflare.zone_id().await?;
+    let url = format!(
+        "{}/zones/{}/dns_records/{}",
+        cloudflare.config.api_base(),
+        zone_id,
+        record_id
+    );
+
+    let response =
+        cloudflare
+            .client
+            .delete(&url)
+            .send()
+            .await
+            .map_err(|e| CloudflareError::DeleteFailed {
+                zone: cloudflare.config.name.clone(),
+                message: format!("Failed to send delete request: {}", e),
+            })?;
+
+    let status = response.status();
+    if status == StatusCode::UNAUTHORIZED {
+        return Err(CloudflareError::InvalidApiToken(
+            cloudflare.config.name.clone(),
+        ));
+    }
+
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return Err(rate_limited_error(cloudflare, &response).await);
+    }
+
+    if !status.is_success() {
+        return Err(CloudflareError::DeleteFailed {
+            zone: cloudflare.config.name.clone(),
+            message: format!("HTTP {}", status),
+        });
+    }
+
+    Ok(())
+}
+
+/// Looks up the ID of the zone named in the configuration, for zones
+/// configured without a `zone_id`.
+/// Fails with [`CloudflareError::ZoneNotFound`] if the credentials can't
+/// see a zone of that name.
+pub async fn lookup_zone_id(cloudflare: &Cloudflare) -> Result<String, CloudflareError> {
+    let name = cloudflare.config.name.trim().trim_end_matches('.');
+    let url = format!("{}/zones?name={}", cloudflare.config.api_base(), name);
+
+    let response =
+        cloudflare
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| CloudflareError::FetchFailed {
+                zone: cloudflare.config.name.clone(),
+                message: format!("Failed to look up zone ID: {}", e),
+            })?;
+
+    let status = response.status();
+    if status == StatusCode::UNAUTHORIZED {
+        return Err(CloudflareError::InvalidApiToken(
+            cloudflare.config.name.clone(),
+        ));
+    }
+
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return Err(rate_limited_error(cloudflare, &response).await);
+    }
+
+    if !status.is_success() {
+        return Err(CloudflareError::FetchFailed {
+            zone: cloudflare.config.name.clone(),
+            message: format!("HTTP {}", status),
+        });
+    }
+
+    let zones =
+        response
+            .json::<ZoneListResponse>()
+            .await
+            .map_err(|e| CloudflareError::FetchFailed {
+                zone: cloudflare.config.name.clone(),
+                message: format!("Failed to parse zone list response: {}", e),
+            })?;
+
+    let zone = zones
+        .result
+        .into_iter()
+        .find(|zone| zone.name.trim_end_matches('.').eq_ignore_ascii_case(name))
+        .ok_or_else(|| CloudflareError::ZoneNotFound(cloudflare.config.name.clone()))?;
+    info!(
+        zone = %cloudflare.config.name,
+        zone_id = %zone.id,
+        "Looked up zone ID by name"
+    );
+    Ok(zone.id)
+}
+
+/// Fails unless the zone is active and is the configured one.
+async fn verify_zone(cloudflare: &Cloudflare) -> Result<(), CloudflareError> {
+    let zone_status = verify_zone_status(cloudflare).await?;
+    if !zone_status.result.status.eq_ignore_ascii_case("active") {
+        return Err(CloudflareError::InactiveZone(
+            cloudflare.config.name.clone(),
+            zone_status.result.status,
+        ));
+    }
+    Ok(())
+}
+
 /// Verifies that the zone is active.
 /// This function checks if the Cloudflare zone is active and available
-/// for DNS record management.
+/// for DNS record management. The zone's name must match the configured
+/// domain, so a zone_id copied from another zone is caught before any
+/// record is created in it.
 async fn verify_zone_status(cloudflare: &Cloudflare) -> Result<ZoneResponse, CloudflareError> {
-    let url = format!(
-        "{}/zones/{}",
-        CLOUDFLARE_API_BASE, cloudflare.config.zone_id
-    );
+    let zone_id = cloudflare.zone_id().await?;
+    let url = format!("{}/zones/{}", cloudflare.config.api_base(), zone_id);
 
     let response =
         cloudflare
@@ -581,6 +1290,10 @@ async fn verify_zone_status(cloudflare: &Cloudflare) -> Result<ZoneResponse, Clo
         ));
     }
 
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return Err(rate_limited_error(cloudflare, &response).await);
+    }
+
     if !status.is_success() {
         return Err(CloudflareError::FetchFailed {
             zone: cloudflare.config.name.clone(),
@@ -588,11 +1301,107 @@ async fn verify_zone_status(cloudflare: &Cloudflare) -> Result<ZoneResponse, Clo
         });
     }
 
-    response
+    let zone = response
         .json::<ZoneResponse>()
         .await
         .map_err(|e| CloudflareError::FetchFailed {
             zone: cloudflare.config.name.clone(),
             message: format!("Failed to parse zone response: {}", e),
-        })
+        })?;
+
+    let zone_name = zone.result.name.trim_end_matches('.');
+    if !zone_name.eq_ignore_ascii_case(cloudflare.config.name.trim_end_matches('.')) {
+        error!(
+            zone = %cloudflare.config.name,
+            actual = %zone_name,
+            "Configured zone_id belongs to another zone"
+        );
+        return Err(CloudflareError::ZoneNameMismatch {
+            zone: cloudflare.config.name.clone(),
+            actual: zone_name.to_string(),
+        });
+    }
+
+    Ok(zone)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn txt(id: &str, content: &str, comment: Option<&str>) -> DnsResponseResult {
+        DnsResponseResult {
+            id: id.to_string(),
+            name: "example.com".to_string(),
+            content: format!("\"{}\"", content),
+            comment: comment.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn txt_sync_creates_next_to_unrelated_records() {
+        let existing = [txt("spf", "v=spf1 -all", None)];
+        assert!(matches!(
+            plan_txt_sync(&existing, "token", &["token"], Some("ddns")),
+            TxtSync::Create
+        ));
+    }
+
+    #[test]
+    fn txt_sync_rewrites_only_its_own_stale_record() {
+        let existing = [
+            txt("spf", "v=spf1 -all", Some("someone else")),
+            txt("old", "old-token", Some("ddns")),
+        ];
+        assert!(matches!(
+            plan_txt_sync(&existing, "token", &["token"], Some("ddns")),
+            TxtSync::Update(record) if record.id == "old"
+        ));
+        assert!(matches!(
+            plan_txt_sync(&existing, "token", &["token"], None),
+            TxtSync::Create
+        ));
+    }
+
+    #[test]
+    fn txt_sync_keeps_two_values_on_one_name() {
+        let configured = ["first", "second"];
+
+        // Neither value overwrites the other
+        let existing = [txt("b", "second", Some("ddns"))];
+        assert!(matches!(
+            plan_txt_sync(&existing, "first", &configured, Some("ddns")),
+            TxtSync::Create
+        ));
+        assert!(matches!(
+            plan_txt_sync(&existing, "second", &configured, Some("ddns")),
+            TxtSync::Present
+        ));
+
+        // Once both exist the sync is stable
+        let existing = [
+            txt("a", "first", Some("ddns")),
+            txt("b", "second", Some("ddns")),
+        ];
+        for value in configured {
+            assert!(matches!(
+                plan_txt_sync(&existing, value, &configured, Some("ddns")),
+                TxtSync::Present
+            ));
+        }
+    }
+
+    #[test]
+    fn record_exists_error_is_recognized() {
+        // Body of the 400 response to creating a record that already exists
+        let body = r#"{"result":null,"success":false,"errors":[{"code":81057,"message":"Record already exists."}],"messages":[]}"#;
+        assert!(is_record_exists_error(body));
+    }
+
+    #[test]
+    fn other_errors_are_not_record_exists() {
+        let body = r#"{"result":null,"success":false,"errors":[{"code":9005,"message":"Content for A record is invalid."}],"messages":[]}"#;
+        assert!(!is_record_exists_error(body));
+        assert!(!is_record_exists_error("Bad Request"));
+    }
 }
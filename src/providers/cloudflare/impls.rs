@@ -3,18 +3,35 @@ use std::fmt;
 use std::future::Future;
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::sync::Arc;
+use std::time::Duration;
 
 // 3rd party crates
 use async_trait::async_trait;
+use reqwest::Url;
+use tokio::sync::OnceCell;
 
 // Project modules
+use crate::metrics::types::MetricsManager;
 use crate::providers::traits::DnsProvider;
-use crate::utility::rate_limiter::types::TokenBucketRateLimiter;
+use crate::providers::types::RetryPolicy;
+use crate::settings::types::HttpClientOptions;
+use crate::utility::ip_detector::types::IpVersion as DetectedIpVersion;
+use crate::utility::rate_limiter::types::{RateLimitStatus, TokenBucketRateLimiter};
 
 // Current module imports
+use super::constants::{
+    CLOUDFLARE_API_BASE, CLOUDFLARE_AUTO_TTL, CLOUDFLARE_MAX_TTL, CLOUDFLARE_MAX_TXT_LENGTH,
+    CLOUDFLARE_MIN_TTL, DEFAULT_IPV6_PREFIX_LENGTH,
+};
 use super::errors::{CloudflareError, CloudflareValidationError};
-use super::functions::{create_reqwest_client, update_dns_records};
-use super::types::{CfConfig, Cloudflare, IpVersion};
+use super::functions::{
+    create_reqwest_client, fetch_current_records, lookup_zone_id, record_fqdn, sync_cname_records,
+    sync_txt_records, update_dns_records,
+};
+use super::types::{
+    CfAuth, CfConfig, CfRecordType, CfSubDomain, CfTxtRecord, Cloudflare, DnsResponseResult,
+    IpVersion,
+};
 
 // Manual Debug implementation for Cloudflare
 impl fmt::Debug for Cloudflare {
@@ -23,6 +40,13 @@ impl fmt::Debug for Cloudflare {
             .field("config", &self.config)
             .field("client", &self.client)
             .field("rate_limiter", &"<rate limiter>")
+            .field("metrics", &self.metrics)
+            .field("dry_run", &self.dry_run)
+            .field("retry_policy", &self.retry_policy)
+            .field("record_comment", &self.record_comment)
+            .field("respect_foreign_records", &self.respect_foreign_records)
+            .field("resolved_zone_id", &self.resolved_zone_id)
+            .field("zone_verified", &self.zone_verified)
             .finish()
     }
 }
@@ -34,6 +58,13 @@ impl Clone for Cloudflare {
             config: self.config.clone(),
             client: self.client.clone(),
             rate_limiter: Arc::clone(&self.rate_limiter),
+            metrics: Arc::clone(&self.metrics),
+            dry_run: self.dry_run,
+            retry_policy: self.retry_policy,
+            record_comment: self.record_comment.clone(),
+            respect_foreign_records: self.respect_foreign_records,
+            resolved_zone_id: Arc::clone(&self.resolved_zone_id),
+            zone_verified: Arc::clone(&self.zone_verified),
         }
     }
 }
@@ -42,16 +73,85 @@ impl Cloudflare {
     /// Creates a new Cloudflare instance with the provided configuration.
     /// This will initialize the HTTP client and rate limiter.
     pub fn new(config: CfConfig) -> Result<Self, CloudflareError> {
-        let client = create_reqwest_client(&config)?;
+        let client = create_reqwest_client(&config, &HttpClientOptions::default())?;
         let rate_limiter = Arc::new(TokenBucketRateLimiter::new(config.rate_limit.clone()));
 
         Ok(Self {
             config,
             client,
             rate_limiter,
+            metrics: Arc::new(MetricsManager::new()),
+            dry_run: false,
+            retry_policy: RetryPolicy::default(),
+            record_comment: None,
+            respect_foreign_records: false,
+            resolved_zone_id: Arc::new(OnceCell::new()),
+            zone_verified: Arc::new(OnceCell::new()),
         })
     }
 
+    /// Rebuilds the HTTP client with the given User-Agent and proxy.
+    pub fn with_http_options(
+        mut self,
+        options: &HttpClientOptions,
+    ) -> Result<Self, CloudflareError> {
+        self.client = create_reqwest_client(&self.config, options)?;
+        Ok(self)
+    }
+
+    /// Replaces the metrics collector, so several instances can share one.
+    pub fn with_metrics(mut self, metrics: Arc<MetricsManager>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Enables or disables dry-run mode.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Replaces the policy for retrying failed record updates.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Sets the comment attached to created and updated records; empty disables it.
+    pub fn with_record_comment(mut self, comment: &str) -> Self {
+        self.record_comment = (!comment.is_empty()).then(|| comment.to_string());
+        self
+    }
+
+    /// Enables or disables protection of records commented by another owner.
+    pub fn with_respect_foreign_records(mut self, respect: bool) -> Self {
+        self.respect_foreign_records = respect;
+        self
+    }
+
+    /// Returns the configured zone ID, or looks it up by the zone name on
+    /// first use. The looked up ID is kept for the lifetime of this instance.
+    /// Callers already hold a rate limit permit for the request that needs
+    /// the ID, so the lookup doesn't acquire another one.
+    pub async fn zone_id(&self) -> Result<&str, CloudflareError> {
+        let configured = self.config.zone_id.trim();
+        if !configured.is_empty() {
+            return Ok(configured);
+        }
+        self.resolved_zone_id
+            .get_or_try_init(|| lookup_zone_id(self))
+            .await
+            .map(String::as_str)
+    }
+
+    /// Whether `record` carries a comment other than the one this client sets.
+    pub fn is_foreign_record(&self, record: &DnsResponseResult) -> bool {
+        match record.comment.as_deref().map(str::trim) {
+            None | Some("") => false,
+            Some(comment) => self.record_comment.as_deref() != Some(comment),
+        }
+    }
+
     /// Acquires a rate limit permit before making an API call.
     /// This ensures we respect Cloudflare's API rate limits.
     pub async fn with_rate_limit<F, T, E>(&self, f: F) -> Result<T, E>
@@ -63,26 +163,164 @@ impl Cloudflare {
             return Err(CloudflareError::RateLimited(self.config.name.clone()).into());
         }
 
-        let result = f.await;
-        self.rate_limiter.release().await;
-        result
+        f.await
+    }
+}
+
+impl fmt::Display for IpVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            IpVersion::V4 => "v4",
+            IpVersion::V6 => "v6",
+            IpVersion::Both => "both",
+        })
+    }
+}
+
+impl IpVersion {
+    /// Checks whether this subdomain setting covers the given IP version.
+    pub fn includes(&self, version: DetectedIpVersion) -> bool {
+        matches!(
+            (self, version),
+            (IpVersion::Both, _)
+                | (IpVersion::V4, DetectedIpVersion::V4)
+                | (IpVersion::V6, DetectedIpVersion::V6)
+        )
+    }
+}
+
+impl CfSubDomain {
+    /// Returns the fully qualified record name in `zone_name`.
+    /// An empty name is the zone root. Wildcard names such as "*" or "*.dev"
+    /// are joined like any other, which is how Cloudflare lists them.
+    pub fn fqdn(&self, zone_name: &str) -> String {
+        record_fqdn(&self.name, zone_name)
+    }
+
+    /// Returns the TTL to send to Cloudflare, falling back to automatic.
+    pub fn ttl(&self) -> u32 {
+        self.ttl.unwrap_or(CLOUDFLARE_AUTO_TTL)
+    }
+
+    /// Returns the IP versions published for this subdomain, falling back to both.
+    pub fn ip_version(&self) -> IpVersion {
+        self.ip_version.unwrap_or_default()
+    }
+
+    /// Returns whether records are proxied through Cloudflare, falling back to true.
+    pub fn proxied(&self) -> bool {
+        self.proxied.unwrap_or(true)
+    }
+
+    /// Returns the hostname this CNAME points at, defaulting to the zone root.
+    pub fn cname_target<'a>(&'a self, zone_name: &'a str) -> &'a str {
+        self.target
+            .as_deref()
+            .map(|target| target.trim().trim_end_matches('.'))
+            .unwrap_or(zone_name)
+    }
+
+    /// Returns the address to publish for the detected IP.
+    /// With an `ipv6_suffix`, the leading `ipv6_prefix_length` bits of a
+    /// detected IPv6 address are kept and the rest are taken from the suffix.
+    pub fn address_for(&self, ip: &IpAddr) -> IpAddr {
+        let IpAddr::V6(detected) = ip else {
+            return *ip;
+        };
+        let Some(suffix) = self
+            .ipv6_suffix
+            .as_deref()
+            .and_then(|suffix| suffix.trim().parse::<Ipv6Addr>().ok())
+        else {
+            return *ip;
+        };
+
+        let prefix_length = u32::from(
+            self.ipv6_prefix_length
+                .unwrap_or(DEFAULT_IPV6_PREFIX_LENGTH),
+        );
+        let prefix_mask: u128 = u128::MAX.checked_shl(128 - prefix_length).unwrap_or(0);
+        IpAddr::V6(Ipv6Addr::from(
+            (u128::from(*detected) & prefix_mask) | (u128::from(suffix) & !prefix_mask),
+        ))
+    }
+
+    /// Checks whether this subdomain holds the detected IP for the given version.
+    /// Disabled subdomains need no IP.
+    pub fn needs_ip_version(&self, version: DetectedIpVersion) -> bool {
+        self.enabled && self.record_type == CfRecordType::A && self.ip_version().includes(version)
+    }
+}
+
+impl CfTxtRecord {
+    /// Returns the fully qualified record name in `zone_name`, like
+    /// [`CfSubDomain::fqdn`].
+    pub fn fqdn(&self, zone_name: &str) -> String {
+        record_fqdn(&self.name, zone_name)
     }
 }
 
 impl CfConfig {
-    pub fn validate(&self) -> Result<(), CloudflareValidationError> {
-        if self.zone_id.trim().is_empty() {
-            return Err(CloudflareValidationError::MissingZoneId);
+    /// Returns the configured credentials, requiring exactly one authentication method.
+    /// Empty values count as unset.
+    pub fn auth(&self) -> Result<CfAuth<'_>, CloudflareValidationError> {
+        fn non_empty(value: &Option<String>) -> Option<&str> {
+            value
+                .as_deref()
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
         }
 
-        if self.api_token.trim().is_empty() {
-            return Err(CloudflareValidationError::MissingApiToken);
+        match (
+            non_empty(&self.api_token),
+            non_empty(&self.api_key),
+            non_empty(&self.account_email),
+        ) {
+            (Some(_), Some(_), _) => Err(CloudflareValidationError::ConflictingAuth),
+            (Some(api_token), None, _) => Ok(CfAuth::ApiToken(api_token)),
+            (None, Some(api_key), Some(account_email)) => Ok(CfAuth::GlobalApiKey {
+                api_key,
+                account_email,
+            }),
+            (None, Some(_), None) => Err(CloudflareValidationError::MissingAccountEmail),
+            (None, None, _) => Err(CloudflareValidationError::MissingApiToken),
         }
+    }
+
+    /// Fills every setting a subdomain leaves unset from the zone's `defaults`.
+    /// Settings still unset afterwards use the built-in defaults.
+    pub fn apply_defaults(&mut self) {
+        for subdomain in &mut self.subdomains {
+            subdomain.ttl = subdomain.ttl.or(self.defaults.ttl);
+            subdomain.proxied = subdomain.proxied.or(self.defaults.proxied);
+            subdomain.ip_version = subdomain.ip_version.or(self.defaults.ip_version);
+        }
+    }
+
+    /// Returns the base URL API requests are sent to, without a trailing slash.
+    pub fn api_base(&self) -> &str {
+        self.api_base
+            .as_deref()
+            .map(|api_base| api_base.trim().trim_end_matches('/'))
+            .filter(|api_base| !api_base.is_empty())
+            .unwrap_or(CLOUDFLARE_API_BASE)
+    }
+
+    pub fn validate(&self) -> Result<(), CloudflareValidationError> {
+        self.auth()?;
 
         if self.name.trim().is_empty() {
             return Err(CloudflareValidationError::MissingName);
         }
 
+        if !Url::parse(self.api_base())
+            .is_ok_and(|url| matches!(url.scheme(), "http" | "https") && url.has_host())
+        {
+            return Err(CloudflareValidationError::InvalidApiBase(
+                self.api_base().to_string(),
+            ));
+        }
+
         if self.subdomains.is_empty() {
             return Err(CloudflareValidationError::NoSubdomains);
         }
@@ -103,8 +341,73 @@ impl CfConfig {
         // Validate subdomain configurations
         let mut has_ipv4 = false;
         let mut has_ipv6 = false;
+        let mut has_cname = false;
         for subdomain in &self.subdomains {
-            match subdomain.ip_version {
+            if let Some(ttl) = subdomain.ttl {
+                if ttl != CLOUDFLARE_AUTO_TTL
+                    && !(CLOUDFLARE_MIN_TTL..=CLOUDFLARE_MAX_TTL).contains(&ttl)
+                {
+                    return Err(CloudflareValidationError::InvalidTtl {
+                        subdomain: subdomain.name.clone(),
+                        reason: format!(
+                            "must be {} (automatic) or between {} and {}, got {}",
+                            CLOUDFLARE_AUTO_TTL, CLOUDFLARE_MIN_TTL, CLOUDFLARE_MAX_TTL, ttl
+                        ),
+                    });
+                }
+            }
+
+            if let Some(suffix) = &subdomain.ipv6_suffix {
+                if suffix.trim().parse::<Ipv6Addr>().is_err() {
+                    return Err(CloudflareValidationError::InvalidIpv6Suffix {
+                        subdomain: subdomain.name.clone(),
+                        reason: format!("'{}' is not an IPv6 address such as \"::1\"", suffix),
+                    });
+                }
+            }
+            if let Some(prefix_length) = subdomain.ipv6_prefix_length {
+                if !(1..=127).contains(&prefix_length) {
+                    return Err(CloudflareValidationError::InvalidIpv6Suffix {
+                        subdomain: subdomain.name.clone(),
+                        reason: format!(
+                            "ipv6_prefix_length must be between 1 and 127, got {}",
+                            prefix_length
+                        ),
+                    });
+                }
+            }
+
+            // A wildcard may only stand for the leftmost label, as in "*" or "*.dev"
+            let wildcard_label = subdomain.name == "*" || subdomain.name.starts_with("*.");
+            let wildcards = subdomain.name.matches('*').count();
+            if wildcards > 1 || (wildcards == 1 && !wildcard_label) {
+                return Err(CloudflareValidationError::InvalidSubdomainName {
+                    subdomain: subdomain.name.clone(),
+                    reason: "'*' is only allowed as the leftmost label".into(),
+                });
+            }
+
+            if subdomain.record_type == CfRecordType::Cname {
+                let full_domain = subdomain.fqdn(&self.name);
+                let target = subdomain.cname_target(&self.name);
+                if subdomain.name.trim().is_empty() {
+                    return Err(CloudflareValidationError::InvalidCname {
+                        subdomain: subdomain.name.clone(),
+                        reason: "the zone root cannot be a CNAME".into(),
+                    });
+                }
+                if target.is_empty() || target.eq_ignore_ascii_case(&full_domain) {
+                    return Err(CloudflareValidationError::InvalidCname {
+                        subdomain: subdomain.name.clone(),
+                        reason: format!("invalid target '{}'", target),
+                    });
+                }
+                // CNAMEs hold no IP address
+                has_cname |= subdomain.enabled;
+                continue;
+            }
+
+            match subdomain.ip_version() {
                 super::types::IpVersion::V4 => has_ipv4 = true,
                 super::types::IpVersion::V6 => has_ipv6 = true,
                 super::types::IpVersion::Both => {
@@ -114,10 +417,29 @@ impl CfConfig {
             }
         }
 
-        // Ensure at least one IP version is enabled
-        if !has_ipv4 && !has_ipv6 {
+        for txt_record in &self.txt_records {
+            if txt_record.value.is_empty() {
+                return Err(CloudflareValidationError::InvalidTxtRecord {
+                    name: txt_record.name.clone(),
+                    reason: "value must not be empty".into(),
+                });
+            }
+            if txt_record.value.len() > CLOUDFLARE_MAX_TXT_LENGTH {
+                return Err(CloudflareValidationError::InvalidTxtRecord {
+                    name: txt_record.name.clone(),
+                    reason: format!(
+                        "value is longer than {} characters",
+                        CLOUDFLARE_MAX_TXT_LENGTH
+                    ),
+                });
+            }
+        }
+
+        // Ensure some record is managed, an address record or a CNAME
+        if !has_ipv4 && !has_ipv6 && !has_cname {
             return Err(CloudflareValidationError::InvalidIpVersion(
-                "At least one subdomain must have IPv4 or IPv6 enabled".into(),
+                "At least one subdomain must have IPv4 or IPv6 enabled or be an enabled CNAME"
+                    .into(),
             ));
         }
 
@@ -125,12 +447,6 @@ impl CfConfig {
     }
 }
 
-impl Default for IpVersion {
-    fn default() -> Self {
-        Self::Both
-    }
-}
-
 #[async_trait]
 impl DnsProvider for Cloudflare {
     type Config = CfConfig;
@@ -146,26 +462,43 @@ impl DnsProvider for Cloudflare {
 
     async fn update_dns_records_v6(&self, ip: &Ipv6Addr) -> Result<(), Self::Error> {
         // Check if any subdomain needs IPv6
-        let needs_ipv6 = self
-            .config
-            .subdomains
-            .iter()
-            .any(|subdomain| matches!(subdomain.ip_version, IpVersion::V6 | IpVersion::Both));
-
-        if !needs_ipv6 {
+        if !self.needs_ip_version(DetectedIpVersion::V6) {
             return Ok(());
         }
         update_dns_records(self, &IpAddr::V6(*ip)).await
     }
 
+    fn needs_ip_version(&self, version: DetectedIpVersion) -> bool {
+        self.config
+            .subdomains
+            .iter()
+            .any(|subdomain| subdomain.needs_ip_version(version))
+    }
+
+    async fn get_current_records(&self) -> Result<Vec<(String, IpAddr)>, Self::Error> {
+        fetch_current_records(self).await
+    }
+
+    fn expected_records(&self, ip: &IpAddr) -> Vec<(String, IpAddr)> {
+        self.config
+            .subdomains
+            .iter()
+            .filter(|subdomain| subdomain.needs_ip_version(DetectedIpVersion::from(ip)))
+            .map(|subdomain| (subdomain.fqdn(&self.config.name), subdomain.address_for(ip)))
+            .collect()
+    }
+
+    async fn sync_static_records(&self) -> Result<(), Self::Error> {
+        // A failing CNAME doesn't hold back the TXT records
+        let cnames = sync_cname_records(self).await;
+        sync_txt_records(self).await.and(cnames)
+    }
+
     fn validate_config(&self) -> Result<(), Self::Error> {
         // Basic validation
-        if self.config.api_token.is_empty() || self.config.api_token == "your_api_token_here" {
+        if let CfAuth::ApiToken("your_api_token_here") = self.config.auth()? {
             return Err(CloudflareError::InvalidApiToken(self.config.name.clone()));
         }
-        if self.config.zone_id.is_empty() {
-            return Err(CloudflareError::InvalidZoneId(self.config.name.clone()));
-        }
         if self.config.subdomains.is_empty() {
             return Err(CloudflareError::NoSubdomains(self.config.name.clone()));
         }
@@ -187,6 +520,14 @@ impl DnsProvider for Cloudflare {
         Ok(())
     }
 
+    fn update_interval(&self) -> Option<Duration> {
+        self.config.interval.map(Duration::from_secs)
+    }
+
+    async fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        Some(self.rate_limiter.status().await)
+    }
+
     fn is_enabled(&self) -> bool {
         self.config.enabled
     }
@@ -195,3 +536,75 @@ impl DnsProvider for Cloudflare {
         &self.config.name
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn config(subdomain: &str) -> CfConfig {
+        serde_json::from_value(json!({
+            "enabled": true,
+            "name": "example.com",
+            "api_token": "token",
+            "subdomains": [{ "name": subdomain }],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn cname_only_zone_is_valid() {
+        let config: CfConfig = serde_json::from_value(json!({
+            "enabled": true,
+            "name": "example.com",
+            "api_token": "token",
+            "subdomains": [{ "name": "www", "record_type": "CNAME" }],
+        }))
+        .unwrap();
+        assert!(config.validate().is_ok());
+        assert!(!config.subdomains[0].needs_ip_version(DetectedIpVersion::V4));
+    }
+
+    #[test]
+    fn zone_with_only_disabled_cnames_is_rejected() {
+        let config: CfConfig = serde_json::from_value(json!({
+            "enabled": true,
+            "name": "example.com",
+            "api_token": "token",
+            "subdomains": [{ "name": "www", "record_type": "CNAME", "enabled": false }],
+        }))
+        .unwrap();
+        assert!(matches!(
+            config.validate(),
+            Err(CloudflareValidationError::InvalidIpVersion(_))
+        ));
+    }
+
+    #[test]
+    fn wildcard_names_are_joined_with_the_zone() {
+        let root = config("*");
+        assert_eq!(root.subdomains[0].fqdn(&root.name), "*.example.com");
+        let nested = config("*.dev");
+        assert_eq!(nested.subdomains[0].fqdn(&nested.name), "*.dev.example.com");
+    }
+
+    #[test]
+    fn wildcard_in_the_leftmost_label_is_valid() {
+        assert!(config("*").validate().is_ok());
+        assert!(config("*.dev").validate().is_ok());
+    }
+
+    #[test]
+    fn wildcard_elsewhere_is_rejected() {
+        for name in ["a*b", "*a", "dev.*", "*.*"] {
+            assert!(
+                matches!(
+                    config(name).validate(),
+                    Err(CloudflareValidationError::InvalidSubdomainName { .. })
+                ),
+                "{} should be rejected",
+                name
+            );
+        }
+    }
+}
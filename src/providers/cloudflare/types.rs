@@ -1,11 +1,15 @@
 // Standard library
+use std::path::PathBuf;
 use std::sync::Arc;
 
 // 3rd party crates
 use reqwest::Client;
 use serde::Deserialize;
+use tokio::sync::OnceCell;
 
 // Project modules
+use crate::metrics::types::MetricsManager;
+use crate::providers::types::RetryPolicy;
 use crate::utility::rate_limiter::traits::RateLimiter;
 use crate::utility::rate_limiter::types::RateLimitConfig;
 
@@ -17,10 +21,22 @@ use crate::utility::rate_limiter::types::RateLimitConfig;
 /// - Managing both IPv4 (A) and IPv6 (AAAA) records
 ///
 /// The client includes built-in rate limiting to respect Cloudflare's API limits.
+/// In dry-run mode records are still fetched, but never created or changed.
 pub struct Cloudflare {
     pub config: CfConfig,
     pub client: Client,
     pub rate_limiter: Arc<dyn RateLimiter>,
+    pub metrics: Arc<MetricsManager>,
+    pub dry_run: bool,
+    pub retry_policy: RetryPolicy,
+    /// Comment attached to created and updated records, if any
+    pub record_comment: Option<String>,
+    /// Whether records commented by another owner are left untouched
+    pub respect_foreign_records: bool,
+    /// Zone ID looked up by name on first use, when none is configured
+    pub resolved_zone_id: Arc<OnceCell<String>>,
+    /// Set once the zone has been verified, when `verify_zone` is off
+    pub zone_verified: Arc<OnceCell<()>>,
 }
 
 /// Configuration for Cloudflare API interactions.
@@ -30,17 +46,64 @@ pub struct Cloudflare {
 pub struct CfConfig {
     /// Whether this Cloudflare configuration is enabled
     pub enabled: bool,
+    /// Seconds between updates of this provider; defaults to `[update] interval`
+    #[serde(default)]
+    pub interval: Option<u64>,
     /// The domain name (e.g., "example.com")
     pub name: String,
-    /// The Cloudflare zone ID for the domain
+    /// The Cloudflare zone ID for the domain; looked up by `name` if empty
+    #[serde(default)]
     pub zone_id: String,
     /// The Cloudflare API token with appropriate permissions
-    pub api_token: String,
+    #[serde(default)]
+    pub api_token: Option<String>,
+    /// File holding the API token, e.g. a Docker secret; replaces `api_token`
+    #[serde(default)]
+    pub api_token_file: Option<PathBuf>,
+    /// Legacy Global API Key, used with `account_email` when there is no `api_token`
+    #[serde(default)]
+    pub api_key: Option<String>,
+    /// Account email for Global API Key authentication
+    #[serde(default)]
+    pub account_email: Option<String>,
+    /// Base URL of the Cloudflare API, e.g. for an API gateway or a mock server;
+    /// defaults to `CLOUDFLARE_API_BASE`
+    #[serde(default)]
+    pub api_base: Option<String>,
+    /// Check before every update that the zone is active and matches `name`;
+    /// turned off, the check is made once, before the first update
+    #[serde(default = "default_verify_zone")]
+    pub verify_zone: bool,
     /// Rate limiting configuration to respect Cloudflare's API limits
     #[serde(default = "default_rate_limit_config")]
     pub rate_limit: RateLimitConfig,
+    /// Settings applied to every subdomain that does not set them itself
+    #[serde(default)]
+    pub defaults: CfSubDomainDefaults,
     /// List of subdomains to manage
     pub subdomains: Vec<CfSubDomain>,
+    /// TXT records kept at a fixed value, independent of the detected IP
+    #[serde(default)]
+    pub txt_records: Vec<CfTxtRecord>,
+}
+
+/// Zone-wide defaults for the per-subdomain record settings.
+/// A value set on a subdomain always wins over the value set here.
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct CfSubDomainDefaults {
+    /// Default record TTL in seconds; 1 means automatic
+    #[serde(default)]
+    pub ttl: Option<u32>,
+    /// Default for whether records are proxied through Cloudflare
+    #[serde(default)]
+    pub proxied: Option<bool>,
+    /// Default IP versions to publish
+    #[serde(default)]
+    pub ip_version: Option<IpVersion>,
+}
+
+fn default_verify_zone() -> bool {
+    true
 }
 
 fn default_rate_limit_config() -> RateLimitConfig {
@@ -50,6 +113,18 @@ fn default_rate_limit_config() -> RateLimitConfig {
     }
 }
 
+/// Credentials used to authenticate against the Cloudflare API.
+#[derive(Debug, Clone, Copy)]
+pub enum CfAuth<'a> {
+    /// Scoped API token, sent as a bearer token
+    ApiToken(&'a str),
+    /// Legacy Global API Key, sent as `X-Auth-Key` and `X-Auth-Email`
+    GlobalApiKey {
+        api_key: &'a str,
+        account_email: &'a str,
+    },
+}
+
 /// Represents a subdomain configuration in Cloudflare.
 /// An empty name represents the root domain.
 #[derive(Debug, Deserialize, Clone)]
@@ -58,13 +133,90 @@ pub struct CfSubDomain {
     /// Leave empty for root domain
     #[serde(default)]
     pub name: String,
-    /// Which IP versions to use for this subdomain
+    /// Which IP versions to use for this subdomain; defaults to the zone's
+    /// `[cloudflare.defaults]`, then to both
     #[serde(default)]
-    pub ip_version: IpVersion,
+    pub ip_version: Option<IpVersion>,
+    /// Record TTL in seconds; omitted or 1 means automatic unless the zone's
+    /// `[cloudflare.defaults]` sets one
+    #[serde(default)]
+    pub ttl: Option<u32>,
+    /// Whether traffic is proxied through Cloudflare; defaults to the zone's
+    /// `[cloudflare.defaults]`, then to true
+    #[serde(default)]
+    pub proxied: Option<bool>,
+    /// Kind of record to manage: "A" (A/AAAA per `ip_version`) or "CNAME"
+    #[serde(default)]
+    pub record_type: CfRecordType,
+    /// CNAME target; defaults to the zone root
+    #[serde(default)]
+    pub target: Option<String>,
+    /// Host part (e.g. "::1") combined with the detected IPv6 prefix
+    /// instead of publishing the detected address itself
+    #[serde(default)]
+    pub ipv6_suffix: Option<String>,
+    /// Length of the detected prefix kept when `ipv6_suffix` is set; defaults to 64
+    #[serde(default)]
+    pub ipv6_prefix_length: Option<u8>,
+    /// Whether this subdomain is updated; disabled ones are kept in the file but skipped
+    #[serde(default = "default_subdomain_enabled")]
+    pub enabled: bool,
+    /// Whether existing records are kept in sync or only missing ones are created
+    #[serde(default)]
+    pub manage_mode: CfManageMode,
 }
 
-/// Specifies which IP versions should be used for a subdomain
+fn default_subdomain_enabled() -> bool {
+    true
+}
+
+/// A TXT record whose content is kept at the configured value.
+/// An empty name represents the root domain.
 #[derive(Debug, Deserialize, Clone)]
+pub struct CfTxtRecord {
+    /// The record name relative to the zone (e.g., "_acme-challenge")
+    #[serde(default)]
+    pub name: String,
+    /// The content the record must hold
+    pub value: String,
+}
+
+/// What syncing one configured TXT value does to the records at its name.
+#[derive(Debug)]
+pub enum TxtSync<'a> {
+    /// A record already holds the value
+    Present,
+    /// This client's record holding no configured value is rewritten
+    Update(&'a DnsResponseResult),
+    /// A new record is created next to the existing ones
+    Create,
+}
+
+/// Kind of record managed for a subdomain
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum CfRecordType {
+    /// A and/or AAAA records holding the detected IP (default)
+    #[default]
+    #[serde(alias = "AAAA")]
+    A,
+    /// A CNAME record pointing at `target`; needs no IP detection
+    Cname,
+}
+
+/// How much of a subdomain's records this client owns
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum CfManageMode {
+    /// Create missing records and update existing ones (default)
+    #[default]
+    Sync,
+    /// Create missing records, but never modify existing ones
+    CreateOnly,
+}
+
+/// Specifies which IP versions should be used for a subdomain
+#[derive(Debug, Deserialize, Clone, Copy, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum IpVersion {
     /// Use only IPv4
@@ -73,6 +225,7 @@ pub enum IpVersion {
     V6,
     /// Use both IPv4 and IPv6 (default)
     #[serde(rename = "both")]
+    #[default]
     Both,
 }
 
@@ -80,6 +233,16 @@ pub enum IpVersion {
 #[derive(Debug, Deserialize)]
 pub struct DnsResponse {
     pub result: Vec<DnsResponseResult>,
+    /// Pagination details, present on list responses
+    #[serde(default)]
+    pub result_info: Option<DnsResultInfo>,
+}
+
+/// Pagination details of a DNS record listing.
+#[derive(Debug, Deserialize)]
+pub struct DnsResultInfo {
+    /// The number of pages in the listing
+    pub total_pages: u32,
 }
 
 /// Details of the DNS response result.
@@ -87,8 +250,56 @@ pub struct DnsResponse {
 pub struct DnsResponseResult {
     /// The record ID
     pub id: String,
-    /// The record content (IP address)
+    /// The fully qualified record name
+    #[serde(default)]
+    pub name: String,
+    /// The record content (IP address or CNAME target)
     pub content: String,
+    /// The record comment, if one is set
+    #[serde(default)]
+    pub comment: Option<String>,
+}
+
+/// Represents the response from a DNS record creation.
+#[derive(Debug, Deserialize)]
+pub struct CreateRecordResponse {
+    pub result: CreateRecordResult,
+}
+
+/// Details of the created record.
+#[derive(Debug, Deserialize)]
+pub struct CreateRecordResult {
+    /// The ID assigned to the new record
+    pub id: String,
+}
+
+/// Error details of a failed API request.
+#[derive(Debug, Deserialize)]
+pub struct ApiErrorResponse {
+    #[serde(default)]
+    pub errors: Vec<ApiError>,
+}
+
+/// A single error reported by the API.
+#[derive(Debug, Deserialize)]
+pub struct ApiError {
+    /// The Cloudflare error code
+    pub code: u32,
+}
+
+/// Represents the response from a zone listing filtered by name.
+#[derive(Debug, Deserialize)]
+pub struct ZoneListResponse {
+    pub result: Vec<ZoneListResult>,
+}
+
+/// A zone in a zone listing.
+#[derive(Debug, Deserialize)]
+pub struct ZoneListResult {
+    /// The zone ID
+    pub id: String,
+    /// The zone's domain name
+    pub name: String,
 }
 
 /// Represents the response from a zone request.
@@ -102,4 +313,6 @@ pub struct ZoneResponse {
 pub struct ZoneResponseResult {
     /// The zone status (e.g., "active")
     pub status: String,
+    /// The zone's domain name
+    pub name: String,
 }
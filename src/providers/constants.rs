@@ -0,0 +1,18 @@
+/// Shortest update interval accepted without `allow_aggressive_interval`
+pub const MIN_UPDATE_INTERVAL_SECS: u64 = 30;
+/// Default number of retries for a failed DNS record update
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+/// Default delay before the first retry, doubled on every further attempt
+pub const DEFAULT_RETRY_BASE_DELAY_SECS: u64 = 2;
+/// Upper bound for a single retry delay
+pub const MAX_RETRY_DELAY_SECS: u64 = 30;
+/// Default number of times a failed first detection is retried at startup
+pub const DEFAULT_STARTUP_RETRIES: u32 = 3;
+/// Default delay before the first startup retry, doubled on every further attempt
+pub const DEFAULT_STARTUP_RETRY_DELAY_SECS: u64 = 5;
+/// Default time in-flight DNS updates get to finish after a shutdown signal
+pub const DEFAULT_SHUTDOWN_GRACE_SECS: u64 = 5;
+/// Default time all provider updates of one pass may take together
+pub const DEFAULT_UPDATE_TIMEOUT_SECS: u64 = 30;
+/// Default number of provider updates allowed in flight at once
+pub const DEFAULT_MAX_CONCURRENT_UPDATES: usize = 16;
@@ -0,0 +1,2 @@
+pub const DUCKDNS_UPDATE_URL: &str = "https://www.duckdns.org/update";
+pub const DUCKDNS_DOMAIN_SUFFIX: &str = ".duckdns.org";
@@ -0,0 +1,37 @@
+// 3rd party crates
+use thiserror::Error;
+
+/// Represents errors that can occur during DuckDNS API operations
+#[derive(Debug, Error)]
+pub enum DuckDnsError {
+    #[error("Invalid DuckDNS token")]
+    InvalidToken,
+
+    #[error("No domains configured for DuckDNS")]
+    NoDomains,
+
+    #[error("HTTP client error: {0}")]
+    HttpClientBuild(#[from] reqwest::Error),
+
+    #[error("Failed to update DuckDNS domains '{domains}': {message}")]
+    UpdateFailed { domains: String, message: String },
+
+    #[error("DuckDNS rejected the update for domains '{0}'")]
+    UpdateRejected(String),
+
+    #[error("DuckDNS update timed out for domains '{0}'")]
+    Timeout(String),
+
+    #[error("Validation error: {0}")]
+    Validation(#[from] DuckDnsValidationError),
+}
+
+#[derive(Debug, Error)]
+pub enum DuckDnsValidationError {
+    #[error("Missing or empty token, set token or token_file")]
+    MissingToken,
+    #[error("No domains configured")]
+    NoDomains,
+    #[error("Invalid domain name: '{0}'")]
+    InvalidDomain(String),
+}
@@ -0,0 +1,118 @@
+// Standard library
+use std::error::Error;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+// 3rd party crates
+use reqwest::Client;
+use tokio::sync::RwLockReadGuard;
+use tracing::{debug, error, info};
+
+// Project modules
+use crate::settings::types::{ConfigManager, HttpClientOptions, Settings};
+
+// Current module imports
+use super::constants::DUCKDNS_UPDATE_URL;
+use super::errors::DuckDnsError;
+use super::types::DuckDns;
+
+/// Creates the reqwest client used for DuckDNS updates.
+/// The token travels as a query parameter, so no default headers are needed.
+pub fn create_reqwest_client(options: &HttpClientOptions) -> Result<Client, DuckDnsError> {
+    options.apply(Client::builder()).build().map_err(|e| {
+        error!("Failed to build HTTP client: {}", e);
+        DuckDnsError::HttpClientBuild(e)
+    })
+}
+
+/// Gets all enabled DuckDNS instances from the configuration.
+pub async fn get_duckdns(config: Arc<ConfigManager>) -> Result<Vec<DuckDns>, Box<dyn Error>> {
+    let settings: RwLockReadGuard<Settings> = config.settings.read().await;
+    let http_options = settings.http_client_options();
+
+    let mut duckdns = Vec::new();
+    for duckdns_config in settings.duckdns.iter() {
+        if duckdns_config.enabled {
+            match DuckDns::new(duckdns_config.clone())
+                .and_then(|instance| instance.with_http_options(&http_options))
+            {
+                Ok(instance) => duckdns.push(instance.with_dry_run(settings.update.dry_run)),
+                Err(e) => error!("Failed to create DuckDNS instance: {}", e),
+            }
+        }
+    }
+    Ok(duckdns)
+}
+
+/// Updates all configured DuckDNS domains with the given address.
+/// IPv4 addresses are sent as `ip`, IPv6 addresses as `ipv6`. The response
+/// body is a plain `OK` or `KO`.
+pub async fn update_dns_records(duckdns: &DuckDns, ip: &IpAddr) -> Result<(), DuckDnsError> {
+    let domains = duckdns.config.domain_list();
+    let ip_param = match ip {
+        IpAddr::V4(_) => "ip",
+        IpAddr::V6(_) => "ipv6",
+    };
+
+    if duckdns.dry_run {
+        info!(
+            domains = %domains,
+            "Would update DuckDNS domains to {}",
+            ip
+        );
+        return Ok(());
+    }
+
+    debug!(
+        domains = %domains,
+        "Sending DuckDNS update request"
+    );
+
+    let request = duckdns.client.get(DUCKDNS_UPDATE_URL).query(&[
+        ("domains", domains.as_str()),
+        ("token", duckdns.config.token.as_str()),
+        (ip_param, ip.to_string().as_str()),
+    ]);
+
+    let response = tokio::time::timeout(Duration::from_secs(10), request.send())
+        .await
+        .map_err(|_| DuckDnsError::Timeout(domains.clone()))?
+        .map_err(|e| DuckDnsError::UpdateFailed {
+            domains: domains.clone(),
+            // The URL carries the token, so it is kept out of the message
+            message: format!("Failed to send update request: {}", e.without_url()),
+        })?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(DuckDnsError::UpdateFailed {
+            domains,
+            message: format!("HTTP {}", status),
+        });
+    }
+
+    let body = response
+        .text()
+        .await
+        .map_err(|e| DuckDnsError::UpdateFailed {
+            domains: domains.clone(),
+            message: format!("Failed to read response body: {}", e),
+        })?;
+
+    match body.trim() {
+        "OK" => {
+            info!(
+                domains = %domains,
+                "Successfully updated DuckDNS domains to {}",
+                ip
+            );
+            Ok(())
+        }
+        "KO" => Err(DuckDnsError::UpdateRejected(domains)),
+        other => Err(DuckDnsError::UpdateFailed {
+            domains,
+            message: format!("Unexpected response: {}", other),
+        }),
+    }
+}
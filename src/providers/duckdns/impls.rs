@@ -0,0 +1,116 @@
+// Standard library
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::time::Duration;
+
+// 3rd party crates
+use async_trait::async_trait;
+
+// Project modules
+use crate::providers::traits::DnsProvider;
+use crate::settings::types::HttpClientOptions;
+use crate::utility::ip_detector::types::IpVersion;
+
+// Current module imports
+use super::constants::DUCKDNS_DOMAIN_SUFFIX;
+use super::errors::{DuckDnsError, DuckDnsValidationError};
+use super::functions::{create_reqwest_client, update_dns_records};
+use super::types::{DuckDns, DuckDnsConfig};
+
+impl DuckDns {
+    /// Creates a new DuckDNS instance with the provided configuration.
+    pub fn new(config: DuckDnsConfig) -> Result<Self, DuckDnsError> {
+        let client = create_reqwest_client(&HttpClientOptions::default())?;
+        Ok(Self {
+            config,
+            client,
+            dry_run: false,
+        })
+    }
+
+    /// Rebuilds the HTTP client with the given User-Agent and proxy.
+    pub fn with_http_options(mut self, options: &HttpClientOptions) -> Result<Self, DuckDnsError> {
+        self.client = create_reqwest_client(options)?;
+        Ok(self)
+    }
+
+    /// Enables or disables dry-run mode.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+}
+
+impl DuckDnsConfig {
+    pub fn validate(&self) -> Result<(), DuckDnsValidationError> {
+        if self.token.trim().is_empty() {
+            return Err(DuckDnsValidationError::MissingToken);
+        }
+
+        if self.domains.is_empty() {
+            return Err(DuckDnsValidationError::NoDomains);
+        }
+
+        for domain in &self.domains {
+            let name = domain.trim_end_matches(DUCKDNS_DOMAIN_SUFFIX);
+            if name.is_empty() || name.contains('.') {
+                return Err(DuckDnsValidationError::InvalidDomain(domain.clone()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the comma-separated domain list expected by the DuckDNS API.
+    pub fn domain_list(&self) -> String {
+        self.domains
+            .iter()
+            .map(|domain| domain.trim_end_matches(DUCKDNS_DOMAIN_SUFFIX))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+#[async_trait]
+impl DnsProvider for DuckDns {
+    type Config = DuckDnsConfig;
+    type Error = DuckDnsError;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Self::new(config)
+    }
+
+    async fn update_dns_records_v4(&self, ip: &Ipv4Addr) -> Result<(), Self::Error> {
+        update_dns_records(self, &IpAddr::V4(*ip)).await
+    }
+
+    async fn update_dns_records_v6(&self, ip: &Ipv6Addr) -> Result<(), Self::Error> {
+        update_dns_records(self, &IpAddr::V6(*ip)).await
+    }
+
+    fn needs_ip_version(&self, version: IpVersion) -> bool {
+        self.config.ip_version.includes(version)
+    }
+
+    fn validate_config(&self) -> Result<(), Self::Error> {
+        if self.config.token.is_empty() || self.config.token == "your_token_here" {
+            return Err(DuckDnsError::InvalidToken);
+        }
+        if self.config.domains.is_empty() {
+            return Err(DuckDnsError::NoDomains);
+        }
+
+        Ok(())
+    }
+
+    fn update_interval(&self) -> Option<Duration> {
+        self.config.interval.map(Duration::from_secs)
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    fn get_name(&self) -> &str {
+        "duckdns"
+    }
+}
@@ -0,0 +1,5 @@
+pub mod constants;
+pub mod errors;
+pub mod functions;
+pub mod impls;
+pub mod types;
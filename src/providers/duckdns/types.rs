@@ -0,0 +1,41 @@
+// Standard library
+use std::path::PathBuf;
+
+// 3rd party crates
+use reqwest::Client;
+use serde::Deserialize;
+
+// Project modules
+use crate::providers::cloudflare::types::IpVersion;
+
+/// Represents a client for the DuckDNS update API.
+/// DuckDNS has no zones or record IDs; every update is a single GET
+/// carrying the token, the domains and the new address.
+/// Since DuckDNS cannot be queried first, dry-run mode skips the request entirely.
+#[derive(Debug, Clone)]
+pub struct DuckDns {
+    pub config: DuckDnsConfig,
+    pub client: Client,
+    pub dry_run: bool,
+}
+
+/// Configuration for DuckDNS.
+#[derive(Debug, Deserialize, Clone)]
+pub struct DuckDnsConfig {
+    /// Whether this DuckDNS configuration is enabled
+    pub enabled: bool,
+    /// Seconds between updates of this provider; defaults to `[update] interval`
+    #[serde(default)]
+    pub interval: Option<u64>,
+    /// The DuckDNS account token
+    #[serde(default)]
+    pub token: String,
+    /// File holding the account token, e.g. a Docker secret; replaces `token`
+    #[serde(default)]
+    pub token_file: Option<PathBuf>,
+    /// Domains to update, with or without the ".duckdns.org" suffix
+    pub domains: Vec<String>,
+    /// Which IP versions to update; defaults to both
+    #[serde(default)]
+    pub ip_version: IpVersion,
+}
@@ -0,0 +1,18 @@
+// 3rd party crates
+use thiserror::Error;
+
+/// Errors raised while coordinating updates across all providers.
+#[derive(Debug, Error)]
+pub enum ProviderError {
+    #[error("DNS updates timed out after {0} seconds in total")]
+    UpdateTimeout(u64),
+
+    #[error("DNS update of this provider timed out after {0} seconds")]
+    ProviderTimeout(u64),
+
+    #[error("One or more DNS updates failed")]
+    UpdatesFailed,
+
+    #[error("DNS updates were cancelled by shutdown")]
+    Cancelled,
+}
@@ -0,0 +1,318 @@
+// Standard library
+use std::error::Error;
+use std::future::Future;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+// 3rd party crates
+use futures::{stream::FuturesUnordered, StreamExt};
+use tokio::sync::{broadcast, Semaphore};
+use tokio::time::timeout;
+use tracing::{debug, error, info, warn};
+
+// Project modules
+use crate::metrics::types::MetricsManager;
+use crate::settings::types::ConfigManager;
+
+// Current module imports
+use super::arvancloud::functions::get_arvanclouds;
+use super::cloudflare::functions::get_cloudflares;
+use super::duckdns::functions::get_duckdns;
+use super::errors::ProviderError;
+#[cfg(feature = "mock-provider")]
+use super::mock::functions::get_mocks;
+use super::porkbun::functions::get_porkbuns;
+use super::route53::functions::get_route53s;
+use super::traits::AnyDnsProvider;
+use super::types::{ProviderUpdateResult, PushedIps, UpdateLimits, UpdateSummary};
+
+/// Gets all enabled DNS providers from the configuration.
+/// Each provider module exposes its own discovery function; this collects
+/// their instances behind the object-safe `AnyDnsProvider` trait so the
+/// update loop never needs to know which concrete providers exist.
+pub async fn get_providers(
+    config: Arc<ConfigManager>,
+    metrics: Arc<MetricsManager>,
+) -> Result<Vec<Box<dyn AnyDnsProvider>>, Box<dyn Error>> {
+    let mut providers: Vec<Box<dyn AnyDnsProvider>> = Vec::new();
+
+    for cloudflare in get_cloudflares(Arc::clone(&config), Arc::clone(&metrics)).await? {
+        providers.push(Box::new(cloudflare));
+    }
+
+    for arvancloud in get_arvanclouds(Arc::clone(&config)).await? {
+        providers.push(Box::new(arvancloud));
+    }
+
+    for duckdns in get_duckdns(Arc::clone(&config)).await? {
+        providers.push(Box::new(duckdns));
+    }
+
+    for route53 in get_route53s(Arc::clone(&config)).await? {
+        providers.push(Box::new(route53));
+    }
+
+    for porkbun in get_porkbuns(Arc::clone(&config)).await? {
+        providers.push(Box::new(porkbun));
+    }
+
+    #[cfg(feature = "mock-provider")]
+    for mock in get_mocks(Arc::clone(&config)).await? {
+        providers.push(Box::new(mock));
+    }
+
+    Ok(providers)
+}
+
+/// Processes updates concurrently for multiple DNS providers.
+/// This function handles updating DNS records for multiple domains in parallel,
+/// using a FuturesUnordered to manage concurrent updates efficiently.
+/// At most `limits.max_concurrent` providers update at the same time; the rest
+/// wait for a semaphore permit.
+/// Each provider update is cut off after `limits.provider_timeout`, and the
+/// whole pass after `limits.update_timeout`, so one hung provider can't hold
+/// up the others. On a shutdown signal the in-flight updates get
+/// `limits.shutdown_grace` to finish, after which they are cancelled.
+/// Every enabled provider gets an entry in the returned summary; updates cut
+/// short by a timeout or a shutdown are reported as [`ProviderError`]s.
+/// With `pushed_ips`, providers that already hold `ip` skip their API calls,
+/// and successful updates are recorded there.
+/// Only providers marked in `due`, indexed by position, are updated.
+pub async fn process_updates(
+    providers: &[Box<dyn AnyDnsProvider>],
+    ip: &IpAddr,
+    shutdown_rx: Option<broadcast::Receiver<()>>,
+    limits: UpdateLimits,
+    pushed_ips: Option<&PushedIps>,
+    due: &[bool],
+) -> UpdateSummary {
+    // Keep each provider's position in the full list, it keys `pushed_ips`
+    let enabled: Vec<(usize, &dyn AnyDnsProvider)> = providers
+        .iter()
+        .enumerate()
+        .filter(|(position, provider)| provider.is_enabled() && due[*position])
+        .map(|(position, provider)| (position, provider.as_ref()))
+        .collect();
+
+    // Bounds the number of providers updating at once
+    let semaphore = Semaphore::new(limits.max_concurrent);
+    let semaphore = &semaphore;
+
+    // Create a FuturesUnordered to hold our concurrent tasks.
+    let mut futures = FuturesUnordered::new();
+
+    // For each enabled provider, push an async task to update DNS records.
+    // Each task yields its index so the result can be matched to its provider.
+    for (index, (position, provider)) in enabled.iter().enumerate() {
+        info!(
+            provider = %provider.get_name(),
+            "Starting DNS update process"
+        );
+        let previous = pushed_ips.and_then(|pushed_ips| pushed_ips.get(*position, ip));
+        // Push the future into the FuturesUnordered stream.
+        futures.push(async move {
+            // The semaphore is never closed, so a permit always arrives
+            let _permit = semaphore.acquire().await;
+            let update = provider.update_dns_records_ip(ip, previous);
+            let result = match timeout(limits.provider_timeout, update).await {
+                Ok(result) => result,
+                Err(_) => Err(Box::new(ProviderError::ProviderTimeout(
+                    limits.provider_timeout.as_secs(),
+                )) as Box<dyn Error + Send + Sync>),
+            };
+            (index, result)
+        });
+    }
+
+    let mut results: Vec<Option<Result<(), Box<dyn Error + Send + Sync>>>> =
+        enabled.iter().map(|_| None).collect();
+
+    // Process updates with timeout and shutdown handling
+    let cancelled = match timeout(
+        limits.update_timeout,
+        process_updates_with_shutdown(
+            &mut futures,
+            &mut results,
+            shutdown_rx,
+            limits.shutdown_grace,
+        ),
+    )
+    .await
+    {
+        Ok(cancelled) => cancelled,
+        Err(_) => {
+            error!(
+                "DNS updates timed out after {} seconds",
+                limits.update_timeout.as_secs()
+            );
+            false
+        }
+    };
+    // Dropping the unfinished futures cancels their requests
+    drop(futures);
+
+    let results: Vec<ProviderUpdateResult> = enabled
+        .iter()
+        .zip(results)
+        .map(|((position, provider), result)| {
+            let result = result.unwrap_or_else(|| {
+                Err(Box::new(if cancelled {
+                    ProviderError::Cancelled
+                } else {
+                    ProviderError::UpdateTimeout(limits.update_timeout.as_secs())
+                }))
+            });
+            if let (Ok(()), Some(pushed_ips)) = (&result, pushed_ips) {
+                pushed_ips.record(*position, *ip);
+            }
+            ProviderUpdateResult {
+                provider: provider.get_name().to_string(),
+                result,
+            }
+        })
+        .collect();
+
+    // Report results
+    for update in &results {
+        match &update.result {
+            Ok(()) => debug!(provider = %update.provider, "DNS update completed"),
+            Err(e) => error!(provider = %update.provider, "Error updating DNS records: {}", e),
+        }
+    }
+    let summary = UpdateSummary { results };
+    let update_count = summary.results.len() - summary.failures().count();
+    if update_count > 0 {
+        info!("Completed {} DNS updates", update_count);
+    }
+
+    summary
+}
+
+/// Checks whether a record value already holds `expected`.
+/// The update paths use it to decide whether a record must be written, and
+/// the `status` and `reconcile` commands to report drift, so both agree.
+/// Addresses are compared parsed, since IPv6 has several textual forms;
+/// other content, such as a CNAME target, case-insensitively and without a
+/// trailing dot.
+pub fn record_content_matches(current: &str, expected: &str) -> bool {
+    let (current, expected) = (current.trim(), expected.trim());
+    match (current.parse::<IpAddr>(), expected.parse::<IpAddr>()) {
+        (Ok(current), Ok(expected)) => current == expected,
+        _ => current
+            .trim_end_matches('.')
+            .eq_ignore_ascii_case(expected.trim_end_matches('.')),
+    }
+}
+
+/// Brings the IP-independent records, such as TXT records, of every enabled
+/// provider marked in `due` to their configured content. Providers are
+/// synced concurrently.
+/// Returns `false` if any provider failed.
+pub async fn sync_static_records(providers: &[Box<dyn AnyDnsProvider>], due: &[bool]) -> bool {
+    let mut futures: FuturesUnordered<_> = providers
+        .iter()
+        .zip(due)
+        .filter(|(provider, due)| provider.is_enabled() && **due)
+        .map(|(provider, _)| provider)
+        .map(|provider| async move { (provider.get_name(), provider.sync_static_records().await) })
+        .collect();
+
+    let mut succeeded = true;
+    while let Some((provider, result)) = futures.next().await {
+        if let Err(e) = result {
+            error!(provider = %provider, "Error syncing static DNS records: {}", e);
+            succeeded = false;
+        }
+    }
+    succeeded
+}
+
+/// Helper function to drive the updates with shutdown handling.
+/// Finished updates are stored in `results` at the index they were tagged with.
+/// Returns `true` if a shutdown cut the remaining updates short.
+async fn process_updates_with_shutdown<F>(
+    futures: &mut FuturesUnordered<F>,
+    results: &mut [Option<Result<(), Box<dyn Error + Send + Sync>>>],
+    mut shutdown_rx: Option<broadcast::Receiver<()>>,
+    shutdown_grace: Duration,
+) -> bool
+where
+    F: Future<Output = (usize, Result<(), Box<dyn Error + Send + Sync>>)>,
+{
+    loop {
+        tokio::select! {
+            // Handle shutdown signal if provided
+            Some(shutdown_result) = async {
+                if let Some(rx) = &mut shutdown_rx {
+                    Some(rx.recv().await)
+                } else {
+                    None
+                }
+            } => {
+                match shutdown_result {
+                    Ok(()) => {
+                        info!(
+                            "Received shutdown signal during DNS updates, allowing {} in-progress updates {} seconds to finish...",
+                            futures.len(),
+                            shutdown_grace.as_secs()
+                        );
+                        let drain = async {
+                            while let Some((index, result)) = futures.next().await {
+                                results[index] = Some(result);
+                            }
+                        };
+                        if timeout(shutdown_grace, drain).await.is_err() {
+                            warn!("Cancelling {} DNS updates still in progress", futures.len());
+                            return true;
+                        }
+                        return false;
+                    }
+                    Err(e) => {
+                        warn!("Shutdown receiver error: {}", e);
+                        // Keep processing, but stop listening if the channel is gone
+                        if matches!(e, broadcast::error::RecvError::Closed) {
+                            shutdown_rx = None;
+                        }
+                        continue;
+                    }
+                }
+            }
+            // Process next update
+            Some((index, result)) = futures.next() => {
+                results[index] = Some(result);
+
+                // Check if all updates are complete
+                if futures.is_empty() {
+                    return false;
+                }
+            }
+            // All futures completed
+            else => return false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_content_matches_compares_addresses_parsed() {
+        assert!(record_content_matches("2001:db8::1", "2001:0db8:0:0::1"));
+        assert!(record_content_matches(" 192.0.2.1 ", "192.0.2.1"));
+        assert!(!record_content_matches("192.0.2.1", "192.0.2.2"));
+    }
+
+    #[test]
+    fn record_content_matches_compares_names_case_insensitively() {
+        assert!(record_content_matches(
+            "Target.Example.com.",
+            "target.example.com"
+        ));
+        assert!(!record_content_matches(
+            "other.example.com",
+            "target.example.com"
+        ));
+    }
+}
@@ -0,0 +1,158 @@
+// Standard library
+use std::net::IpAddr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// Project imports
+use crate::utility::ip_detector::types::IpVersion;
+
+// Current module imports
+use super::constants::{DEFAULT_MAX_RETRIES, DEFAULT_RETRY_BASE_DELAY_SECS, MAX_RETRY_DELAY_SECS};
+use super::errors::ProviderError;
+use super::types::{ProviderUpdateResult, PushedIps, RetryPolicy, UpdateSchedule, UpdateSummary};
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_RETRIES, DEFAULT_RETRY_BASE_DELAY_SECS)
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a retry policy capped at `MAX_RETRY_DELAY_SECS` per delay.
+    pub fn new(max_retries: u32, base_delay_secs: u64) -> Self {
+        Self {
+            max_retries,
+            base_delay: Duration::from_secs(base_delay_secs),
+            max_delay: Duration::from_secs(MAX_RETRY_DELAY_SECS),
+        }
+    }
+
+    /// Returns the delay before the given retry (starting at 1).
+    /// The exponential delay is jittered into its upper half so that
+    /// clients failing together don't retry in lockstep.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(16);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+        let half_ms = (delay.as_millis() / 2) as u64;
+        Duration::from_millis(half_ms + fastrand::u64(0..=half_ms))
+    }
+}
+
+impl UpdateSummary {
+    /// Checks whether every provider was updated.
+    pub fn succeeded(&self) -> bool {
+        self.results.iter().all(|update| update.result.is_ok())
+    }
+
+    /// Returns the providers whose update failed.
+    pub fn failures(&self) -> impl Iterator<Item = &ProviderUpdateResult> {
+        self.results.iter().filter(|update| update.result.is_err())
+    }
+
+    /// Checks whether any update was cancelled by a shutdown.
+    pub fn cancelled(&self) -> bool {
+        self.failures().any(|update| {
+            matches!(
+                update
+                    .result
+                    .as_ref()
+                    .err()
+                    .and_then(|e| e.downcast_ref::<ProviderError>()),
+                Some(ProviderError::Cancelled)
+            )
+        })
+    }
+
+    /// Describes every failure as "provider: error", separated by semicolons.
+    pub fn failure_message(&self) -> String {
+        self.failures()
+            .filter_map(|update| {
+                let e = update.result.as_ref().err()?;
+                Some(format!("{}: {}", update.provider, e))
+            })
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+}
+
+impl PushedIps {
+    /// Returns the address last pushed by the provider at `index` for the
+    /// same IP version as `ip`.
+    pub fn get(&self, index: usize, ip: &IpAddr) -> Option<IpAddr> {
+        let ips = self.ips.lock().ok()?;
+        ips.get(&(index, IpVersion::from(ip))).copied()
+    }
+
+    /// Records that the provider at `index` now holds `ip`.
+    pub fn record(&self, index: usize, ip: IpAddr) {
+        if let Ok(mut ips) = self.ips.lock() {
+            ips.insert((index, IpVersion::from(&ip)), ip);
+        }
+    }
+
+    /// Records `ip` for each of the first `count` providers that has no
+    /// address of its version yet, so they count as holding it.
+    pub fn assume(&self, count: usize, ip: IpAddr) {
+        if let Ok(mut ips) = self.ips.lock() {
+            for index in 0..count {
+                ips.entry((index, IpVersion::from(&ip))).or_insert(ip);
+            }
+        }
+    }
+}
+
+impl UpdateSchedule {
+    /// Creates a schedule with every provider due right away.
+    pub fn new(intervals: Vec<Duration>) -> Self {
+        let now = Instant::now();
+        Self {
+            next_due: Mutex::new(vec![now; intervals.len()]),
+            intervals,
+        }
+    }
+
+    /// Returns, by position, which providers are due now.
+    pub fn due(&self) -> Vec<bool> {
+        let now = Instant::now();
+        match self.next_due.lock() {
+            Ok(next_due) => next_due.iter().map(|due| *due <= now).collect(),
+            Err(_) => vec![true; self.intervals.len()],
+        }
+    }
+
+    /// Schedules the next update of every provider in `due` one interval from now.
+    pub fn reschedule(&self, due: &[bool]) {
+        let now = Instant::now();
+        if let Ok(mut next_due) = self.next_due.lock() {
+            for ((next, interval), _) in next_due
+                .iter_mut()
+                .zip(&self.intervals)
+                .zip(due)
+                .filter(|(_, due)| **due)
+            {
+                *next = now + *interval;
+            }
+        }
+    }
+
+    /// Makes every provider due right away, e.g. to retry a failed first cycle.
+    pub fn reset(&self) {
+        let now = Instant::now();
+        if let Ok(mut next_due) = self.next_due.lock() {
+            next_due.fill(now);
+        }
+    }
+
+    /// Returns how long until the next provider is due; zero if one already is.
+    pub fn next_delay(&self) -> Duration {
+        let now = Instant::now();
+        self.next_due
+            .lock()
+            .ok()
+            .and_then(|next_due| next_due.iter().min().copied())
+            .map_or(Duration::ZERO, |due| due.saturating_duration_since(now))
+    }
+}
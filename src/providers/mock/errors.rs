@@ -0,0 +1,24 @@
+// 3rd party crates
+use thiserror::Error;
+
+/// Errors a mock provider can be configured to return.
+#[derive(Debug, Error)]
+pub enum MockError {
+    #[error("Simulated update failure for mock provider '{0}'")]
+    UpdateFailed(String),
+
+    #[error("Simulated rate limit for mock provider '{0}'")]
+    RateLimited(String),
+
+    #[error("Simulated timeout for mock provider '{0}'")]
+    Timeout(String),
+
+    #[error("Validation error: {0}")]
+    Validation(#[from] MockValidationError),
+}
+
+#[derive(Debug, Error)]
+pub enum MockValidationError {
+    #[error("Missing or empty name")]
+    MissingName,
+}
@@ -0,0 +1,24 @@
+// Standard library
+use std::error::Error;
+use std::sync::Arc;
+
+// 3rd party crates
+use tokio::sync::RwLockReadGuard;
+
+// Project modules
+use crate::settings::types::{ConfigManager, Settings};
+
+// Current module imports
+use super::types::MockProvider;
+
+/// Gets all enabled mock providers from the configuration.
+pub async fn get_mocks(config: Arc<ConfigManager>) -> Result<Vec<MockProvider>, Box<dyn Error>> {
+    let settings: RwLockReadGuard<Settings> = config.settings.read().await;
+
+    Ok(settings
+        .mock
+        .iter()
+        .filter(|mock_config| mock_config.enabled)
+        .map(|mock_config| MockProvider::new(mock_config.clone()))
+        .collect())
+}
@@ -0,0 +1,131 @@
+// Standard library
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+// 3rd party crates
+use async_trait::async_trait;
+use tracing::info;
+
+// Project modules
+use crate::providers::traits::DnsProvider;
+use crate::utility::ip_detector::types::IpVersion;
+
+// Current module imports
+use super::errors::{MockError, MockValidationError};
+use super::types::{MockConfig, MockFailure, MockProvider};
+
+impl MockProvider {
+    /// Creates a new mock provider with an empty call log.
+    pub fn new(config: MockConfig) -> Self {
+        Self {
+            config,
+            calls: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Records the call, waits out the configured delay and returns the
+    /// configured failure. The call is recorded before the delay, so an
+    /// update cancelled by a shutdown still shows up in the log.
+    async fn update(&self, ip: IpAddr) -> Result<(), MockError> {
+        if let Ok(mut calls) = self.calls.lock() {
+            calls.push(ip);
+        }
+
+        if self.config.delay_ms > 0 {
+            tokio::time::sleep(Duration::from_millis(self.config.delay_ms)).await;
+        }
+
+        match self.config.fail_with {
+            None => {
+                info!(
+                    provider = %self.config.name,
+                    "Mock provider recorded update to {}",
+                    ip
+                );
+                Ok(())
+            }
+            Some(MockFailure::Update) => Err(MockError::UpdateFailed(self.config.name.clone())),
+            Some(MockFailure::RateLimited) => Err(MockError::RateLimited(self.config.name.clone())),
+            Some(MockFailure::Timeout) => Err(MockError::Timeout(self.config.name.clone())),
+        }
+    }
+}
+
+// Helpers for tests driving the update loop
+#[cfg(test)]
+impl MockProvider {
+    /// Makes every update return the given failure.
+    pub fn with_failure(mut self, failure: MockFailure) -> Self {
+        self.config.fail_with = Some(failure);
+        self
+    }
+
+    /// Delays every update by the given duration.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.config.delay_ms = delay.as_millis() as u64;
+        self
+    }
+
+    /// Returns the addresses of all update calls so far.
+    pub fn calls(&self) -> Vec<IpAddr> {
+        self.calls
+            .lock()
+            .map(|calls| calls.clone())
+            .unwrap_or_default()
+    }
+}
+
+impl MockConfig {
+    pub fn validate(&self) -> Result<(), MockValidationError> {
+        if self.name.trim().is_empty() {
+            return Err(MockValidationError::MissingName);
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DnsProvider for MockProvider {
+    type Config = MockConfig;
+    type Error = MockError;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Ok(Self::new(config))
+    }
+
+    async fn update_dns_records_v4(&self, ip: &Ipv4Addr) -> Result<(), Self::Error> {
+        if !self.needs_ip_version(IpVersion::V4) {
+            return Ok(());
+        }
+        self.update(IpAddr::V4(*ip)).await
+    }
+
+    async fn update_dns_records_v6(&self, ip: &Ipv6Addr) -> Result<(), Self::Error> {
+        if !self.needs_ip_version(IpVersion::V6) {
+            return Ok(());
+        }
+        self.update(IpAddr::V6(*ip)).await
+    }
+
+    fn needs_ip_version(&self, version: IpVersion) -> bool {
+        self.config.ip_version.includes(version)
+    }
+
+    fn validate_config(&self) -> Result<(), Self::Error> {
+        Ok(self.config.validate()?)
+    }
+
+    fn update_interval(&self) -> Option<Duration> {
+        self.config.interval.map(Duration::from_secs)
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    fn get_name(&self) -> &str {
+        &self.config.name
+    }
+}
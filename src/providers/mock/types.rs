@@ -0,0 +1,55 @@
+// Standard library
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+
+// 3rd party crates
+use serde::Deserialize;
+
+// Project modules
+use crate::providers::cloudflare::types::IpVersion;
+
+/// A provider that talks to no DNS service at all.
+/// Every update is recorded in memory so the orchestration can be observed,
+/// and configured failures let callers exercise the error paths.
+/// Only built with the `mock-provider` feature.
+#[derive(Debug, Clone)]
+pub struct MockProvider {
+    pub config: MockConfig,
+    /// Addresses passed to every update call, in order; shared between clones
+    pub calls: Arc<Mutex<Vec<IpAddr>>>,
+}
+
+/// Configuration for a mock provider.
+#[derive(Debug, Deserialize, Clone)]
+pub struct MockConfig {
+    /// Whether this mock provider is enabled
+    pub enabled: bool,
+    /// Seconds between updates of this provider; defaults to `[update] interval`
+    #[serde(default)]
+    pub interval: Option<u64>,
+    /// Name used in logs and update summaries
+    #[serde(default = "default_mock_name")]
+    pub name: String,
+    /// Which IP versions the provider asks to be updated
+    #[serde(default)]
+    pub ip_version: IpVersion,
+    /// Error returned from every update, if any
+    #[serde(default)]
+    pub fail_with: Option<MockFailure>,
+    /// Delay before each update completes, in milliseconds
+    #[serde(default)]
+    pub delay_ms: u64,
+}
+
+/// Failure modes a mock provider can simulate.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum MockFailure {
+    Update,
+    RateLimited,
+    Timeout,
+}
+
+fn default_mock_name() -> String {
+    "mock".to_string()
+}
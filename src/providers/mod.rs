@@ -1,4 +1,15 @@
+pub mod arvancloud;
 pub mod cloudflare;
+pub mod constants;
+pub mod duckdns;
+pub mod errors;
+pub mod functions;
+pub mod impls;
+#[cfg(feature = "mock-provider")]
+pub mod mock;
+pub mod porkbun;
+pub mod route53;
 pub mod traits;
+pub mod types;
 
-pub use traits::DnsProvider;
+pub use traits::AnyDnsProvider;
@@ -0,0 +1,9 @@
+pub const PORKBUN_API_BASE: &str = "https://api.porkbun.com/api/json/v3";
+
+/// Value of the `status` field in successful responses
+pub const PORKBUN_STATUS_SUCCESS: &str = "SUCCESS";
+
+/// Smallest TTL Porkbun accepts, also used when a subdomain does not set one
+pub const PORKBUN_MIN_TTL: u32 = 600;
+/// Largest TTL Porkbun accepts
+pub const PORKBUN_MAX_TTL: u32 = 86400;
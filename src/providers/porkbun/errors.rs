@@ -0,0 +1,65 @@
+// 3rd party crates
+use thiserror::Error;
+
+/// Represents errors that can occur during Porkbun API operations
+/// The variants mirror `CloudflareError` so every provider surfaces
+/// failures in the same shape to the update loop.
+#[derive(Debug, Error)]
+pub enum PorkbunError {
+    #[error("Invalid API keys for domain '{0}'")]
+    InvalidCredentials(String),
+
+    #[error("No subdomains configured for domain '{0}'")]
+    NoSubdomains(String),
+
+    #[error("HTTP client error: {0}")]
+    HttpClientBuild(#[from] reqwest::Error),
+
+    #[error("Failed to fetch DNS records for domain '{domain}': {message}")]
+    FetchFailed { domain: String, message: String },
+
+    #[error("Failed to create DNS record '{record}' in domain '{domain}': {message}")]
+    CreateFailed {
+        domain: String,
+        record: String,
+        message: String,
+    },
+
+    #[error("Failed to update DNS record '{record}' in domain '{domain}': {message}")]
+    UpdateFailed {
+        domain: String,
+        record: String,
+        message: String,
+    },
+
+    #[error("Rate limit exceeded for domain '{0}'")]
+    RateLimited(String),
+
+    #[error("Porkbun API rate limit hit for domain '{0}' (HTTP 429)")]
+    Throttled(String),
+
+    #[error("Invalid rate limit configuration for domain '{domain}': {reason}")]
+    InvalidRateLimit { domain: String, reason: String },
+
+    #[error("Operation timed out for domain '{domain}': {message}")]
+    Timeout { domain: String, message: String },
+
+    #[error("Validation error: {0}")]
+    Validation(#[from] PorkbunValidationError),
+}
+
+#[derive(Debug, Error)]
+pub enum PorkbunValidationError {
+    #[error("Missing or empty domain")]
+    MissingDomain,
+    #[error("Missing or empty api_key")]
+    MissingApiKey,
+    #[error("Missing or empty secret_api_key, set secret_api_key or secret_api_key_file")]
+    MissingSecretApiKey,
+    #[error("No subdomains configured")]
+    NoSubdomains,
+    #[error("Invalid rate limit: {0}")]
+    InvalidRateLimit(String),
+    #[error("Invalid TTL for subdomain '{subdomain}': {reason}")]
+    InvalidTtl { subdomain: String, reason: String },
+}
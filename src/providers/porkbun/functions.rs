@@ -0,0 +1,400 @@
+// Standard library
+use std::error::Error;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+// 3rd party crates
+use reqwest::{Client, StatusCode};
+use serde::Serialize;
+use tokio::sync::RwLockReadGuard;
+use tracing::{debug, error, info, warn};
+
+// Project modules
+use crate::providers::functions::record_content_matches;
+use crate::settings::types::{ConfigManager, HttpClientOptions, Settings};
+use crate::utility::ip_detector::types::IpVersion as DetectedIpVersion;
+
+// Current module imports
+use super::constants::{PORKBUN_API_BASE, PORKBUN_STATUS_SUCCESS};
+use super::errors::PorkbunError;
+use super::types::{
+    CreateRecordRequest, EditRecordRequest, Porkbun, PorkbunRecord, PorkbunResponse,
+};
+
+/// Creates the reqwest client used for Porkbun requests.
+/// The API keys travel in each request body, so no default headers are set.
+pub fn create_reqwest_client(options: &HttpClientOptions) -> Result<Client, PorkbunError> {
+    options.apply(Client::builder()).build().map_err(|e| {
+        error!("Failed to build HTTP client: {}", e);
+        PorkbunError::HttpClientBuild(e)
+    })
+}
+
+/// Gets all enabled Porkbun instances from the configuration.
+pub async fn get_porkbuns(config: Arc<ConfigManager>) -> Result<Vec<Porkbun>, Box<dyn Error>> {
+    let settings: RwLockReadGuard<Settings> = config.settings.read().await;
+    let http_options = settings.http_client_options();
+
+    let mut porkbuns = Vec::new();
+    for porkbun_config in settings.porkbun.iter() {
+        if porkbun_config.enabled {
+            match Porkbun::new(porkbun_config.clone())
+                .and_then(|porkbun| porkbun.with_http_options(&http_options))
+            {
+                Ok(porkbun) => porkbuns.push(porkbun.with_dry_run(settings.update.dry_run)),
+                Err(e) => error!("Failed to create Porkbun instance: {}", e),
+            }
+        }
+    }
+    Ok(porkbuns)
+}
+
+/// Sends a JSON POST to the given API path.
+/// Returns the status and body; callers check them with [`parse_response`].
+async fn send_request<T: Serialize + Sync>(
+    porkbun: &Porkbun,
+    path: &str,
+    body: &T,
+) -> Result<(StatusCode, String), PorkbunError> {
+    let domain = porkbun.config.domain.clone();
+    let url = format!("{}{}", PORKBUN_API_BASE, path);
+
+    debug!(
+        domain = %domain,
+        url = %url,
+        "Sending Porkbun request"
+    );
+
+    let response = tokio::time::timeout(
+        Duration::from_secs(10),
+        porkbun.client.post(&url).json(body).send(),
+    )
+    .await
+    .map_err(|_| PorkbunError::Timeout {
+        domain: domain.clone(),
+        message: "Porkbun request timed out".to_string(),
+    })??;
+
+    let status = response.status();
+    let body = response.text().await?;
+    Ok((status, body))
+}
+
+/// Parses a Porkbun response, which reports failures with an "ERROR" status.
+/// Credential and throttling failures are returned as errors; other failures
+/// yield the API error message so callers can wrap it in their own variant.
+fn parse_response(
+    porkbun: &Porkbun,
+    status: StatusCode,
+    body: &str,
+) -> Result<Result<PorkbunResponse, String>, PorkbunError> {
+    let domain = porkbun.config.domain.clone();
+    let parsed = serde_json::from_str::<PorkbunResponse>(body);
+    let message = match &parsed {
+        Ok(response) => response.message.clone().unwrap_or_default(),
+        Err(_) => body.trim().to_string(),
+    };
+
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        warn!(
+            domain = %domain,
+            "Porkbun API throttled the request"
+        );
+        return Err(PorkbunError::Throttled(domain));
+    }
+
+    if matches!(status, StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN)
+        || message.contains("Invalid API key")
+    {
+        error!(
+            domain = %domain,
+            "Porkbun rejected the API keys: {}",
+            message
+        );
+        return Err(PorkbunError::InvalidCredentials(domain));
+    }
+
+    Ok(match parsed {
+        Ok(response) if status.is_success() && response.status == PORKBUN_STATUS_SUCCESS => {
+            Ok(response)
+        }
+        Ok(_) => Err(format!("HTTP {}: {}", status, message)),
+        Err(e) => Err(format!("HTTP {}: failed to parse response: {}", status, e)),
+    })
+}
+
+/// Fetches the records with the given subdomain and type.
+async fn fetch_records(
+    porkbun: &Porkbun,
+    subdomain: &str,
+    record_type: &str,
+) -> Result<Vec<PorkbunRecord>, PorkbunError> {
+    let path = format!(
+        "/dns/retrieveByNameType/{}/{}/{}",
+        porkbun.config.domain_name(),
+        record_type,
+        subdomain
+    );
+    let (status, body) = send_request(porkbun, &path, &porkbun.auth()).await?;
+    let response =
+        parse_response(porkbun, status, &body)?.map_err(|message| PorkbunError::FetchFailed {
+            domain: porkbun.config.domain.clone(),
+            message,
+        })?;
+
+    debug!(
+        domain = %porkbun.config.domain,
+        record = %porkbun.config.fqdn(subdomain),
+        response = %body,
+        "Received records response"
+    );
+
+    Ok(response.records)
+}
+
+/// Sets every record with the given subdomain and type to `content`.
+async fn edit_records(
+    porkbun: &Porkbun,
+    subdomain: &str,
+    record_type: &str,
+    ttl: u32,
+    content: &str,
+) -> Result<(), PorkbunError> {
+    let path = format!(
+        "/dns/editByNameType/{}/{}/{}",
+        porkbun.config.domain_name(),
+        record_type,
+        subdomain
+    );
+    let request = EditRecordRequest {
+        auth: porkbun.auth(),
+        content,
+        ttl: ttl.to_string(),
+    };
+    let (status, body) = send_request(porkbun, &path, &request).await?;
+    parse_response(porkbun, status, &body)?.map_err(|message| PorkbunError::UpdateFailed {
+        domain: porkbun.config.domain.clone(),
+        record: porkbun.config.fqdn(subdomain),
+        message,
+    })?;
+
+    Ok(())
+}
+
+/// Creates a new record with the given subdomain, type and content.
+async fn create_record(
+    porkbun: &Porkbun,
+    subdomain: &str,
+    record_type: &str,
+    ttl: u32,
+    content: &str,
+) -> Result<(), PorkbunError> {
+    let path = format!("/dns/create/{}", porkbun.config.domain_name());
+    let request = CreateRecordRequest {
+        auth: porkbun.auth(),
+        name: subdomain,
+        record_type,
+        content,
+        ttl: ttl.to_string(),
+    };
+    let (status, body) = send_request(porkbun, &path, &request).await?;
+    parse_response(porkbun, status, &body)?.map_err(|message| PorkbunError::CreateFailed {
+        domain: porkbun.config.domain.clone(),
+        record: porkbun.config.fqdn(subdomain),
+        message,
+    })?;
+
+    Ok(())
+}
+
+/// Fetches the addresses every managed record currently holds.
+pub async fn fetch_current_records(
+    porkbun: &Porkbun,
+) -> Result<Vec<(String, IpAddr)>, PorkbunError> {
+    let mut current = Vec::new();
+    for subdomain in &porkbun.config.subdomains {
+        let name = subdomain.name.trim();
+        let fqdn = porkbun.config.fqdn(name);
+
+        for (version, record_type) in [
+            (DetectedIpVersion::V4, "A"),
+            (DetectedIpVersion::V6, "AAAA"),
+        ] {
+            if !subdomain.ip_version.includes(version) {
+                continue;
+            }
+            let records = porkbun
+                .with_rate_limit(fetch_records(porkbun, name, record_type))
+                .await?;
+            for record in records {
+                match record.content.trim().parse::<IpAddr>() {
+                    Ok(ip) => current.push((fqdn.clone(), ip)),
+                    Err(_) => warn!(
+                        domain = %porkbun.config.domain,
+                        record = %fqdn,
+                        "Ignoring {} record with non-IP content {}",
+                        record_type,
+                        record.content
+                    ),
+                }
+            }
+        }
+    }
+    Ok(current)
+}
+
+/// Creates or updates the records of one subdomain so they hold `ip`.
+/// Like the Cloudflare provider, the current records are fetched first and
+/// only written when they are missing or differ.
+async fn process_domain_record(
+    porkbun: &Porkbun,
+    subdomain: &str,
+    record_type: &str,
+    ttl: u32,
+    ip: &IpAddr,
+) -> Result<(), PorkbunError> {
+    let content = ip.to_string();
+    let fqdn = porkbun.config.fqdn(subdomain);
+    let records = porkbun
+        .with_rate_limit(fetch_records(porkbun, subdomain, record_type))
+        .await?;
+
+    if records.is_empty() {
+        if porkbun.dry_run {
+            info!(
+                domain = %porkbun.config.domain,
+                record = %fqdn,
+                "Would create {} record with {}",
+                record_type,
+                content
+            );
+            return Ok(());
+        }
+
+        warn!(
+            domain = %porkbun.config.domain,
+            record = %fqdn,
+            "No DNS record found, attempting to create"
+        );
+        porkbun
+            .with_rate_limit(create_record(
+                porkbun,
+                subdomain,
+                record_type,
+                ttl,
+                &content,
+            ))
+            .await?;
+        info!(
+            domain = %porkbun.config.domain,
+            record = %fqdn,
+            "Successfully created DNS record with {}",
+            content
+        );
+        return Ok(());
+    }
+
+    let values: Vec<&str> = records.iter().map(|record| record.content.trim()).collect();
+    if values
+        .iter()
+        .all(|value| record_content_matches(value, &content))
+    {
+        debug!(
+            domain = %porkbun.config.domain,
+            record = %fqdn,
+            "DNS record already set to {}",
+            content
+        );
+        return Ok(());
+    }
+
+    if porkbun.dry_run {
+        info!(
+            domain = %porkbun.config.domain,
+            record = %fqdn,
+            "Would update {} from {} to {}",
+            fqdn,
+            values.join(", "),
+            content
+        );
+        return Ok(());
+    }
+
+    info!(
+        domain = %porkbun.config.domain,
+        record = %fqdn,
+        "Updating DNS record from {} to {}",
+        values.join(", "),
+        content
+    );
+    porkbun
+        .with_rate_limit(edit_records(porkbun, subdomain, record_type, ttl, &content))
+        .await?;
+    info!(
+        domain = %porkbun.config.domain,
+        record = %fqdn,
+        "Successfully updated DNS record to {}",
+        content
+    );
+    Ok(())
+}
+
+/// Updates DNS records for all configured subdomains.
+/// Every subdomain is attempted; the last error is returned if any failed.
+pub async fn update_dns_records(porkbun: &Porkbun, ip: &IpAddr) -> Result<(), PorkbunError> {
+    let mut last_error: Option<PorkbunError> = None;
+    let mut update_count = 0;
+
+    let record_type = match ip {
+        IpAddr::V4(_) => "A",
+        IpAddr::V6(_) => "AAAA",
+    };
+
+    for subdomain in &porkbun.config.subdomains {
+        if !subdomain.ip_version.includes(DetectedIpVersion::from(ip)) {
+            debug!(
+                domain = %porkbun.config.domain,
+                subdomain = %subdomain.name,
+                ip_type = %record_type,
+                "Skipping DNS update - IP version not enabled for subdomain"
+            );
+            continue;
+        }
+
+        let name = subdomain.name.trim();
+        info!(
+            domain = %porkbun.config.domain,
+            record = %porkbun.config.fqdn(name),
+            record_type = %record_type,
+            "Processing DNS records"
+        );
+
+        match process_domain_record(porkbun, name, record_type, subdomain.ttl(), ip).await {
+            Ok(()) => update_count += 1,
+            Err(e) => {
+                error!(
+                    domain = %porkbun.config.domain,
+                    record = %porkbun.config.fqdn(name),
+                    error = %e,
+                    "Failed to process DNS record"
+                );
+                last_error = Some(e);
+            }
+        }
+    }
+
+    if update_count > 0 {
+        info!(
+            domain = %porkbun.config.domain,
+            count = update_count,
+            "Successfully processed {} DNS records",
+            update_count
+        );
+    }
+
+    match last_error {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
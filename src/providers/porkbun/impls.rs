@@ -0,0 +1,268 @@
+// Standard library
+use std::fmt;
+use std::future::Future;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::Arc;
+use std::time::Duration;
+
+// 3rd party crates
+use async_trait::async_trait;
+
+// Project modules
+use crate::providers::traits::DnsProvider;
+use crate::settings::types::HttpClientOptions;
+use crate::utility::ip_detector::types::IpVersion;
+use crate::utility::rate_limiter::types::{RateLimitStatus, TokenBucketRateLimiter};
+
+// Current module imports
+use super::constants::{PORKBUN_MAX_TTL, PORKBUN_MIN_TTL};
+use super::errors::{PorkbunError, PorkbunValidationError};
+use super::functions::{create_reqwest_client, fetch_current_records, update_dns_records};
+use super::types::{Porkbun, PorkbunAuth, PorkbunConfig, PorkbunSubDomain};
+
+// Manual Debug implementation for Porkbun
+impl fmt::Debug for Porkbun {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Porkbun")
+            .field("config", &self.config)
+            .field("client", &self.client)
+            .field("rate_limiter", &"<rate limiter>")
+            .field("dry_run", &self.dry_run)
+            .finish()
+    }
+}
+
+// Manual Clone implementation for Porkbun
+impl Clone for Porkbun {
+    fn clone(&self) -> Self {
+        Self {
+            config: self.config.clone(),
+            client: self.client.clone(),
+            rate_limiter: Arc::clone(&self.rate_limiter),
+            dry_run: self.dry_run,
+        }
+    }
+}
+
+// Manual Debug implementation that keeps the keys out of the logs
+impl fmt::Debug for PorkbunConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PorkbunConfig")
+            .field("enabled", &self.enabled)
+            .field("interval", &self.interval)
+            .field("domain", &self.domain)
+            .field("api_key", &"<redacted>")
+            .field("secret_api_key", &"<redacted>")
+            .field("secret_api_key_file", &self.secret_api_key_file)
+            .field("rate_limit", &self.rate_limit)
+            .field("subdomains", &self.subdomains)
+            .finish()
+    }
+}
+
+impl Porkbun {
+    /// Creates a new Porkbun instance with the provided configuration.
+    /// This will initialize the HTTP client and rate limiter.
+    pub fn new(config: PorkbunConfig) -> Result<Self, PorkbunError> {
+        let client = create_reqwest_client(&HttpClientOptions::default())?;
+        let rate_limiter = Arc::new(TokenBucketRateLimiter::new(config.rate_limit.clone()));
+
+        Ok(Self {
+            config,
+            client,
+            rate_limiter,
+            dry_run: false,
+        })
+    }
+
+    /// Rebuilds the HTTP client with the given User-Agent and proxy.
+    pub fn with_http_options(mut self, options: &HttpClientOptions) -> Result<Self, PorkbunError> {
+        self.client = create_reqwest_client(options)?;
+        Ok(self)
+    }
+
+    /// Enables or disables dry-run mode.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Returns the API key pair for a request body.
+    pub fn auth(&self) -> PorkbunAuth<'_> {
+        PorkbunAuth {
+            apikey: &self.config.api_key,
+            secretapikey: &self.config.secret_api_key,
+        }
+    }
+
+    /// Acquires a rate limit permit before making an API call.
+    /// This ensures we respect Porkbun's API rate limits.
+    pub async fn with_rate_limit<F, T, E>(&self, f: F) -> Result<T, E>
+    where
+        F: Future<Output = Result<T, E>>,
+        E: From<PorkbunError>,
+    {
+        if !self.rate_limiter.acquire().await {
+            return Err(PorkbunError::RateLimited(self.config.domain.clone()).into());
+        }
+
+        f.await
+    }
+}
+
+impl PorkbunConfig {
+    /// Returns the domain without surrounding whitespace or a trailing dot.
+    pub fn domain_name(&self) -> &str {
+        self.domain.trim().trim_end_matches('.')
+    }
+
+    /// Returns the fully qualified record name, used in logs.
+    pub fn fqdn(&self, subdomain: &str) -> String {
+        if subdomain.is_empty() {
+            self.domain_name().to_string()
+        } else {
+            format!("{}.{}", subdomain, self.domain_name())
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), PorkbunValidationError> {
+        if self.domain_name().is_empty() {
+            return Err(PorkbunValidationError::MissingDomain);
+        }
+
+        if self.api_key.trim().is_empty() {
+            return Err(PorkbunValidationError::MissingApiKey);
+        }
+
+        if self.secret_api_key.trim().is_empty() {
+            return Err(PorkbunValidationError::MissingSecretApiKey);
+        }
+
+        if self.subdomains.is_empty() {
+            return Err(PorkbunValidationError::NoSubdomains);
+        }
+
+        // Validate rate limit configuration
+        if self.rate_limit.max_requests == 0 {
+            return Err(PorkbunValidationError::InvalidRateLimit(
+                "max_requests must be greater than 0".into(),
+            ));
+        }
+
+        if self.rate_limit.window_secs == 0 {
+            return Err(PorkbunValidationError::InvalidRateLimit(
+                "window_secs must be greater than 0".into(),
+            ));
+        }
+
+        for subdomain in &self.subdomains {
+            if !(PORKBUN_MIN_TTL..=PORKBUN_MAX_TTL).contains(&subdomain.ttl()) {
+                return Err(PorkbunValidationError::InvalidTtl {
+                    subdomain: subdomain.name.clone(),
+                    reason: format!(
+                        "must be between {} and {}, got {}",
+                        PORKBUN_MIN_TTL,
+                        PORKBUN_MAX_TTL,
+                        subdomain.ttl()
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl PorkbunSubDomain {
+    /// Returns the record TTL, falling back to Porkbun's minimum.
+    pub fn ttl(&self) -> u32 {
+        self.ttl.unwrap_or(PORKBUN_MIN_TTL)
+    }
+}
+
+#[async_trait]
+impl DnsProvider for Porkbun {
+    type Config = PorkbunConfig;
+    type Error = PorkbunError;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Self::new(config)
+    }
+
+    async fn update_dns_records_v4(&self, ip: &Ipv4Addr) -> Result<(), Self::Error> {
+        update_dns_records(self, &IpAddr::V4(*ip)).await
+    }
+
+    async fn update_dns_records_v6(&self, ip: &Ipv6Addr) -> Result<(), Self::Error> {
+        // Check if any subdomain needs IPv6
+        if !self.needs_ip_version(IpVersion::V6) {
+            return Ok(());
+        }
+        update_dns_records(self, &IpAddr::V6(*ip)).await
+    }
+
+    fn needs_ip_version(&self, version: IpVersion) -> bool {
+        self.config
+            .subdomains
+            .iter()
+            .any(|subdomain| subdomain.ip_version.includes(version))
+    }
+
+    async fn get_current_records(&self) -> Result<Vec<(String, IpAddr)>, Self::Error> {
+        fetch_current_records(self).await
+    }
+
+    fn expected_records(&self, ip: &IpAddr) -> Vec<(String, IpAddr)> {
+        self.config
+            .subdomains
+            .iter()
+            .filter(|subdomain| subdomain.ip_version.includes(IpVersion::from(ip)))
+            .map(|subdomain| (self.config.fqdn(subdomain.name.trim()), *ip))
+            .collect()
+    }
+
+    fn validate_config(&self) -> Result<(), Self::Error> {
+        // Basic validation
+        if self.config.api_key.is_empty()
+            || self.config.secret_api_key.is_empty()
+            || self.config.secret_api_key == "your_secret_api_key_here"
+        {
+            return Err(PorkbunError::InvalidCredentials(self.config.domain.clone()));
+        }
+        if self.config.subdomains.is_empty() {
+            return Err(PorkbunError::NoSubdomains(self.config.domain.clone()));
+        }
+
+        // Rate limit validation
+        if self.config.rate_limit.max_requests == 0 {
+            return Err(PorkbunError::InvalidRateLimit {
+                domain: self.config.domain.clone(),
+                reason: "max_requests must be greater than 0".to_string(),
+            });
+        }
+        if self.config.rate_limit.window_secs == 0 {
+            return Err(PorkbunError::InvalidRateLimit {
+                domain: self.config.domain.clone(),
+                reason: "window_secs must be greater than 0".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn update_interval(&self) -> Option<Duration> {
+        self.config.interval.map(Duration::from_secs)
+    }
+
+    async fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        Some(self.rate_limiter.status().await)
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    fn get_name(&self) -> &str {
+        &self.config.domain
+    }
+}
@@ -0,0 +1,123 @@
+// Standard library
+use std::path::PathBuf;
+use std::sync::Arc;
+
+// 3rd party crates
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+// Project modules
+use crate::providers::cloudflare::types::IpVersion;
+use crate::utility::rate_limiter::traits::RateLimiter;
+use crate::utility::rate_limiter::types::RateLimitConfig;
+
+/// Represents a client for the Porkbun DNS API.
+/// Every request is a POST whose JSON body carries the API key pair.
+/// Records are read with `retrieveByNameType`, changed with `editByNameType`
+/// and added with `create`.
+///
+/// The client includes built-in rate limiting to respect Porkbun's API limits.
+/// In dry-run mode records are still fetched, but never created or changed.
+pub struct Porkbun {
+    pub config: PorkbunConfig,
+    pub client: Client,
+    pub rate_limiter: Arc<dyn RateLimiter>,
+    pub dry_run: bool,
+}
+
+/// Configuration for a Porkbun domain.
+#[derive(Deserialize, Clone)]
+pub struct PorkbunConfig {
+    /// Whether this Porkbun configuration is enabled
+    pub enabled: bool,
+    /// Seconds between updates of this provider; defaults to `[update] interval`
+    #[serde(default)]
+    pub interval: Option<u64>,
+    /// The registered domain (e.g., "example.com")
+    pub domain: String,
+    /// API key, starting with "pk1_"
+    pub api_key: String,
+    /// Secret API key, starting with "sk1_"
+    #[serde(default)]
+    pub secret_api_key: String,
+    /// File holding the secret API key, e.g. a Docker secret; replaces `secret_api_key`
+    #[serde(default)]
+    pub secret_api_key_file: Option<PathBuf>,
+    /// Rate limiting configuration to respect Porkbun's API limits
+    #[serde(default = "default_rate_limit_config")]
+    pub rate_limit: RateLimitConfig,
+    /// List of subdomains to manage
+    pub subdomains: Vec<PorkbunSubDomain>,
+}
+
+fn default_rate_limit_config() -> RateLimitConfig {
+    RateLimitConfig {
+        max_requests: 60,
+        window_secs: 60,
+    }
+}
+
+/// Represents a subdomain configuration in Porkbun.
+/// An empty name represents the root domain.
+#[derive(Debug, Deserialize, Clone)]
+pub struct PorkbunSubDomain {
+    /// The subdomain name (e.g., "www" for www.example.com)
+    /// Leave empty for the root domain
+    #[serde(default)]
+    pub name: String,
+    /// Which IP versions to use for this subdomain
+    #[serde(default)]
+    pub ip_version: IpVersion,
+    /// Record TTL in seconds; defaults to 600
+    #[serde(default)]
+    pub ttl: Option<u32>,
+}
+
+/// The API key pair sent with every request.
+#[derive(Debug, Serialize)]
+pub struct PorkbunAuth<'a> {
+    pub apikey: &'a str,
+    pub secretapikey: &'a str,
+}
+
+/// Request body of `editByNameType`.
+#[derive(Debug, Serialize)]
+pub struct EditRecordRequest<'a> {
+    #[serde(flatten)]
+    pub auth: PorkbunAuth<'a>,
+    pub content: &'a str,
+    pub ttl: String,
+}
+
+/// Request body of `create`.
+#[derive(Debug, Serialize)]
+pub struct CreateRecordRequest<'a> {
+    #[serde(flatten)]
+    pub auth: PorkbunAuth<'a>,
+    /// Subdomain part of the record name, empty for the root domain
+    pub name: &'a str,
+    #[serde(rename = "type")]
+    pub record_type: &'a str,
+    pub content: &'a str,
+    pub ttl: String,
+}
+
+/// Common shape of every Porkbun response.
+#[derive(Debug, Deserialize)]
+pub struct PorkbunResponse {
+    /// "SUCCESS" or "ERROR"
+    pub status: String,
+    /// Error description, present when `status` is "ERROR"
+    #[serde(default)]
+    pub message: Option<String>,
+    /// Records returned by `retrieveByNameType`
+    #[serde(default)]
+    pub records: Vec<PorkbunRecord>,
+}
+
+/// A DNS record as returned by the API.
+#[derive(Debug, Deserialize)]
+pub struct PorkbunRecord {
+    /// The record content (IP address)
+    pub content: String,
+}
@@ -0,0 +1,15 @@
+pub const ROUTE53_API_BASE: &str = "https://route53.amazonaws.com/2013-04-01";
+pub const ROUTE53_HOST: &str = "route53.amazonaws.com";
+/// XML namespace of the 2013-04-01 Route53 API
+pub const ROUTE53_XMLNS: &str = "https://route53.amazonaws.com/doc/2013-04-01/";
+
+/// SigV4 signing settings
+pub const ROUTE53_SERVICE: &str = "route53";
+/// Route53 is a global service signed against us-east-1
+pub const ROUTE53_DEFAULT_REGION: &str = "us-east-1";
+pub const SIGV4_ALGORITHM: &str = "AWS4-HMAC-SHA256";
+
+/// TTL used when a subdomain does not set one
+pub const ROUTE53_DEFAULT_TTL: u32 = 300;
+/// Largest TTL Route53 accepts
+pub const ROUTE53_MAX_TTL: u32 = 2_147_483_647;
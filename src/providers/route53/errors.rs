@@ -0,0 +1,65 @@
+// 3rd party crates
+use thiserror::Error;
+
+/// Represents errors that can occur during Route53 API operations
+/// The variants mirror `CloudflareError` so every provider surfaces
+/// failures in the same shape to the update loop.
+#[derive(Debug, Error)]
+pub enum Route53Error {
+    #[error("Invalid AWS credentials for hosted zone '{0}'")]
+    InvalidCredentials(String),
+
+    #[error("No subdomains configured for hosted zone '{0}'")]
+    NoSubdomains(String),
+
+    #[error("HTTP client error: {0}")]
+    HttpClientBuild(#[from] reqwest::Error),
+
+    #[error("Invalid header value: {0}")]
+    InvalidHeaderValue(#[from] reqwest::header::InvalidHeaderValue),
+
+    #[error("Failed to fetch DNS records for hosted zone '{zone}': {message}")]
+    FetchFailed { zone: String, message: String },
+
+    #[error("Failed to change DNS record '{domain}' in hosted zone '{zone}': {message}")]
+    ChangeFailed {
+        zone: String,
+        domain: String,
+        message: String,
+    },
+
+    #[error("Rate limit exceeded for hosted zone '{0}'")]
+    RateLimited(String),
+
+    #[error("Route53 API throttled requests for hosted zone '{0}'")]
+    Throttled(String),
+
+    #[error("Invalid rate limit configuration for hosted zone '{zone}': {reason}")]
+    InvalidRateLimit { zone: String, reason: String },
+
+    #[error("Operation timed out for hosted zone '{zone}': {message}")]
+    Timeout { zone: String, message: String },
+
+    #[error("Validation error: {0}")]
+    Validation(#[from] Route53ValidationError),
+}
+
+#[derive(Debug, Error)]
+pub enum Route53ValidationError {
+    #[error("Missing or empty name")]
+    MissingName,
+    #[error("Missing or empty hosted_zone_id")]
+    MissingHostedZoneId,
+    #[error("Missing or empty access_key_id")]
+    MissingAccessKeyId,
+    #[error("Missing or empty secret_access_key, set secret_access_key or secret_access_key_file")]
+    MissingSecretAccessKey,
+    #[error("Missing or empty region")]
+    MissingRegion,
+    #[error("No subdomains configured")]
+    NoSubdomains,
+    #[error("Invalid rate limit: {0}")]
+    InvalidRateLimit(String),
+    #[error("Invalid TTL for subdomain '{subdomain}': {reason}")]
+    InvalidTtl { subdomain: String, reason: String },
+}
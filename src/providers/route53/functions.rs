@@ -0,0 +1,640 @@
+// Standard library
+use std::error::Error;
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+// 3rd party crates
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use quick_xml::escape::escape;
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+use reqwest::{Client, Method, StatusCode};
+use sha2::{Digest, Sha256};
+use tokio::sync::RwLockReadGuard;
+use tracing::{debug, error, info, warn};
+
+// Project modules
+use crate::providers::functions::record_content_matches;
+use crate::settings::types::{ConfigManager, HttpClientOptions, Settings};
+use crate::utility::ip_detector::types::IpVersion as DetectedIpVersion;
+
+// Current module imports
+use super::constants::{
+    ROUTE53_API_BASE, ROUTE53_HOST, ROUTE53_SERVICE, ROUTE53_XMLNS, SIGV4_ALGORITHM,
+};
+use super::errors::Route53Error;
+use super::types::{
+    ChangeAction, ErrorResponse, ListResourceRecordSetsResponse, ResourceRecordSet, Route53,
+    SignableRequest,
+};
+
+/// Creates the reqwest client used for Route53 requests.
+/// Every request is signed individually, so no default headers are set.
+pub fn create_reqwest_client(options: &HttpClientOptions) -> Result<Client, Route53Error> {
+    options.apply(Client::builder()).build().map_err(|e| {
+        error!("Failed to build HTTP client: {}", e);
+        Route53Error::HttpClientBuild(e)
+    })
+}
+
+/// Gets all enabled Route53 instances from the configuration.
+pub async fn get_route53s(config: Arc<ConfigManager>) -> Result<Vec<Route53>, Box<dyn Error>> {
+    let settings: RwLockReadGuard<Settings> = config.settings.read().await;
+    let http_options = settings.http_client_options();
+
+    let mut route53s = Vec::new();
+    for route53_config in settings.route53.iter() {
+        if route53_config.enabled {
+            match Route53::new(route53_config.clone())
+                .and_then(|route53| route53.with_http_options(&http_options))
+            {
+                Ok(route53) => route53s.push(route53.with_dry_run(settings.update.dry_run)),
+                Err(e) => error!("Failed to create Route53 instance: {}", e),
+            }
+        }
+    }
+    Ok(route53s)
+}
+
+/// Percent-encodes everything except the RFC 3986 unreserved characters,
+/// as SigV4 canonicalization requires.
+fn uri_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+fn sha256_hex(data: &str) -> String {
+    hex::encode(Sha256::digest(data.as_bytes()))
+}
+
+fn hmac_sha256(key: &[u8], data: &str) -> Vec<u8> {
+    // HMAC accepts keys of any length, so this cannot fail
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Computes the AWS Signature Version 4 `Authorization` header value.
+/// `request.headers` must hold every signed header with lowercase names,
+/// including `host` and `x-amz-date`.
+pub fn sigv4_authorization(
+    access_key_id: &str,
+    secret_access_key: &str,
+    region: &str,
+    service: &str,
+    request: &SignableRequest,
+    now: DateTime<Utc>,
+) -> String {
+    let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let date = now.format("%Y%m%d").to_string();
+
+    let mut headers: Vec<(&str, &str)> = request
+        .headers
+        .iter()
+        .map(|(name, value)| (*name, value.trim()))
+        .collect();
+    headers.sort_by_key(|(name, _)| *name);
+    let canonical_headers: String = headers
+        .iter()
+        .map(|(name, value)| format!("{}:{}\n", name, value))
+        .collect();
+    let signed_headers: String = headers
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(";");
+
+    let canonical_request = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}",
+        request.method,
+        request.path,
+        request.query,
+        canonical_headers,
+        signed_headers,
+        sha256_hex(request.payload)
+    );
+
+    let scope = format!("{}/{}/{}/aws4_request", date, region, service);
+    let string_to_sign = format!(
+        "{}\n{}\n{}\n{}",
+        SIGV4_ALGORITHM,
+        amz_date,
+        scope,
+        sha256_hex(&canonical_request)
+    );
+
+    let date_key = hmac_sha256(format!("AWS4{}", secret_access_key).as_bytes(), &date);
+    let region_key = hmac_sha256(&date_key, region);
+    let service_key = hmac_sha256(&region_key, service);
+    let signing_key = hmac_sha256(&service_key, "aws4_request");
+    let signature = hex::encode(hmac_sha256(&signing_key, &string_to_sign));
+
+    format!(
+        "{} Credential={}/{}, SignedHeaders={}, Signature={}",
+        SIGV4_ALGORITHM, access_key_id, scope, signed_headers, signature
+    )
+}
+
+/// Sends a signed request to the Route53 API and returns the status and body.
+/// `query` must be sorted by key; it is encoded once and used both for
+/// signing and in the URL so the two always match.
+async fn send_signed(
+    route53: &Route53,
+    method: Method,
+    path: &str,
+    query: &[(&str, &str)],
+    payload: String,
+) -> Result<(StatusCode, String), Route53Error> {
+    let config = &route53.config;
+    let zone = config.name.clone();
+    let canonical_query = query
+        .iter()
+        .map(|(key, value)| format!("{}={}", uri_encode(key), uri_encode(value)))
+        .collect::<Vec<_>>()
+        .join("&");
+
+    let now = Utc::now();
+    let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let session_token = config
+        .session_token
+        .as_deref()
+        .filter(|token| !token.trim().is_empty());
+
+    let mut signed: Vec<(&'static str, String)> =
+        vec![("host", ROUTE53_HOST.to_string()), ("x-amz-date", amz_date)];
+    if let Some(token) = session_token {
+        signed.push(("x-amz-security-token", token.to_string()));
+    }
+
+    // reqwest sets the host header itself, the rest are sent as signed
+    let mut headers = HeaderMap::new();
+    for (name, value) in signed.iter().filter(|(name, _)| *name != "host") {
+        let mut value = HeaderValue::from_str(value)?;
+        value.set_sensitive(*name == "x-amz-security-token");
+        headers.insert(*name, value);
+    }
+
+    let authorization = sigv4_authorization(
+        &config.access_key_id,
+        &config.secret_access_key,
+        &config.region,
+        ROUTE53_SERVICE,
+        &SignableRequest {
+            method: method.as_str(),
+            path,
+            query: &canonical_query,
+            headers: signed,
+            payload: &payload,
+        },
+        now,
+    );
+    let mut authorization = HeaderValue::from_str(&authorization)?;
+    authorization.set_sensitive(true);
+    headers.insert(AUTHORIZATION, authorization);
+    if !payload.is_empty() {
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/xml"));
+    }
+
+    let mut url = format!(
+        "{}{}",
+        ROUTE53_API_BASE,
+        path.trim_start_matches("/2013-04-01")
+    );
+    if !canonical_query.is_empty() {
+        url = format!("{}?{}", url, canonical_query);
+    }
+
+    debug!(
+        zone = %zone,
+        url = %url,
+        "Sending Route53 request"
+    );
+
+    let response = tokio::time::timeout(
+        Duration::from_secs(10),
+        route53
+            .client
+            .request(method, &url)
+            .headers(headers)
+            .body(payload)
+            .send(),
+    )
+    .await
+    .map_err(|_| Route53Error::Timeout {
+        zone: zone.clone(),
+        message: "Route53 request timed out".to_string(),
+    })??;
+
+    let status = response.status();
+    let body = response.text().await?;
+    Ok((status, body))
+}
+
+/// Turns an unsuccessful Route53 response into an error.
+/// Returns the API error message so callers can wrap it in their own variant.
+fn api_error(route53: &Route53, status: StatusCode, body: &str) -> Result<String, Route53Error> {
+    let zone = route53.config.name.clone();
+    let (code, message) = match quick_xml::de::from_str::<ErrorResponse>(body) {
+        Ok(response) => (response.error.code, response.error.message),
+        Err(_) => (String::new(), body.trim().to_string()),
+    };
+
+    match (status, code.as_str()) {
+        (StatusCode::TOO_MANY_REQUESTS, _) | (_, "Throttling" | "PriorRequestNotComplete") => {
+            warn!(
+                zone = %zone,
+                "Route53 API throttled the request"
+            );
+            Err(Route53Error::Throttled(zone))
+        }
+        (StatusCode::FORBIDDEN, _)
+        | (_, "InvalidClientTokenId" | "SignatureDoesNotMatch" | "AccessDenied") => {
+            error!(
+                zone = %zone,
+                "Route53 rejected the credentials: {}",
+                message
+            );
+            Err(Route53Error::InvalidCredentials(zone))
+        }
+        _ if code.is_empty() => Ok(format!("HTTP {}: {}", status, message)),
+        _ => Ok(format!("HTTP {} {}: {}", status, code, message)),
+    }
+}
+
+/// Fetches the record set with the given name and type, if it exists.
+/// `ListResourceRecordSets` starts listing at the given name, so the first
+/// result is only a match if its name and type are exactly the ones asked for.
+async fn fetch_record_set(
+    route53: &Route53,
+    fqdn: &str,
+    record_type: &str,
+) -> Result<Option<ResourceRecordSet>, Route53Error> {
+    let path = format!(
+        "/2013-04-01/hostedzone/{}/rrset",
+        uri_encode(route53.config.zone_id())
+    );
+    let query = [("maxitems", "1"), ("name", fqdn), ("type", record_type)];
+    let (status, body) = send_signed(route53, Method::GET, &path, &query, String::new()).await?;
+
+    if !status.is_success() {
+        let message = api_error(route53, status, &body)?;
+        return Err(Route53Error::FetchFailed {
+            zone: route53.config.name.clone(),
+            message,
+        });
+    }
+
+    debug!(
+        zone = %route53.config.name,
+        domain = %fqdn,
+        response = %body,
+        "Received record sets response"
+    );
+
+    let response: ListResourceRecordSetsResponse =
+        quick_xml::de::from_str(&body).map_err(|e| Route53Error::FetchFailed {
+            zone: route53.config.name.clone(),
+            message: format!("Failed to parse response: {}", e),
+        })?;
+
+    Ok(response
+        .resource_record_sets
+        .record_sets
+        .into_iter()
+        .find(|record_set| {
+            record_set.name.eq_ignore_ascii_case(fqdn) && record_set.record_type == record_type
+        }))
+}
+
+/// Creates or replaces a single-value record set.
+async fn change_record_set(
+    route53: &Route53,
+    action: ChangeAction,
+    fqdn: &str,
+    record_type: &str,
+    ttl: u32,
+    value: &str,
+) -> Result<(), Route53Error> {
+    let path = format!(
+        "/2013-04-01/hostedzone/{}/rrset/",
+        uri_encode(route53.config.zone_id())
+    );
+    let payload = format!(
+        concat!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+            r#"<ChangeResourceRecordSetsRequest xmlns="{}"><ChangeBatch>"#,
+            "<Comment>Updated by fariba-ddns</Comment>",
+            "<Changes><Change><Action>{}</Action><ResourceRecordSet>",
+            "<Name>{}</Name><Type>{}</Type><TTL>{}</TTL>",
+            "<ResourceRecords><ResourceRecord><Value>{}</Value></ResourceRecord></ResourceRecords>",
+            "</ResourceRecordSet></Change></Changes>",
+            "</ChangeBatch></ChangeResourceRecordSetsRequest>"
+        ),
+        ROUTE53_XMLNS,
+        action.as_str(),
+        escape(fqdn),
+        record_type,
+        ttl,
+        escape(value)
+    );
+
+    let (status, body) = send_signed(route53, Method::POST, &path, &[], payload).await?;
+    if !status.is_success() {
+        let message = api_error(route53, status, &body)?;
+        return Err(Route53Error::ChangeFailed {
+            zone: route53.config.name.clone(),
+            domain: fqdn.to_string(),
+            message,
+        });
+    }
+
+    Ok(())
+}
+
+/// Fetches the addresses every managed record currently holds.
+/// Alias records carry no values and are left out.
+pub async fn fetch_current_records(
+    route53: &Route53,
+) -> Result<Vec<(String, IpAddr)>, Route53Error> {
+    let mut current = Vec::new();
+    for subdomain in &route53.config.subdomains {
+        let fqdn = route53.config.fqdn(&subdomain.name);
+
+        for (version, record_type) in [
+            (DetectedIpVersion::V4, "A"),
+            (DetectedIpVersion::V6, "AAAA"),
+        ] {
+            if !subdomain.ip_version.includes(version) {
+                continue;
+            }
+            let Some(record_set) = route53
+                .with_rate_limit(fetch_record_set(route53, &fqdn, record_type))
+                .await?
+            else {
+                continue;
+            };
+            for record in &record_set.resource_records.records {
+                match record.value.trim().parse::<IpAddr>() {
+                    Ok(ip) => current.push((fqdn.clone(), ip)),
+                    Err(_) => warn!(
+                        zone = %route53.config.name,
+                        domain = %fqdn,
+                        "Ignoring {} record with non-IP content {}",
+                        record_type,
+                        record.value
+                    ),
+                }
+            }
+        }
+    }
+    Ok(current)
+}
+
+/// Creates or updates the record for one name so it holds exactly `ip`.
+/// Like the Cloudflare provider, the current record is fetched first and only
+/// written when it is missing or differs.
+async fn process_domain_record(
+    route53: &Route53,
+    fqdn: &str,
+    record_type: &str,
+    ttl: u32,
+    ip: &IpAddr,
+) -> Result<(), Route53Error> {
+    let content = ip.to_string();
+    let record_set = route53
+        .with_rate_limit(fetch_record_set(route53, fqdn, record_type))
+        .await?;
+
+    let action = match record_set {
+        None => {
+            if route53.dry_run {
+                info!(
+                    zone = %route53.config.name,
+                    domain = %fqdn,
+                    "Would create {} record with {}",
+                    record_type,
+                    content
+                );
+                return Ok(());
+            }
+
+            warn!(
+                zone = %route53.config.name,
+                domain = %fqdn,
+                "No DNS record found, attempting to create"
+            );
+            ChangeAction::Create
+        }
+        Some(record_set) => {
+            let values: Vec<&str> = record_set
+                .resource_records
+                .records
+                .iter()
+                .map(|record| record.value.trim())
+                .collect();
+            // Alias records carry no values; replacing them would break the alias
+            if values.is_empty() {
+                return Err(Route53Error::ChangeFailed {
+                    zone: route53.config.name.clone(),
+                    domain: fqdn.to_string(),
+                    message: "record is an alias and cannot be managed".to_string(),
+                });
+            }
+            if let [value] = values.as_slice() {
+                if record_content_matches(value, &content) {
+                    debug!(
+                        zone = %route53.config.name,
+                        domain = %fqdn,
+                        "DNS record already set to {}",
+                        content
+                    );
+                    return Ok(());
+                }
+            }
+
+            if route53.dry_run {
+                info!(
+                    zone = %route53.config.name,
+                    domain = %fqdn,
+                    "Would update {} from {} to {}",
+                    fqdn,
+                    values.join(", "),
+                    content
+                );
+                return Ok(());
+            }
+
+            info!(
+                zone = %route53.config.name,
+                domain = %fqdn,
+                "Updating DNS record from {} to {}",
+                values.join(", "),
+                content
+            );
+            ChangeAction::Upsert
+        }
+    };
+
+    route53
+        .with_rate_limit(change_record_set(
+            route53,
+            action,
+            fqdn,
+            record_type,
+            ttl,
+            &content,
+        ))
+        .await?;
+
+    info!(
+        zone = %route53.config.name,
+        domain = %fqdn,
+        "Successfully set DNS record to {}",
+        content
+    );
+    Ok(())
+}
+
+/// Updates DNS records for all configured subdomains.
+/// Every subdomain is attempted; the last error is returned if any failed.
+pub async fn update_dns_records(route53: &Route53, ip: &IpAddr) -> Result<(), Route53Error> {
+    let mut last_error: Option<Route53Error> = None;
+    let mut update_count = 0;
+
+    let record_type = match ip {
+        IpAddr::V4(_) => "A",
+        IpAddr::V6(_) => "AAAA",
+    };
+
+    for subdomain in &route53.config.subdomains {
+        if !subdomain.ip_version.includes(DetectedIpVersion::from(ip)) {
+            debug!(
+                zone = %route53.config.name,
+                subdomain = %subdomain.name,
+                ip_type = %record_type,
+                "Skipping DNS update - IP version not enabled for subdomain"
+            );
+            continue;
+        }
+
+        let fqdn = route53.config.fqdn(&subdomain.name);
+        info!(
+            zone = %route53.config.name,
+            domain = %fqdn,
+            record_type = %record_type,
+            "Processing DNS records"
+        );
+
+        match process_domain_record(route53, &fqdn, record_type, subdomain.ttl(), ip).await {
+            Ok(()) => update_count += 1,
+            Err(e) => {
+                error!(
+                    zone = %route53.config.name,
+                    domain = %fqdn,
+                    error = %e,
+                    "Failed to process DNS record"
+                );
+                last_error = Some(e);
+            }
+        }
+    }
+
+    if update_count > 0 {
+        info!(
+            zone = %route53.config.name,
+            count = update_count,
+            "Successfully processed {} DNS records",
+            update_count
+        );
+    }
+
+    match last_error {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Credentials and date shared by the AWS SigV4 test suite
+    const ACCESS_KEY_ID: &str = "AKIDEXAMPLE";
+    const SECRET_ACCESS_KEY: &str = "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY";
+
+    fn suite_date() -> DateTime<Utc> {
+        "2015-08-30T12:36:00Z".parse().unwrap()
+    }
+
+    #[test]
+    fn sigv4_matches_get_vanilla() {
+        let request = SignableRequest {
+            method: "GET",
+            path: "/",
+            query: "",
+            headers: vec![
+                ("host", "example.amazonaws.com".to_string()),
+                ("x-amz-date", "20150830T123600Z".to_string()),
+            ],
+            payload: "",
+        };
+        assert_eq!(
+            sigv4_authorization(
+                ACCESS_KEY_ID,
+                SECRET_ACCESS_KEY,
+                "us-east-1",
+                "service",
+                &request,
+                suite_date()
+            ),
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+             SignedHeaders=host;x-amz-date, \
+             Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+        );
+    }
+
+    #[test]
+    fn sigv4_sorts_headers_before_signing() {
+        let request = |headers| SignableRequest {
+            method: "GET",
+            path: "/",
+            query: "",
+            headers,
+            payload: "",
+        };
+        let sign = |request: &SignableRequest| {
+            sigv4_authorization(
+                ACCESS_KEY_ID,
+                SECRET_ACCESS_KEY,
+                "us-east-1",
+                "service",
+                request,
+                suite_date(),
+            )
+        };
+        let sorted = request(vec![
+            ("host", "example.amazonaws.com".to_string()),
+            ("x-amz-date", "20150830T123600Z".to_string()),
+        ]);
+        let unsorted = request(vec![
+            ("x-amz-date", "20150830T123600Z".to_string()),
+            ("host", "example.amazonaws.com".to_string()),
+        ]);
+        assert_eq!(sign(&sorted), sign(&unsorted));
+    }
+
+    #[test]
+    fn uri_encode_keeps_only_unreserved_characters() {
+        assert_eq!(uri_encode("a-Z_0.~"), "a-Z_0.~");
+        assert_eq!(uri_encode("www.example.com."), "www.example.com.");
+        assert_eq!(uri_encode("a b/*"), "a%20b%2F%2A");
+    }
+}
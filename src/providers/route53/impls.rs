@@ -0,0 +1,286 @@
+// Standard library
+use std::fmt;
+use std::future::Future;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::Arc;
+use std::time::Duration;
+
+// 3rd party crates
+use async_trait::async_trait;
+
+// Project modules
+use crate::providers::traits::DnsProvider;
+use crate::settings::types::HttpClientOptions;
+use crate::utility::ip_detector::types::IpVersion;
+use crate::utility::rate_limiter::types::{RateLimitStatus, TokenBucketRateLimiter};
+
+// Current module imports
+use super::constants::{ROUTE53_DEFAULT_TTL, ROUTE53_MAX_TTL};
+use super::errors::{Route53Error, Route53ValidationError};
+use super::functions::{create_reqwest_client, fetch_current_records, update_dns_records};
+use super::types::{ChangeAction, Route53, Route53Config, Route53SubDomain};
+
+// Manual Debug implementation for Route53
+impl fmt::Debug for Route53 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Route53")
+            .field("config", &self.config)
+            .field("client", &self.client)
+            .field("rate_limiter", &"<rate limiter>")
+            .field("dry_run", &self.dry_run)
+            .finish()
+    }
+}
+
+// Manual Clone implementation for Route53
+impl Clone for Route53 {
+    fn clone(&self) -> Self {
+        Self {
+            config: self.config.clone(),
+            client: self.client.clone(),
+            rate_limiter: Arc::clone(&self.rate_limiter),
+            dry_run: self.dry_run,
+        }
+    }
+}
+
+// Manual Debug implementation that keeps the secrets out of the logs
+impl fmt::Debug for Route53Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Route53Config")
+            .field("enabled", &self.enabled)
+            .field("interval", &self.interval)
+            .field("name", &self.name)
+            .field("hosted_zone_id", &self.hosted_zone_id)
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &"<redacted>")
+            .field("secret_access_key_file", &self.secret_access_key_file)
+            .field(
+                "session_token",
+                &self.session_token.as_ref().map(|_| "<redacted>"),
+            )
+            .field("region", &self.region)
+            .field("rate_limit", &self.rate_limit)
+            .field("subdomains", &self.subdomains)
+            .finish()
+    }
+}
+
+impl Route53 {
+    /// Creates a new Route53 instance with the provided configuration.
+    /// This will initialize the HTTP client and rate limiter.
+    pub fn new(config: Route53Config) -> Result<Self, Route53Error> {
+        let client = create_reqwest_client(&HttpClientOptions::default())?;
+        let rate_limiter = Arc::new(TokenBucketRateLimiter::new(config.rate_limit.clone()));
+
+        Ok(Self {
+            config,
+            client,
+            rate_limiter,
+            dry_run: false,
+        })
+    }
+
+    /// Rebuilds the HTTP client with the given User-Agent and proxy.
+    pub fn with_http_options(mut self, options: &HttpClientOptions) -> Result<Self, Route53Error> {
+        self.client = create_reqwest_client(options)?;
+        Ok(self)
+    }
+
+    /// Enables or disables dry-run mode.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Acquires a rate limit permit before making an API call.
+    /// This ensures we respect Route53's API rate limits.
+    pub async fn with_rate_limit<F, T, E>(&self, f: F) -> Result<T, E>
+    where
+        F: Future<Output = Result<T, E>>,
+        E: From<Route53Error>,
+    {
+        if !self.rate_limiter.acquire().await {
+            return Err(Route53Error::RateLimited(self.config.name.clone()).into());
+        }
+
+        f.await
+    }
+}
+
+impl Route53Config {
+    /// Returns the bare hosted zone ID, accepting the "/hostedzone/ID" form too.
+    pub fn zone_id(&self) -> &str {
+        self.hosted_zone_id
+            .trim()
+            .trim_start_matches("/hostedzone/")
+    }
+
+    /// Returns the fully qualified record name, with the trailing dot Route53 uses.
+    pub fn fqdn(&self, subdomain: &str) -> String {
+        let zone = self.name.trim().trim_end_matches('.');
+        if subdomain.is_empty() {
+            format!("{}.", zone)
+        } else {
+            format!("{}.{}.", subdomain, zone)
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), Route53ValidationError> {
+        if self.name.trim().is_empty() {
+            return Err(Route53ValidationError::MissingName);
+        }
+
+        if self.zone_id().is_empty() {
+            return Err(Route53ValidationError::MissingHostedZoneId);
+        }
+
+        if self.access_key_id.trim().is_empty() {
+            return Err(Route53ValidationError::MissingAccessKeyId);
+        }
+
+        if self.secret_access_key.trim().is_empty() {
+            return Err(Route53ValidationError::MissingSecretAccessKey);
+        }
+
+        if self.region.trim().is_empty() {
+            return Err(Route53ValidationError::MissingRegion);
+        }
+
+        if self.subdomains.is_empty() {
+            return Err(Route53ValidationError::NoSubdomains);
+        }
+
+        // Validate rate limit configuration
+        if self.rate_limit.max_requests == 0 {
+            return Err(Route53ValidationError::InvalidRateLimit(
+                "max_requests must be greater than 0".into(),
+            ));
+        }
+
+        if self.rate_limit.window_secs == 0 {
+            return Err(Route53ValidationError::InvalidRateLimit(
+                "window_secs must be greater than 0".into(),
+            ));
+        }
+
+        for subdomain in &self.subdomains {
+            if !(1..=ROUTE53_MAX_TTL).contains(&subdomain.ttl()) {
+                return Err(Route53ValidationError::InvalidTtl {
+                    subdomain: subdomain.name.clone(),
+                    reason: format!(
+                        "must be between 1 and {}, got {}",
+                        ROUTE53_MAX_TTL,
+                        subdomain.ttl()
+                    ),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Route53SubDomain {
+    /// Returns the record TTL, falling back to the default.
+    pub fn ttl(&self) -> u32 {
+        self.ttl.unwrap_or(ROUTE53_DEFAULT_TTL)
+    }
+}
+
+impl ChangeAction {
+    /// Returns the action name used in change batches.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Create => "CREATE",
+            Self::Upsert => "UPSERT",
+        }
+    }
+}
+
+#[async_trait]
+impl DnsProvider for Route53 {
+    type Config = Route53Config;
+    type Error = Route53Error;
+
+    fn new(config: Self::Config) -> Result<Self, Self::Error> {
+        Self::new(config)
+    }
+
+    async fn update_dns_records_v4(&self, ip: &Ipv4Addr) -> Result<(), Self::Error> {
+        update_dns_records(self, &IpAddr::V4(*ip)).await
+    }
+
+    async fn update_dns_records_v6(&self, ip: &Ipv6Addr) -> Result<(), Self::Error> {
+        // Check if any subdomain needs IPv6
+        if !self.needs_ip_version(IpVersion::V6) {
+            return Ok(());
+        }
+        update_dns_records(self, &IpAddr::V6(*ip)).await
+    }
+
+    fn needs_ip_version(&self, version: IpVersion) -> bool {
+        self.config
+            .subdomains
+            .iter()
+            .any(|subdomain| subdomain.ip_version.includes(version))
+    }
+
+    async fn get_current_records(&self) -> Result<Vec<(String, IpAddr)>, Self::Error> {
+        fetch_current_records(self).await
+    }
+
+    fn expected_records(&self, ip: &IpAddr) -> Vec<(String, IpAddr)> {
+        self.config
+            .subdomains
+            .iter()
+            .filter(|subdomain| subdomain.ip_version.includes(IpVersion::from(ip)))
+            .map(|subdomain| (self.config.fqdn(&subdomain.name), *ip))
+            .collect()
+    }
+
+    fn validate_config(&self) -> Result<(), Self::Error> {
+        // Basic validation
+        if self.config.access_key_id.is_empty()
+            || self.config.secret_access_key.is_empty()
+            || self.config.secret_access_key == "your_secret_access_key_here"
+        {
+            return Err(Route53Error::InvalidCredentials(self.config.name.clone()));
+        }
+        if self.config.subdomains.is_empty() {
+            return Err(Route53Error::NoSubdomains(self.config.name.clone()));
+        }
+
+        // Rate limit validation
+        if self.config.rate_limit.max_requests == 0 {
+            return Err(Route53Error::InvalidRateLimit {
+                zone: self.config.name.clone(),
+                reason: "max_requests must be greater than 0".to_string(),
+            });
+        }
+        if self.config.rate_limit.window_secs == 0 {
+            return Err(Route53Error::InvalidRateLimit {
+                zone: self.config.name.clone(),
+                reason: "window_secs must be greater than 0".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn update_interval(&self) -> Option<Duration> {
+        self.config.interval.map(Duration::from_secs)
+    }
+
+    async fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        Some(self.rate_limiter.status().await)
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    fn get_name(&self) -> &str {
+        &self.config.name
+    }
+}
@@ -0,0 +1,166 @@
+// Standard library
+use std::path::PathBuf;
+use std::sync::Arc;
+
+// 3rd party crates
+use reqwest::Client;
+use serde::Deserialize;
+
+// Project modules
+use crate::providers::cloudflare::types::IpVersion;
+use crate::utility::rate_limiter::traits::RateLimiter;
+use crate::utility::rate_limiter::types::RateLimitConfig;
+
+use super::constants::ROUTE53_DEFAULT_REGION;
+
+/// Represents a client for the AWS Route53 API.
+/// Requests are signed with AWS Signature Version 4, records are read with
+/// `ListResourceRecordSets` and written with `ChangeResourceRecordSets`.
+///
+/// The client includes built-in rate limiting to respect Route53's API limits.
+/// In dry-run mode records are still fetched, but never created or changed.
+pub struct Route53 {
+    pub config: Route53Config,
+    pub client: Client,
+    pub rate_limiter: Arc<dyn RateLimiter>,
+    pub dry_run: bool,
+}
+
+/// Configuration for a Route53 hosted zone.
+#[derive(Deserialize, Clone)]
+pub struct Route53Config {
+    /// Whether this Route53 configuration is enabled
+    pub enabled: bool,
+    /// Seconds between updates of this provider; defaults to `[update] interval`
+    #[serde(default)]
+    pub interval: Option<u64>,
+    /// The domain name of the hosted zone (e.g., "example.com")
+    pub name: String,
+    /// The hosted zone ID (e.g., "Z0123456789ABCDEFGHIJ")
+    pub hosted_zone_id: String,
+    /// Access key ID of an IAM user allowed to change the zone's records
+    pub access_key_id: String,
+    /// Secret access key belonging to `access_key_id`
+    #[serde(default)]
+    pub secret_access_key: String,
+    /// File holding the secret access key, e.g. a Docker secret; replaces `secret_access_key`
+    #[serde(default)]
+    pub secret_access_key_file: Option<PathBuf>,
+    /// Session token, only needed for temporary credentials
+    #[serde(default)]
+    pub session_token: Option<String>,
+    /// Region used for request signing
+    #[serde(default = "default_region")]
+    pub region: String,
+    /// Rate limiting configuration to respect Route53's API limits
+    #[serde(default = "default_rate_limit_config")]
+    pub rate_limit: RateLimitConfig,
+    /// List of subdomains to manage
+    pub subdomains: Vec<Route53SubDomain>,
+}
+
+fn default_region() -> String {
+    ROUTE53_DEFAULT_REGION.to_string()
+}
+
+fn default_rate_limit_config() -> RateLimitConfig {
+    RateLimitConfig {
+        max_requests: 5, // Route53 allows 5 requests per second per account
+        window_secs: 1,
+    }
+}
+
+/// Represents a subdomain configuration in Route53.
+/// An empty name represents the zone apex.
+#[derive(Debug, Deserialize, Clone)]
+pub struct Route53SubDomain {
+    /// The subdomain name (e.g., "www" for www.example.com)
+    /// Leave empty for the zone apex
+    #[serde(default)]
+    pub name: String,
+    /// Which IP versions to use for this subdomain
+    #[serde(default)]
+    pub ip_version: IpVersion,
+    /// Record TTL in seconds; defaults to 300
+    #[serde(default)]
+    pub ttl: Option<u32>,
+}
+
+/// Response of `ListResourceRecordSets`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListResourceRecordSetsResponse {
+    #[serde(default)]
+    pub resource_record_sets: ResourceRecordSets,
+}
+
+/// The `<ResourceRecordSets>` list.
+#[derive(Debug, Deserialize, Default)]
+pub struct ResourceRecordSets {
+    #[serde(rename = "ResourceRecordSet", default)]
+    pub record_sets: Vec<ResourceRecordSet>,
+}
+
+/// A record set, i.e. all values of one name and type.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ResourceRecordSet {
+    /// Fully qualified name with a trailing dot
+    pub name: String,
+    #[serde(rename = "Type")]
+    pub record_type: String,
+    #[serde(default)]
+    pub resource_records: ResourceRecords,
+}
+
+/// The `<ResourceRecords>` list.
+#[derive(Debug, Deserialize, Default)]
+pub struct ResourceRecords {
+    #[serde(rename = "ResourceRecord", default)]
+    pub records: Vec<ResourceRecord>,
+}
+
+/// A single record value.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ResourceRecord {
+    pub value: String,
+}
+
+/// Error body returned by the Route53 API.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ErrorResponse {
+    pub error: ErrorDetail,
+}
+
+/// Details of a Route53 API error.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ErrorDetail {
+    pub code: String,
+    #[serde(default)]
+    pub message: String,
+}
+
+/// Action of a single change in a `ChangeResourceRecordSets` batch.
+#[derive(Debug, Clone, Copy)]
+pub enum ChangeAction {
+    /// Create a record set that does not exist yet
+    Create,
+    /// Replace the values of an existing record set
+    Upsert,
+}
+
+/// The parts of an HTTP request covered by a SigV4 signature.
+#[derive(Debug)]
+pub struct SignableRequest<'a> {
+    pub method: &'a str,
+    /// Already URI-encoded absolute path
+    pub path: &'a str,
+    /// Canonical query string, sorted and URI-encoded
+    pub query: &'a str,
+    /// Signed headers with lowercase names
+    pub headers: Vec<(&'a str, String)>,
+    pub payload: &'a str,
+}
@@ -1,8 +1,15 @@
 // Standard library
+use std::error::Error;
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::time::Duration;
 
 // 3rd party crates
 use async_trait::async_trait;
+use tracing::debug;
+
+// Project imports
+use crate::utility::ip_detector::types::IpVersion;
+use crate::utility::rate_limiter::types::RateLimitStatus;
 
 /// Core trait that all DNS providers must implement.
 /// This trait defines the basic operations required for a DNS provider
@@ -51,6 +58,10 @@ use async_trait::async_trait;
 ///         Ok(())
 ///     }
 ///
+///     fn needs_ip_version(&self, version: IpVersion) -> bool {
+///         true
+///     }
+///
 ///     fn validate_config(&self) -> Result<(), Self::Error> {
 ///         // Validate API key and domains
 ///         Ok(())
@@ -148,23 +159,93 @@ pub trait DnsProvider: Send + Sync {
     /// Updates DNS records for all configured domains with either IPv4 or IPv6 address.
     ///
     /// This is a convenience method that delegates to either `update_dns_records_v4`
-    /// or `update_dns_records_v6` based on the IP address type.
+    /// or `update_dns_records_v6` based on the IP address type. If `previous`
+    /// already equals `ip`, the records are known to be current and no API
+    /// call is made.
     ///
     /// # Arguments
     ///
     /// * `ip` - The IP address (either v4 or v6) to set in DNS records
+    /// * `previous` - The address this provider last pushed successfully, if known
     ///
     /// # Returns
     ///
-    /// * `Ok(())` - All records updated successfully
+    /// * `Ok(())` - All records updated successfully, or already up to date
     /// * `Err(Self::Error)` - Update failed (partially or completely)
-    async fn update_dns_records_ip(&self, ip: &IpAddr) -> Result<(), Self::Error> {
+    async fn update_dns_records_ip(
+        &self,
+        ip: &IpAddr,
+        previous: Option<IpAddr>,
+    ) -> Result<(), Self::Error> {
+        if previous == Some(*ip) {
+            debug!(
+                provider = %self.get_name(),
+                "DNS records already hold {}, skipping update",
+                ip
+            );
+            return Ok(());
+        }
+
         match ip {
             IpAddr::V4(ipv4) => self.update_dns_records_v4(ipv4).await,
             IpAddr::V6(ipv6) => self.update_dns_records_v6(ipv6).await,
         }
     }
 
+    /// Checks whether any managed record needs the given IP version.
+    ///
+    /// The update loop uses this to decide which IP versions have to be
+    /// detected at all.
+    ///
+    /// # Returns
+    ///
+    /// * `true` - At least one record is updated with this IP version
+    /// * `false` - No record uses this IP version
+    fn needs_ip_version(&self, version: IpVersion) -> bool;
+
+    /// Fetches the address each managed record currently holds, without
+    /// changing anything.
+    ///
+    /// Providers that cannot read their records back keep the default,
+    /// which reports none.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(records)` - `(fully qualified name, address)` for every managed record found
+    /// * `Err(Self::Error)` - The records could not be fetched
+    async fn get_current_records(&self) -> Result<Vec<(String, IpAddr)>, Self::Error> {
+        Ok(vec![])
+    }
+
+    /// Lists the address records an update to `ip` would manage, each with
+    /// the address it would be set to.
+    ///
+    /// Compared with [`get_current_records`](Self::get_current_records),
+    /// this shows which records are out of date without changing them.
+    /// Providers that cannot read their records back keep the default,
+    /// which lists none.
+    ///
+    /// # Returns
+    ///
+    /// `(fully qualified name, address)` for every managed record of `ip`'s version
+    fn expected_records(&self, ip: &IpAddr) -> Vec<(String, IpAddr)> {
+        Vec::new()
+    }
+
+    /// Brings records that do not depend on the detected IP, such as TXT
+    /// records, to their configured content.
+    ///
+    /// The update loop calls this every cycle, whether or not an IP changed.
+    /// Providers without such records keep the default, which does nothing.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Every such record holds its configured content
+    /// * `Err(Self::Error)` - A record could not be read, created or updated
+    async fn sync_static_records(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
     /// Validates the provider's configuration.
     ///
     /// This method should check:
@@ -179,6 +260,30 @@ pub trait DnsProvider: Send + Sync {
     /// * `Err(Self::Error)` - Configuration is invalid
     fn validate_config(&self) -> Result<(), Self::Error>;
 
+    /// Returns this provider's own update interval, if it overrides the
+    /// global one.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(interval)` - Time between this provider's updates
+    /// * `None` - The provider follows `[update] interval`
+    fn update_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Reports how many API requests the provider's rate limiter would
+    /// allow right now, out of how many per window.
+    ///
+    /// Providers without a rate limiter keep the default.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(status)` - Requests that can be made without waiting, and the bucket size
+    /// * `None` - The provider does not rate limit its requests
+    async fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        None
+    }
+
     /// Checks if the provider is enabled.
     ///
     /// This allows providers to be conditionally enabled/disabled
@@ -202,3 +307,107 @@ pub trait DnsProvider: Send + Sync {
     /// A string slice containing the provider name
     fn get_name(&self) -> &str;
 }
+
+/// Object-safe view over any [`DnsProvider`].
+///
+/// `DnsProvider` has associated types and a constructor, so it cannot be used
+/// as a trait object. This trait boxes the provider-specific error, which lets
+/// the update loop hold every provider in one `Vec<Box<dyn AnyDnsProvider>>`.
+/// It is implemented automatically for all `DnsProvider` implementors.
+#[async_trait]
+pub trait AnyDnsProvider: Send + Sync {
+    /// See [`DnsProvider::update_dns_records_ip`].
+    async fn update_dns_records_ip(
+        &self,
+        ip: &IpAddr,
+        previous: Option<IpAddr>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+    /// See [`DnsProvider::needs_ip_version`].
+    fn needs_ip_version(&self, version: IpVersion) -> bool;
+
+    /// See [`DnsProvider::get_current_records`].
+    async fn get_current_records(
+        &self,
+    ) -> Result<Vec<(String, IpAddr)>, Box<dyn Error + Send + Sync>>;
+
+    /// See [`DnsProvider::expected_records`].
+    fn expected_records(&self, ip: &IpAddr) -> Vec<(String, IpAddr)>;
+
+    /// See [`DnsProvider::sync_static_records`].
+    async fn sync_static_records(&self) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+    /// See [`DnsProvider::validate_config`].
+    fn validate_config(&self) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+    /// See [`DnsProvider::update_interval`].
+    fn update_interval(&self) -> Option<Duration>;
+
+    /// See [`DnsProvider::rate_limit_status`].
+    async fn rate_limit_status(&self) -> Option<RateLimitStatus>;
+
+    /// See [`DnsProvider::is_enabled`].
+    fn is_enabled(&self) -> bool;
+
+    /// See [`DnsProvider::get_name`].
+    fn get_name(&self) -> &str;
+}
+
+#[async_trait]
+impl<T> AnyDnsProvider for T
+where
+    T: DnsProvider,
+    T::Error: 'static,
+{
+    async fn update_dns_records_ip(
+        &self,
+        ip: &IpAddr,
+        previous: Option<IpAddr>,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        DnsProvider::update_dns_records_ip(self, ip, previous)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)
+    }
+
+    fn needs_ip_version(&self, version: IpVersion) -> bool {
+        DnsProvider::needs_ip_version(self, version)
+    }
+
+    async fn get_current_records(
+        &self,
+    ) -> Result<Vec<(String, IpAddr)>, Box<dyn Error + Send + Sync>> {
+        DnsProvider::get_current_records(self)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)
+    }
+
+    fn expected_records(&self, ip: &IpAddr) -> Vec<(String, IpAddr)> {
+        DnsProvider::expected_records(self, ip)
+    }
+
+    async fn sync_static_records(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        DnsProvider::sync_static_records(self)
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)
+    }
+
+    fn validate_config(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        DnsProvider::validate_config(self).map_err(|e| Box::new(e) as Box<dyn Error + Send + Sync>)
+    }
+
+    fn update_interval(&self) -> Option<Duration> {
+        DnsProvider::update_interval(self)
+    }
+
+    async fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        DnsProvider::rate_limit_status(self).await
+    }
+
+    fn is_enabled(&self) -> bool {
+        DnsProvider::is_enabled(self)
+    }
+
+    fn get_name(&self) -> &str {
+        DnsProvider::get_name(self)
+    }
+}
@@ -0,0 +1,67 @@
+// Standard library
+use std::collections::HashMap;
+use std::error::Error;
+use std::net::IpAddr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// Project imports
+use crate::utility::ip_detector::types::IpVersion;
+
+/// How failed DNS record updates are retried.
+/// Delays grow exponentially from `base_delay` and are capped at `max_delay`.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Number of retries after the first attempt
+    pub max_retries: u32,
+    /// Delay before the first retry
+    pub base_delay: Duration,
+    /// Upper bound for any single delay
+    pub max_delay: Duration,
+}
+
+/// Bounds on how many provider updates run at once and for how long.
+#[derive(Debug, Clone, Copy)]
+pub struct UpdateLimits {
+    /// Provider updates allowed to run at the same time
+    pub max_concurrent: usize,
+    /// Time all provider updates of one pass may take together
+    pub update_timeout: Duration,
+    /// Time a single provider update may take, counted from when it starts
+    pub provider_timeout: Duration,
+    /// Time in-flight updates get to finish after a shutdown signal
+    pub shutdown_grace: Duration,
+}
+
+/// Outcome of pushing an IP to a single provider.
+#[derive(Debug)]
+pub struct ProviderUpdateResult {
+    /// Name of the provider (zone or domain set)
+    pub provider: String,
+    pub result: Result<(), Box<dyn Error + Send + Sync>>,
+}
+
+/// Outcome of pushing an IP to every enabled provider.
+/// Providers interrupted by a timeout or shutdown carry a [`ProviderError`](super::errors::ProviderError).
+#[derive(Debug, Default)]
+pub struct UpdateSummary {
+    pub results: Vec<ProviderUpdateResult>,
+}
+
+/// The last address each provider successfully pushed, per IP version.
+/// Providers are keyed by their position in the provider list, so the cache
+/// must be rebuilt together with that list.
+#[derive(Debug, Default)]
+pub struct PushedIps {
+    pub ips: Mutex<HashMap<(usize, IpVersion), IpAddr>>,
+}
+
+/// When each provider is next due for an update.
+/// Providers are keyed by their position in the provider list, like [`PushedIps`].
+#[derive(Debug)]
+pub struct UpdateSchedule {
+    /// Time between updates of each provider
+    pub intervals: Vec<Duration>,
+    /// When each provider is next due
+    pub next_due: Mutex<Vec<Instant>>,
+}
@@ -1,41 +1,282 @@
-/// Example configuration
+/// Prefix of environment variables that override settings, e.g. `FDDNS__UPDATE__INTERVAL`
+pub const ENV_PREFIX: &str = "FDDNS";
+/// Separates the prefix and the nested keys in override variables
+pub const ENV_SEPARATOR: &str = "__";
+
+/// User-Agent sent with every HTTP request unless `[update] user_agent` overrides it
+pub const DEFAULT_USER_AGENT: &str = concat!("fariba-ddns/", env!("CARGO_PKG_VERSION"));
+
+/// Quiet period after the last change to the configuration file before it is reloaded,
+/// so a file written in several steps is read once it is complete
+pub const CONFIG_WATCH_DEBOUNCE_MS: u64 = 500;
+
+/// Configuration written on first run.
+/// Every provider is disabled until the user fills in its credentials.
 pub const DEFAULT_CONFIG: &str = r#"
+# Fariba DDNS configuration
+# Nothing is updated until a provider is enabled: pick one of the provider
+# sections below, set enabled = true and fill in its credentials.
+
 # Logging configuration
 [log]
 # Level can be "error", "warn", "info", "debug", or "trace"
-level = "trace"
+level = "info"
+# "text" for human-readable lines, "json" for one JSON object per line
+format = "text"
 
 # Update interval in seconds
 [update]
 interval = 300
+# Intervals below 30 seconds are rejected unless this is set
+# allow_aggressive_interval = false
+# Random delay of up to this many seconds added to each interval (optional)
+# Spreads out requests when many instances run on the same schedule
+jitter_secs = 0
+# Log intended DNS changes without applying them
+dry_run = false
+# Retries for a failed DNS record update, with exponential backoff
+# starting at retry_base_delay_secs (capped at 30 seconds per delay)
+max_retries = 3
+retry_base_delay_secs = 2
+# Retries of a failed first IP detection, e.g. while the network comes up after
+# a reboot, starting startup_retry_delay_secs apart and doubling (0 to disable)
+startup_retries = 3
+startup_retry_delay_secs = 5
+# Seconds in-flight DNS updates may finish after Ctrl+C before they are cancelled
+shutdown_grace_secs = 5
+# Provider updates allowed to run at the same time
+max_concurrent_updates = 16
+# Seconds all provider updates of one cycle may take, and one provider's update
+# may take once it has started (at most update_timeout_secs, defaults to it)
+update_timeout_secs = 30
+# provider_timeout_secs = 10
+# Comment attached to the Cloudflare records this client creates or updates ("" for none)
+record_comment = "managed by fariba-ddns"
+# Leave Cloudflare records alone whose comment shows another owner
+respect_foreign_records = false
+# User-Agent for IP detection and DNS provider requests (default "fariba-ddns/<version>")
+# user_agent = "fariba-ddns"
+
+# IP detection configuration (optional)
+# [ip_detection]
+# "services" asks external services, "interface" reads a local interface address,
+# "dns" asks the OpenDNS and Google name servers (UDP port 53 must be allowed out)
+# method = "services"
+# interface = "eth0"
+# Accept private, CGNAT, unique-local and link-local addresses, which are
+# otherwise rejected whether read from an interface or returned by the services
+# allow_private_addresses = false
+# Never publish temporary (privacy extension) IPv6 addresses, which rotate;
+# with method = "interface" a stable address of the interface is used instead
+# reject_temporary_ipv6 = false
+# Use these addresses instead of detecting them, e.g. for a static IP
+# override_ipv4 = "203.0.113.10"
+# override_ipv6 = "2001:db8::10"
+# Push a changed IP only after it was detected this many cycles in a row,
+# for connections whose public IP flaps between addresses
+# stable_cycles = 1
+# Accept an IP at least two services agree on once this many seconds of a
+# detection passed without reaching min_consensus
+# accept_partial_after_secs = 20
+# Detection failures of this version ("v4" or "v6") are warnings, the other one's are debug messages
+# primary_version = "v4"
+# While the network is down, check again with backoff capped at this many seconds (1-30)
+# network_retry_interval = 30
+# Timeout in seconds for each detection service request (1-60)
+# request_timeout_secs = 5
+# Attempts per detection service query (1-10) and the delay between them in milliseconds
+# max_retries = 2
+# retry_delay_ms = 500
+# "consensus" waits for min_consensus agreeing services, "first" trusts the first valid answer
+# strategy = "consensus"
+# min_consensus = 4
+# Count each primary service response primary_weight times towards min_consensus
+# weighted_consensus = false
+# primary_weight = 2
+# Query only the custom services below instead of adding them to the built-in ones
+# replace_builtin_services = false
+# JSON fields the address is read from, for services without a json_field
+# json_ip_fields = ["ip", "address", "ipAddress", "query"]
+# Reject JSON answers without an address in json_ip_fields, or whose fields
+# disagree, instead of falling back to parsing the raw answer
+# strict_json = false
+# After the services agree, ask the OpenDNS (then Google) name servers for the
+# address as an independent check and warn if it differs; with
+# reject_dns_mismatch such an address is not published. UDP port 53 must be
+# allowed out; if DNS can't be reached the services' address is used
+# dns_cross_check = false
+# reject_dns_mismatch = false
+#
+# Services on a host that is already used are ignored, so each host counts once
+# [[ip_detection.custom_services]]
+# url = "https://ip.example.com"
+# path = "/"
+# is_primary = true
+# version = "v4"
+# For JSON answers: the field holding the address, dotted for nested fields
+# json_field = "data.ip"
 
 # Cloudflare provider configuration
 [[cloudflare]]
-enabled = true
+# Set to true once the zone and api_token below are filled in
+enabled = false
 name = "example"
+# Optional: omit zone_id to look it up by name (the token needs Zone - Zone - Read)
 zone_id = "your_zone_id"
+# Optional: seconds between updates of this provider, instead of [update] interval
+# interval = 3600
 api_token = "your_api_token"
+# Or read the token from a file, e.g. a Docker secret, instead of api_token
+# api_token_file = "/run/secrets/cloudflare_api_token"
+# Legacy Global API Key auth, instead of api_token
+# api_key = "your_global_api_key"
+# account_email = "you@example.com"
+# Optional: send API requests to a gateway or mock server instead of Cloudflare
+# api_base = "https://api.cloudflare.com/client/v4"
+# Optional: check the zone is active and matches `name` only before the first
+# update instead of before each one, saving an API call per update
+# verify_zone = true
 
 # Rate limiting configuration (optional)
 rate_limit = { max_requests = 30, window_secs = 60 }
 
+# Optional: defaults for every subdomain of this zone. A setting on a subdomain
+# wins over the one here, which wins over the built-in default
+# (automatic TTL, proxied = true, ip_version = "both")
+# [cloudflare.defaults]
+# ttl = 300
+# proxied = false
+# ip_version = "v4"
+
 # List of subdomains to update
 [[cloudflare.subdomains]]
 name = "www"
 # Optional: specify which IP versions to use (v4, v6, or both)
 # Default is "both" if not specified
 ip_version = "both"
+# Optional: set to false to publish the record without the Cloudflare proxy
+# proxied = true
 
 [[cloudflare.subdomains]]
 name = "ipv4-only"
 ip_version = "v4"
+# Optional: record TTL in seconds (30-86400)
+# Omit or set to 1 for Cloudflare's automatic TTL
+ttl = 300
+# Optional: set to false to skip this subdomain without removing it
+# enabled = false
+# Optional: "create-only" creates the record if it is missing, but never
+# changes an existing one; default "sync" keeps it at the detected address
+# manage_mode = "sync"
 
 [[cloudflare.subdomains]]
 name = "ipv6-only"
 ip_version = "v6"
+# Optional: publish <detected prefix>::1 instead of the detected address,
+# keeping the first ipv6_prefix_length bits (default 64) of the detected address
+# ipv6_suffix = "::1"
+# ipv6_prefix_length = 56
 
 [[cloudflare.subdomains]]
 # Empty name means root domain
 name = ""
 ip_version = "both"
+
+# CNAME records need no IP detection; target defaults to the zone root
+# [[cloudflare.subdomains]]
+# name = "blog"
+# record_type = "CNAME"
+# target = "example.com"
+
+# TXT records kept at a fixed value every cycle, e.g. for ACME challenges
+# [[cloudflare.txt_records]]
+# name = "_acme-challenge"
+# value = "challenge-token"
+
+# ArvanCloud provider configuration
+[[arvancloud]]
+enabled = false
+name = "example.ir"
+api_key = "your_api_key_here"
+
+# Rate limiting configuration (optional)
+rate_limit = { max_requests = 60, window_secs = 60 }
+
+# List of subdomains to update
+[[arvancloud.subdomains]]
+name = "www"
+# Optional: specify which IP versions to use (v4, v6, or both)
+# Default is "both" if not specified
+ip_version = "both"
+
+[[arvancloud.subdomains]]
+# Empty name means root domain
+name = ""
+ip_version = "v4"
+# Optional: record TTL in seconds (120-86400), default 120
+# ttl = 600
+
+# DuckDNS provider configuration
+[[duckdns]]
+enabled = false
+token = "your_token_here"
+# Domains with or without the ".duckdns.org" suffix
+domains = ["example"]
+# Optional: which IP versions to update (v4, v6, or both), defaults to "both"
+# ip_version = "both"
+
+# Route53 provider configuration
+[[route53]]
+enabled = false
+name = "example.com"
+hosted_zone_id = "your_hosted_zone_id"
+access_key_id = "your_access_key_id"
+secret_access_key = "your_secret_access_key_here"
+# session_token = "..."  # Only for temporary credentials
+region = "us-east-1"
+rate_limit = { max_requests = 5, window_secs = 1 }
+subdomains = [
+    { name = "" },  # Root domain
+    { name = "www", ip_version = "both", ttl = 300 },
+]
+
+# Porkbun provider configuration
+[[porkbun]]
+enabled = false
+domain = "example.com"
+api_key = "pk1_your_api_key"
+secret_api_key = "your_secret_api_key_here"
+rate_limit = { max_requests = 60, window_secs = 60 }
+subdomains = [
+    { name = "" },  # Root domain
+    # Optional: record TTL in seconds (600-86400), default 600
+    { name = "www", ip_version = "both", ttl = 600 },
+]
+
+# Outbound network settings (optional)
+# Without a proxy here, the HTTP_PROXY and HTTPS_PROXY environment variables are used
+# [network]
+# proxy = "http://proxy.example.com:3128"  # or "socks5://127.0.0.1:1080"
+
+# Metrics output (optional)
+# Writes the DNS update counters as JSON after every update cycle
+# [metrics]
+# json_path = "/var/lib/fddns/metrics.json"
+
+# Local status socket (optional, Unix only, read at startup)
+# Answers the line commands "get-ip v4", "get-ip v6" and "get-status" with one JSON line
+# [ipc]
+# socket = "/run/fddns.sock"
+# Recent events reported by "get-status" (0 keeps none)
+# event_log_size = 100
+
+# Notifications (optional)
+# POSTs {"version", "old", "new", "timestamp"} as JSON whenever a new IP is pushed
+# [notifications.webhook]
+# url = "https://example.com/ddns-hook"
+
+# Sends a Telegram message on IP changes and on DNS update failures
+# [notifications.telegram]
+# bot_token = "123456:your_bot_token"
+# chat_id = "123456789"
 "#;
@@ -1,8 +1,18 @@
+// Standard library
+use std::path::PathBuf;
+
 // 3rd party crates
 use thiserror::Error;
 
 // Project imports
+use crate::notifications::errors::NotificationValidationError;
+use crate::providers::arvancloud::errors::ArvanValidationError;
 use crate::providers::cloudflare::errors::CloudflareValidationError;
+use crate::providers::duckdns::errors::DuckDnsValidationError;
+#[cfg(feature = "mock-provider")]
+use crate::providers::mock::errors::MockValidationError;
+use crate::providers::porkbun::errors::PorkbunValidationError;
+use crate::providers::route53::errors::Route53ValidationError;
 use crate::utility::ip_detector::errors::IpDetectionValidationError;
 
 #[derive(Debug, Error)]
@@ -11,10 +21,55 @@ pub enum ValidationError {
     InvalidLogLevel(String),
     #[error("Update interval must be greater than 0, got {0}")]
     InvalidUpdateInterval(u64),
+    #[error(
+        "Update interval must be at least {min} seconds, got {got}; \
+         set allow_aggressive_interval = true to allow it"
+    )]
+    IntervalTooSmall { got: u64, min: u64 },
+    #[error("Retry base delay must be at most {max} seconds, got {got}")]
+    InvalidRetryDelay { got: u64, max: u64 },
+    #[error("Startup retry delay must be at most {max} seconds, got {got}")]
+    InvalidStartupRetryDelay { got: u64, max: u64 },
+    #[error("max_concurrent_updates must be greater than 0")]
+    InvalidMaxConcurrentUpdates,
+    #[error("update_timeout_secs must be greater than 0")]
+    InvalidUpdateTimeout,
+    #[error("provider_timeout_secs must be between 1 and update_timeout_secs ({max}), got {got}")]
+    InvalidProviderTimeout { got: u64, max: u64 },
+    #[error("Invalid proxy: {0}")]
+    InvalidProxy(String),
+    #[error("Both {0} and {0}_file are set; use only one of them")]
+    ConflictingSecret(String),
+    #[error("Failed to read {field}_file {path:?}: {reason}")]
+    UnreadableSecretFile {
+        field: String,
+        path: PathBuf,
+        reason: String,
+    },
     #[error("No providers are enabled")]
     NoProvidersEnabled,
     #[error("Cloudflare configuration error: {0}")]
     CloudflareConfig(#[from] CloudflareValidationError),
+    #[error("ArvanCloud configuration error: {0}")]
+    ArvanCloudConfig(#[from] ArvanValidationError),
+    #[error("DuckDNS configuration error: {0}")]
+    DuckDnsConfig(#[from] DuckDnsValidationError),
+    #[error("Route53 configuration error: {0}")]
+    Route53Config(#[from] Route53ValidationError),
+    #[error("Porkbun configuration error: {0}")]
+    PorkbunConfig(#[from] PorkbunValidationError),
+    #[cfg(feature = "mock-provider")]
+    #[error("Mock provider configuration error: {0}")]
+    MockConfig(#[from] MockValidationError),
     #[error("IP detection configuration error: {0}")]
     IpDetectionConfig(#[from] IpDetectionValidationError),
+    #[error("Notification configuration error: {0}")]
+    NotificationConfig(#[from] NotificationValidationError),
+}
+
+/// Reasons the configuration manager stops before loading any settings.
+#[derive(Debug, Error)]
+pub enum ConfigSetupError {
+    #[error("Created a default configuration file at {0}, please edit it and start again")]
+    DefaultConfigCreated(PathBuf),
 }
@@ -0,0 +1,193 @@
+// Standard library
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+// 3rd party crates
+use config::{Config, ConfigError, File, FileFormat};
+use log::{error, info, warn};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use serde_json::Value as JsonValue;
+use tokio::sync::{broadcast, mpsc};
+use tokio::time::timeout;
+use yaml_rust2::{Yaml, YamlEmitter};
+
+// Current module imports
+use super::constants::{CONFIG_WATCH_DEBOUNCE_MS, DEFAULT_CONFIG};
+use super::errors::ValidationError;
+use super::types::ConfigManager;
+
+/// Picks the configuration format from the file extension.
+/// Files without an extension are read as TOML.
+pub fn config_file_format(config_path: &Path) -> Result<FileFormat, ConfigError> {
+    let extension = config_path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_lowercase);
+    match extension.as_deref() {
+        None | Some("toml") => Ok(FileFormat::Toml),
+        Some("yaml") | Some("yml") => Ok(FileFormat::Yaml),
+        Some("json") => Ok(FileFormat::Json),
+        Some(other) => Err(ConfigError::Message(format!(
+            "Unsupported configuration file extension '.{}', use .toml, .yaml, .yml or .json",
+            other
+        ))),
+    }
+}
+
+/// Reads the secret `field` from `file`, if one is configured.
+/// The contents are trimmed, so a trailing newline doesn't end up in the secret.
+/// Fails if `value` is set as well, or the file can't be read.
+pub fn read_secret_file(
+    value: Option<&str>,
+    file: &Option<PathBuf>,
+    field: &str,
+) -> Result<Option<String>, ValidationError> {
+    let Some(path) = file else {
+        return Ok(None);
+    };
+    if value.is_some_and(|value| !value.trim().is_empty()) {
+        return Err(ValidationError::ConflictingSecret(field.to_string()));
+    }
+
+    fs::read_to_string(path)
+        .map(|contents| Some(contents.trim().to_string()))
+        .map_err(|e| ValidationError::UnreadableSecretFile {
+            field: field.to_string(),
+            path: path.clone(),
+            reason: e.to_string(),
+        })
+}
+
+/// Renders the default configuration in the given format.
+/// Only the TOML version keeps the explanatory comments.
+pub fn default_config(format: FileFormat) -> Result<String, ConfigError> {
+    if format == FileFormat::Toml {
+        return Ok(DEFAULT_CONFIG.to_string());
+    }
+
+    let defaults: JsonValue = Config::builder()
+        .add_source(File::from_str(DEFAULT_CONFIG, FileFormat::Toml))
+        .build()?
+        .try_deserialize()?;
+
+    match format {
+        FileFormat::Yaml => {
+            let mut yaml = String::new();
+            YamlEmitter::new(&mut yaml)
+                .dump(&json_to_yaml(defaults))
+                .map_err(|e| ConfigError::Message(format!("Failed to render YAML: {}", e)))?;
+            yaml.push('\n');
+            Ok(yaml)
+        }
+        _ => serde_json::to_string_pretty(&defaults)
+            .map(|json| json + "\n")
+            .map_err(|e| ConfigError::Message(format!("Failed to render JSON: {}", e))),
+    }
+}
+
+/// Converts a JSON value into the equivalent YAML node.
+fn json_to_yaml(value: JsonValue) -> Yaml {
+    match value {
+        JsonValue::Null => Yaml::Null,
+        JsonValue::Bool(value) => Yaml::Boolean(value),
+        JsonValue::Number(number) => match number.as_i64() {
+            Some(integer) => Yaml::Integer(integer),
+            None => Yaml::Real(number.to_string()),
+        },
+        JsonValue::String(value) => Yaml::String(value),
+        JsonValue::Array(values) => Yaml::Array(values.into_iter().map(json_to_yaml).collect()),
+        JsonValue::Object(entries) => Yaml::Hash(
+            entries
+                .into_iter()
+                .map(|(key, value)| (Yaml::String(key), json_to_yaml(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Reloads the configuration whenever its file changes on disk, until shutdown.
+/// The directory is watched rather than the file, as many editors replace the file
+/// on save. A file that fails validation leaves the current settings in place.
+pub async fn watch_config_file(config: Arc<ConfigManager>, mut shutdown: broadcast::Receiver<()>) {
+    let config_path = config.config_path.clone();
+    let Some(file_name) = config_path.file_name().map(OsStr::to_os_string) else {
+        return;
+    };
+    let directory = match config_path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+
+    let (events_tx, mut events_rx) = mpsc::unbounded_channel();
+    let watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+        let _ = events_tx.send(event);
+    });
+    let mut watcher = match watcher {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            warn!(
+                "Failed to watch the configuration file, reload with SIGHUP instead: {}",
+                e
+            );
+            return;
+        }
+    };
+    if let Err(e) = watcher.watch(&directory, RecursiveMode::NonRecursive) {
+        warn!(
+            "Failed to watch {:?}, reload with SIGHUP instead: {}",
+            directory, e
+        );
+        return;
+    }
+    info!("Watching {:?} for configuration changes", config_path);
+
+    let debounce = Duration::from_millis(CONFIG_WATCH_DEBOUNCE_MS);
+    loop {
+        tokio::select! {
+            _ = shutdown.recv() => return,
+            event = events_rx.recv() => match event {
+                Some(event) if changes_file(&event, &file_name) => {}
+                Some(_) => continue,
+                None => return,
+            },
+        }
+
+        // Wait until the file has been quiet for a while before reading it
+        loop {
+            match timeout(debounce, events_rx.recv()).await {
+                Ok(Some(_)) => continue,
+                Ok(None) => return,
+                Err(_) => break,
+            }
+        }
+
+        info!("Configuration file changed, reloading...");
+        if let Err(e) = config.reload().await {
+            error!(
+                "Failed to reload configuration, keeping the current one: {}",
+                e
+            );
+        }
+    }
+}
+
+/// Whether a watcher event writes, creates, renames or removes the named file.
+/// Reads are ignored, so reloading does not trigger another reload.
+fn changes_file(event: &notify::Result<Event>, file_name: &OsStr) -> bool {
+    match event {
+        Ok(event) => {
+            !matches!(event.kind, EventKind::Access(_))
+                && event
+                    .paths
+                    .iter()
+                    .any(|path| path.file_name() == Some(file_name))
+        }
+        Err(e) => {
+            warn!("Configuration file watcher error: {}", e);
+            false
+        }
+    }
+}
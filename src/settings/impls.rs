@@ -1,23 +1,161 @@
 // Standard library
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::{env, fs};
 
 // 3rd party crates
-use config::{Config, ConfigError, File};
+use config::{Config, ConfigError, Environment, File, Map, Source, Value};
 use log::{error, info, LevelFilter};
-use tokio::sync::RwLock;
+use reqwest::{ClientBuilder, Proxy, Url};
+use tokio::sync::{watch, RwLock};
+
+// Project imports
+use crate::providers::constants::{MAX_RETRY_DELAY_SECS, MIN_UPDATE_INTERVAL_SECS};
+use crate::providers::duckdns::constants::DUCKDNS_DOMAIN_SUFFIX;
 
 // Current module imports
-use super::constants::DEFAULT_CONFIG;
-use super::errors::ValidationError;
-use super::types::{ConfigManager, Settings, ValidatedSettings};
+use super::constants::{DEFAULT_USER_AGENT, ENV_PREFIX, ENV_SEPARATOR};
+use super::errors::{ConfigSetupError, ValidationError};
+use super::functions::{config_file_format, default_config, read_secret_file};
+use super::types::{
+    ConfigManager, EnvOverrides, HttpClientOptions, LogFormat, Network, Settings, ValidatedSettings,
+};
 
 impl Settings {
     pub fn get_log_level(&self) -> String {
         self.log.level.to_lowercase()
     }
 
+    /// Whether any provider is enabled.
+    /// Settings without one are valid, so a fresh default configuration
+    /// loads, but there is nothing to update.
+    pub fn has_enabled_provider(&self) -> bool {
+        let has_enabled_provider = self.cloudflare.iter().any(|cf| cf.enabled)
+            || self.arvancloud.iter().any(|arvan| arvan.enabled)
+            || self.duckdns.iter().any(|duckdns| duckdns.enabled)
+            || self.route53.iter().any(|route53| route53.enabled)
+            || self.porkbun.iter().any(|porkbun| porkbun.enabled);
+        #[cfg(feature = "mock-provider")]
+        let has_enabled_provider =
+            has_enabled_provider || self.mock.iter().any(|mock| mock.enabled);
+        has_enabled_provider
+    }
+
+    /// Names of the enabled provider configurations, as each provider reports
+    /// itself through `get_name`.
+    pub fn enabled_provider_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+        names.extend(
+            self.cloudflare
+                .iter()
+                .filter(|cf| cf.enabled)
+                .map(|cf| cf.name.clone()),
+        );
+        names.extend(
+            self.arvancloud
+                .iter()
+                .filter(|arvan| arvan.enabled)
+                .map(|arvan| arvan.name.clone()),
+        );
+        names.extend(
+            self.duckdns
+                .iter()
+                .filter(|duckdns| duckdns.enabled)
+                .map(|_| "duckdns".to_string()),
+        );
+        names.extend(
+            self.route53
+                .iter()
+                .filter(|route53| route53.enabled)
+                .map(|route53| route53.name.clone()),
+        );
+        names.extend(
+            self.porkbun
+                .iter()
+                .filter(|porkbun| porkbun.enabled)
+                .map(|porkbun| porkbun.domain.clone()),
+        );
+        #[cfg(feature = "mock-provider")]
+        names.extend(
+            self.mock
+                .iter()
+                .filter(|mock| mock.enabled)
+                .map(|mock| mock.name.clone()),
+        );
+        names
+    }
+
+    /// Finds domains managed by more than one enabled provider configuration,
+    /// e.g. the same subdomain listed under two Cloudflare zones.
+    /// Each such domain is returned with the configurations managing it.
+    pub fn overlapping_domains(&self) -> BTreeMap<String, Vec<String>> {
+        fn fqdn(subdomain: &str, zone: &str) -> String {
+            let zone = zone.trim().trim_end_matches('.');
+            let name = match subdomain.trim().trim_end_matches('.') {
+                "" | "@" => zone.to_string(),
+                subdomain => format!("{}.{}", subdomain, zone),
+            };
+            name.to_ascii_lowercase()
+        }
+
+        let mut configs: Vec<(String, BTreeSet<String>)> = Vec::new();
+        for cf in self.cloudflare.iter().filter(|cf| cf.enabled) {
+            let domains = cf
+                .subdomains
+                .iter()
+                .filter(|subdomain| subdomain.enabled)
+                .map(|subdomain| fqdn(&subdomain.name, &cf.name))
+                .collect();
+            configs.push((format!("Cloudflare zone {}", cf.name), domains));
+        }
+        for arvan in self.arvancloud.iter().filter(|arvan| arvan.enabled) {
+            let domains = arvan
+                .subdomains
+                .iter()
+                .map(|subdomain| fqdn(&subdomain.name, &arvan.name))
+                .collect();
+            configs.push((format!("ArvanCloud zone {}", arvan.name), domains));
+        }
+        for duckdns in self.duckdns.iter().filter(|duckdns| duckdns.enabled) {
+            let domains = duckdns
+                .domains
+                .iter()
+                .map(|domain| {
+                    let domain = domain.trim().to_ascii_lowercase();
+                    let name = domain.trim_end_matches(DUCKDNS_DOMAIN_SUFFIX);
+                    fqdn(name, DUCKDNS_DOMAIN_SUFFIX.trim_start_matches('.'))
+                })
+                .collect();
+            configs.push(("DuckDNS".to_string(), domains));
+        }
+        for route53 in self.route53.iter().filter(|route53| route53.enabled) {
+            let domains = route53
+                .subdomains
+                .iter()
+                .map(|subdomain| fqdn(&subdomain.name, &route53.name))
+                .collect();
+            configs.push((format!("Route53 zone {}", route53.name), domains));
+        }
+        for porkbun in self.porkbun.iter().filter(|porkbun| porkbun.enabled) {
+            let domains = porkbun
+                .subdomains
+                .iter()
+                .map(|subdomain| fqdn(&subdomain.name, &porkbun.domain))
+                .collect();
+            configs.push((format!("Porkbun domain {}", porkbun.domain), domains));
+        }
+
+        let mut managers: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for (config, domains) in configs {
+            for domain in domains {
+                managers.entry(domain).or_default().push(config.clone());
+            }
+        }
+        managers.retain(|_, configs| configs.len() > 1);
+        managers
+    }
+
     pub fn validate(&self) -> Result<(), ValidationError> {
         // Validate log level
         match self.log.level.to_lowercase().as_str() {
@@ -25,36 +163,284 @@ impl Settings {
             _ => return Err(ValidationError::InvalidLogLevel(self.log.level.clone())),
         }
 
-        // Validate update interval
-        if self.update.interval == 0 {
-            return Err(ValidationError::InvalidUpdateInterval(self.update.interval));
+        // Validate update interval, and the providers' own intervals the same way
+        self.validate_interval(self.update.interval)?;
+        let provider_intervals = self
+            .cloudflare
+            .iter()
+            .filter(|cf| cf.enabled)
+            .map(|cf| cf.interval)
+            .chain(
+                self.arvancloud
+                    .iter()
+                    .filter(|arvan| arvan.enabled)
+                    .map(|arvan| arvan.interval),
+            )
+            .chain(
+                self.duckdns
+                    .iter()
+                    .filter(|duckdns| duckdns.enabled)
+                    .map(|duckdns| duckdns.interval),
+            )
+            .chain(
+                self.route53
+                    .iter()
+                    .filter(|route53| route53.enabled)
+                    .map(|route53| route53.interval),
+            )
+            .chain(
+                self.porkbun
+                    .iter()
+                    .filter(|porkbun| porkbun.enabled)
+                    .map(|porkbun| porkbun.interval),
+            );
+        #[cfg(feature = "mock-provider")]
+        let provider_intervals = provider_intervals.chain(
+            self.mock
+                .iter()
+                .filter(|mock| mock.enabled)
+                .map(|mock| mock.interval),
+        );
+        for interval in provider_intervals.flatten() {
+            self.validate_interval(interval)?;
+        }
+
+        // Validate retry delay
+        if self.update.retry_base_delay_secs > MAX_RETRY_DELAY_SECS {
+            return Err(ValidationError::InvalidRetryDelay {
+                got: self.update.retry_base_delay_secs,
+                max: MAX_RETRY_DELAY_SECS,
+            });
+        }
+
+        // Validate the startup retry delay
+        if self.update.startup_retry_delay_secs > MAX_RETRY_DELAY_SECS {
+            return Err(ValidationError::InvalidStartupRetryDelay {
+                got: self.update.startup_retry_delay_secs,
+                max: MAX_RETRY_DELAY_SECS,
+            });
         }
 
-        // Validate that at least one provider is enabled
-        let has_enabled_provider = self.cloudflare.iter().any(|cf| cf.enabled);
-        if !has_enabled_provider {
-            return Err(ValidationError::NoProvidersEnabled);
+        // Validate the provider update concurrency
+        if self.update.max_concurrent_updates == 0 {
+            return Err(ValidationError::InvalidMaxConcurrentUpdates);
         }
 
+        // Validate the update timeouts; a provider can't outlast the whole pass
+        if self.update.update_timeout_secs == 0 {
+            return Err(ValidationError::InvalidUpdateTimeout);
+        }
+        if let Some(provider_timeout_secs) = self.update.provider_timeout_secs {
+            if provider_timeout_secs == 0 || provider_timeout_secs > self.update.update_timeout_secs
+            {
+                return Err(ValidationError::InvalidProviderTimeout {
+                    got: provider_timeout_secs,
+                    max: self.update.update_timeout_secs,
+                });
+            }
+        }
+
+        // Validate the proxy URL
+        self.network.proxy()?;
+
         // Validate each enabled Cloudflare config
         for cf_config in self.cloudflare.iter().filter(|cf| cf.enabled) {
             cf_config.validate()?;
         }
 
+        // Validate each enabled ArvanCloud config
+        for arvan_config in self.arvancloud.iter().filter(|arvan| arvan.enabled) {
+            arvan_config.validate()?;
+        }
+
+        // Validate each enabled DuckDNS config
+        for duckdns_config in self.duckdns.iter().filter(|duckdns| duckdns.enabled) {
+            duckdns_config.validate()?;
+        }
+
+        // Validate each enabled Route53 config
+        for route53_config in self.route53.iter().filter(|route53| route53.enabled) {
+            route53_config.validate()?;
+        }
+
+        // Validate each enabled Porkbun config
+        for porkbun_config in self.porkbun.iter().filter(|porkbun| porkbun.enabled) {
+            porkbun_config.validate()?;
+        }
+
+        // Validate each enabled mock config
+        #[cfg(feature = "mock-provider")]
+        for mock_config in self.mock.iter().filter(|mock| mock.enabled) {
+            mock_config.validate()?;
+        }
+
         // Validate IP detection configuration
         self.ip_detection.validate()?;
 
+        // Validate notification configuration
+        self.notifications.validate()?;
+
         Ok(())
     }
 }
 
+impl Settings {
+    /// Resolves provider-level defaults into the records they apply to,
+    /// so everything after loading sees each record's effective settings.
+    pub fn apply_defaults(&mut self) {
+        for cf in &mut self.cloudflare {
+            cf.apply_defaults();
+        }
+    }
+
+    /// Replaces each provider secret configured through a `*_file` setting
+    /// with the trimmed contents of that file. Setting both a secret and its
+    /// file is rejected, so it is always clear which one is used.
+    pub fn load_secret_files(&mut self) -> Result<(), ValidationError> {
+        for cf in &mut self.cloudflare {
+            if let Some(api_token) =
+                read_secret_file(cf.api_token.as_deref(), &cf.api_token_file, "api_token")?
+            {
+                cf.api_token = Some(api_token);
+            }
+        }
+        for arvan in &mut self.arvancloud {
+            if let Some(api_key) =
+                read_secret_file(Some(&arvan.api_key), &arvan.api_key_file, "api_key")?
+            {
+                arvan.api_key = api_key;
+            }
+        }
+        for duckdns in &mut self.duckdns {
+            if let Some(token) =
+                read_secret_file(Some(&duckdns.token), &duckdns.token_file, "token")?
+            {
+                duckdns.token = token;
+            }
+        }
+        for route53 in &mut self.route53 {
+            if let Some(secret_access_key) = read_secret_file(
+                Some(&route53.secret_access_key),
+                &route53.secret_access_key_file,
+                "secret_access_key",
+            )? {
+                route53.secret_access_key = secret_access_key;
+            }
+        }
+        for porkbun in &mut self.porkbun {
+            if let Some(secret_api_key) = read_secret_file(
+                Some(&porkbun.secret_api_key),
+                &porkbun.secret_api_key_file,
+                "secret_api_key",
+            )? {
+                porkbun.secret_api_key = secret_api_key;
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks an update interval against the minimum, which
+    /// `allow_aggressive_interval` lifts.
+    fn validate_interval(&self, interval: u64) -> Result<(), ValidationError> {
+        if interval == 0 {
+            return Err(ValidationError::InvalidUpdateInterval(interval));
+        }
+        if interval < MIN_UPDATE_INTERVAL_SECS && !self.update.allow_aggressive_interval {
+            return Err(ValidationError::IntervalTooSmall {
+                got: interval,
+                min: MIN_UPDATE_INTERVAL_SECS,
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns the options the outbound HTTP clients are built with.
+    /// The proxy was checked by `validate`, so an invalid one is left out.
+    pub fn http_client_options(&self) -> HttpClientOptions {
+        HttpClientOptions {
+            user_agent: self.update.user_agent.clone(),
+            proxy: self.network.proxy().ok().flatten(),
+        }
+    }
+}
+
+impl Network {
+    /// Parses the configured proxy URL.
+    /// Only http, https, socks5 and socks5h proxies are supported.
+    pub fn proxy(&self) -> Result<Option<Proxy>, ValidationError> {
+        let Some(proxy) = self.proxy.as_deref().map(str::trim) else {
+            return Ok(None);
+        };
+        // The URL may hold credentials, so it is not repeated in the errors
+        let url = Url::parse(proxy)
+            .map_err(|e| ValidationError::InvalidProxy(format!("not a valid URL: {}", e)))?;
+        if !matches!(url.scheme(), "http" | "https" | "socks5" | "socks5h") {
+            return Err(ValidationError::InvalidProxy(format!(
+                "unsupported scheme '{}', use http, https, socks5 or socks5h",
+                url.scheme()
+            )));
+        }
+        if url.host_str().is_none_or(str::is_empty) {
+            return Err(ValidationError::InvalidProxy("missing host".into()));
+        }
+        Proxy::all(url)
+            .map(Some)
+            .map_err(|e| ValidationError::InvalidProxy(e.to_string()))
+    }
+}
+
+impl Default for HttpClientOptions {
+    fn default() -> Self {
+        Self {
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            proxy: None,
+        }
+    }
+}
+
+impl HttpClientOptions {
+    /// Applies the User-Agent and proxy to a client under construction.
+    pub fn apply(&self, builder: ClientBuilder) -> ClientBuilder {
+        let builder = builder.user_agent(&self.user_agent);
+        match &self.proxy {
+            Some(proxy) => builder.proxy(proxy.clone()),
+            None => builder,
+        }
+    }
+}
+
 impl ConfigManager {
     /// Creates a new `ConfigManager` instance by loading and validating the configuration.
-    pub async fn new() -> Result<Self, Box<dyn std::error::Error>> {
-        let config_path: PathBuf = Self::get_config_path()?;
-        Self::ensure_config_file_exists(&config_path)?;
+    /// An explicit `config_path` (e.g. from the command line) overrides the default lookup.
+    /// If no file exists yet, a default one is written and
+    /// [`ConfigSetupError::DefaultConfigCreated`] is returned, since the
+    /// defaults need editing before they are of any use.
+    pub async fn new(config_path: Option<PathBuf>) -> Result<Self, Box<dyn std::error::Error>> {
+        let config_path: PathBuf = Self::get_config_path(config_path)?;
+        if Self::ensure_config_file_exists(&config_path)? {
+            return Err(Box::new(ConfigSetupError::DefaultConfigCreated(
+                config_path,
+            )));
+        }
+        Self::open(config_path).await
+    }
 
-        let settings: Settings = Self::load_settings(&config_path)?;
+    /// Like [`ConfigManager::new`], but fails instead of creating a default
+    /// configuration file when none exists.
+    pub async fn load_existing(
+        config_path: Option<PathBuf>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let config_path: PathBuf = Self::get_config_path(config_path)?;
+        if !config_path.exists() {
+            return Err(format!("Configuration file not found: {}", config_path.display()).into());
+        }
+        Self::open(config_path).await
+    }
+
+    /// Loads and validates the settings from an existing configuration file.
+    async fn open(config_path: PathBuf) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut settings: Settings = Self::load_settings(&config_path)?;
+        settings.load_secret_files()?;
 
         // Validate settings before proceeding
         let validated_settings = ValidatedSettings::new(settings).map_err(|e| {
@@ -64,7 +450,10 @@ impl ConfigManager {
 
         let manager = ConfigManager {
             settings: Arc::new(RwLock::new(validated_settings.into_inner())),
-            _config_path: config_path,
+            config_path,
+            reloads: watch::Sender::new(()),
+            forced_dry_run: false,
+            forced_respect_foreign_records: false,
         };
 
         manager.adjust_logging_level().await;
@@ -73,8 +462,11 @@ impl ConfigManager {
     }
 
     /// Determines the configuration file path.
-    fn get_config_path() -> Result<PathBuf, ConfigError> {
-        if let Ok(path) = env::var("FDDNS_CONFIG_PATH") {
+    /// Precedence: explicit path > `FDDNS_CONFIG_PATH` > platform config directory.
+    pub fn get_config_path(config_path: Option<PathBuf>) -> Result<PathBuf, ConfigError> {
+        if let Some(path) = config_path {
+            Ok(path)
+        } else if let Ok(path) = env::var("FDDNS_CONFIG_PATH") {
             Ok(PathBuf::from(path))
         } else if let Some(config_dir) = dirs::config_dir() {
             Ok(config_dir.join("fddns").join("config.toml"))
@@ -86,8 +478,11 @@ impl ConfigManager {
     }
 
     /// Ensures that the configuration file exists, creating it if necessary.
-    fn ensure_config_file_exists(config_path: &Path) -> Result<(), ConfigError> {
+    /// A new file is written in the format matching its extension.
+    /// Returns whether the file was created.
+    fn ensure_config_file_exists(config_path: &Path) -> Result<bool, ConfigError> {
         if !config_path.exists() {
+            let contents: String = default_config(config_file_format(config_path)?)?;
             if let Some(parent_dir) = config_path.parent() {
                 fs::create_dir_all(parent_dir).map_err(|e| {
                     let msg: String = format!("Failed to create configuration directory: {}", e);
@@ -95,36 +490,50 @@ impl ConfigManager {
                     ConfigError::Message(msg)
                 })?;
             }
-            fs::write(config_path, DEFAULT_CONFIG).map_err(|e| {
+            fs::write(config_path, contents).map_err(|e| {
                 let msg: String = format!("Failed to create default configuration file: {}", e);
                 error!("{}", msg);
                 ConfigError::Message(msg)
             })?;
             info!("Default configuration file created at: {:?}", config_path);
+            return Ok(true);
         }
-        Ok(())
+        Ok(false)
     }
 
     /// Loads the settings from the configuration file and environment variables.
+    /// The file may be TOML, YAML or JSON, chosen by its extension.
     fn load_settings(config_path: &Path) -> Result<Settings, ConfigError> {
-        let config_file: &str = config_path.to_str().ok_or_else(|| {
-            let msg: &str = "Configuration file path contains invalid UTF-8 characters";
-            error!("{}", msg);
-            ConfigError::Message(msg.into())
-        })?;
+        let format = config_file_format(config_path)?;
 
         let settings: Config = Config::builder()
-            .add_source(File::with_name(config_file))
+            .add_source(File::from(config_path).format(format))
+            .add_source(EnvOverrides::new())
             .build()?;
 
-        settings.try_deserialize()
+        let mut settings: Settings = settings.try_deserialize()?;
+        settings.apply_defaults();
+        Ok(settings)
     }
 
     /// Reloads the configuration from the file.
-    pub async fn _reload(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let new_settings: Settings = Self::load_settings(&self._config_path)?;
+    /// On success, every receiver from `subscribe_reloads` is notified.
+    /// An invalid file leaves the current settings untouched.
+    pub async fn reload(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let mut new_settings: Settings = Self::load_settings(&self.config_path)?;
+        new_settings.load_secret_files()?;
+        if self.forced_dry_run {
+            new_settings.update.dry_run = true;
+        }
+        if self.forced_respect_foreign_records {
+            new_settings.update.respect_foreign_records = true;
+        }
 
-        // Validate settings before updating
+        // Validate settings before updating; a running client keeps at least one provider
+        if !new_settings.has_enabled_provider() {
+            error!("Configuration validation failed during reload: no providers are enabled");
+            return Err(Box::new(ValidationError::NoProvidersEnabled));
+        }
         let validated_settings = ValidatedSettings::new(new_settings).map_err(|e| {
             error!("Configuration validation failed during reload: {}", e);
             e
@@ -132,10 +541,29 @@ impl ConfigManager {
 
         *self.settings.write().await = validated_settings.into_inner();
         self.adjust_logging_level().await;
-        info!("Configuration reloaded from {:?}", self._config_path);
+        info!("Configuration reloaded from {:?}", self.config_path);
+        self.reloads.send_replace(());
         Ok(())
     }
 
+    /// Returns a receiver that is marked as changed after every successful reload.
+    pub fn subscribe_reloads(&self) -> watch::Receiver<()> {
+        self.reloads.subscribe()
+    }
+
+    /// Turns on dry-run mode regardless of the configuration file, including after reloads.
+    pub async fn force_dry_run(&mut self) {
+        self.forced_dry_run = true;
+        self.settings.write().await.update.dry_run = true;
+    }
+
+    /// Protects records owned by other tools regardless of the configuration file,
+    /// including after reloads.
+    pub async fn force_respect_foreign_records(&mut self) {
+        self.forced_respect_foreign_records = true;
+        self.settings.write().await.update.respect_foreign_records = true;
+    }
+
     /// Adjusts the logging level based on the configuration.
     async fn adjust_logging_level(&self) {
         let level: String = self.get_log_level().await;
@@ -150,14 +578,13 @@ impl ConfigManager {
         log::set_max_level(level_filter);
     }
 
-    /// Provides an `Arc` to the settings `RwLock`.
-    pub fn _get_settings_arc(&self) -> Arc<RwLock<Settings>> {
-        Arc::clone(&self.settings)
-    }
-
     pub async fn get_log_level(&self) -> String {
         self.settings.read().await.get_log_level()
     }
+
+    pub async fn get_log_format(&self) -> LogFormat {
+        self.settings.read().await.log.format
+    }
 }
 
 impl ValidatedSettings {
@@ -179,3 +606,46 @@ impl std::ops::Deref for ValidatedSettings {
         &self.0
     }
 }
+
+impl EnvOverrides {
+    /// Reads variables like `FDDNS__LOG__LEVEL`; `FDDNS_CONFIG_PATH` and
+    /// `FDDNS_STATE_PATH` use a single underscore and are not picked up.
+    pub fn new() -> Self {
+        Self(
+            Environment::with_prefix(ENV_PREFIX)
+                .prefix_separator(ENV_SEPARATOR)
+                .separator(ENV_SEPARATOR),
+        )
+    }
+}
+
+impl Source for EnvOverrides {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    /// Rewrites numeric segments into index syntax (`cloudflare.0.name` to
+    /// `cloudflare[0].name`) so they update array entries instead of
+    /// replacing the array with a table.
+    fn collect(&self) -> Result<Map<String, Value>, ConfigError> {
+        Ok(self
+            .0
+            .collect()?
+            .into_iter()
+            .map(|(key, value)| {
+                let key = key.split('.').fold(String::new(), |mut path, segment| {
+                    if !path.is_empty() && segment.parse::<usize>().is_ok() {
+                        path.push_str(&format!("[{}]", segment));
+                    } else {
+                        if !path.is_empty() {
+                            path.push('.');
+                        }
+                        path.push_str(segment);
+                    }
+                    path
+                });
+                (key, value)
+            })
+            .collect())
+    }
+}
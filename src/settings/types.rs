@@ -3,23 +3,123 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 // 3rd party crates
+use config::Environment;
+use reqwest::Proxy;
 use serde::Deserialize;
-use tokio::sync::RwLock;
+use tokio::sync::{watch, RwLock};
 
 // Project imports
+use crate::ipc::types::Ipc;
+use crate::notifications::types::Notifications;
+use crate::providers::arvancloud::types::ArvanConfig;
+use crate::providers::cloudflare::constants::DEFAULT_RECORD_COMMENT;
 use crate::providers::cloudflare::types::CfConfig;
+use crate::providers::constants::{
+    DEFAULT_MAX_CONCURRENT_UPDATES, DEFAULT_MAX_RETRIES, DEFAULT_RETRY_BASE_DELAY_SECS,
+    DEFAULT_SHUTDOWN_GRACE_SECS, DEFAULT_STARTUP_RETRIES, DEFAULT_STARTUP_RETRY_DELAY_SECS,
+    DEFAULT_UPDATE_TIMEOUT_SECS,
+};
+use crate::providers::duckdns::types::DuckDnsConfig;
+#[cfg(feature = "mock-provider")]
+use crate::providers::mock::types::MockConfig;
+use crate::providers::porkbun::types::PorkbunConfig;
+use crate::providers::route53::types::Route53Config;
 use crate::utility::ip_detector::types::IpDetection;
 
+// Current module imports
+use super::constants::DEFAULT_USER_AGENT;
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct Log {
     #[serde(default = "default_log_level")]
     pub level: String,
+    #[serde(default)]
+    pub format: LogFormat,
+}
+
+/// Output format of the log lines
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Human-readable lines (default)
+    #[default]
+    Text,
+    /// One JSON object per line, for log shippers
+    Json,
 }
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct Update {
     #[serde(default = "default_update_interval")]
     pub interval: u64,
+    /// Accept intervals below `MIN_UPDATE_INTERVAL_SECS`
+    #[serde(default)]
+    pub allow_aggressive_interval: bool,
+    /// Up to this many seconds are randomly added to each interval
+    #[serde(default)]
+    pub jitter_secs: u64,
+    /// Number of retries for a failed DNS record update
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    /// Delay in seconds before the first retry, doubled on every further attempt
+    #[serde(default = "default_retry_base_delay_secs")]
+    pub retry_base_delay_secs: u64,
+    /// Retries of a failed first detection, for networks that come up after the client
+    #[serde(default = "default_startup_retries")]
+    pub startup_retries: u32,
+    /// Delay in seconds before the first startup retry, doubled on every further attempt
+    #[serde(default = "default_startup_retry_delay_secs")]
+    pub startup_retry_delay_secs: u64,
+    /// Seconds in-flight DNS updates may keep running after a shutdown signal
+    #[serde(default = "default_shutdown_grace_secs")]
+    pub shutdown_grace_secs: u64,
+    /// Provider updates allowed to run at the same time
+    #[serde(default = "default_max_concurrent_updates")]
+    pub max_concurrent_updates: usize,
+    /// Seconds all provider updates of one pass may take together
+    #[serde(default = "default_update_timeout_secs")]
+    pub update_timeout_secs: u64,
+    /// Seconds a single provider update may take; defaults to `update_timeout_secs`
+    #[serde(default)]
+    pub provider_timeout_secs: Option<u64>,
+    /// Detect and diff as usual, but never change any DNS record
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Comment attached to Cloudflare records on create and update; empty for none
+    #[serde(default = "default_record_comment")]
+    pub record_comment: String,
+    /// Leave Cloudflare records alone whose comment shows another owner
+    #[serde(default)]
+    pub respect_foreign_records: bool,
+    /// User-Agent of the IP detection and DNS provider HTTP clients
+    #[serde(default = "default_user_agent")]
+    pub user_agent: String,
+}
+
+/// Outbound network configuration
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct Network {
+    /// Proxy for every outbound request, e.g. "http://proxy:3128" or "socks5://proxy:1080".
+    /// Without one the HTTP_PROXY and HTTPS_PROXY environment variables are honored.
+    #[serde(default)]
+    pub proxy: Option<String>,
+}
+
+/// Settings every outbound HTTP client is built with
+#[derive(Debug, Clone)]
+pub struct HttpClientOptions {
+    /// User-Agent sent with every request
+    pub user_agent: String,
+    /// Explicit proxy, replacing the proxy environment variables
+    pub proxy: Option<Proxy>,
+}
+
+/// Optional outputs of the DNS update metrics
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct Metrics {
+    /// File the metrics are written to as JSON after every update cycle
+    #[serde(default)]
+    pub json_path: Option<PathBuf>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -29,13 +129,68 @@ pub struct Settings {
     #[serde(default)]
     pub ip_detection: IpDetection,
     #[serde(default)]
+    pub network: Network,
+    #[serde(default)]
+    pub metrics: Metrics,
+    #[serde(default)]
+    pub ipc: Ipc,
+    #[serde(default)]
     pub cloudflare: Vec<CfConfig>,
+    #[serde(default)]
+    pub arvancloud: Vec<ArvanConfig>,
+    #[serde(default)]
+    pub duckdns: Vec<DuckDnsConfig>,
+    #[serde(default)]
+    pub route53: Vec<Route53Config>,
+    #[serde(default)]
+    pub porkbun: Vec<PorkbunConfig>,
+    #[cfg(feature = "mock-provider")]
+    #[serde(default)]
+    pub mock: Vec<MockConfig>,
+    #[serde(default)]
+    pub notifications: Notifications,
 }
 
 fn default_update_interval() -> u64 {
     300 // 5 minutes
 }
 
+fn default_max_retries() -> u32 {
+    DEFAULT_MAX_RETRIES
+}
+
+fn default_retry_base_delay_secs() -> u64 {
+    DEFAULT_RETRY_BASE_DELAY_SECS
+}
+
+fn default_startup_retries() -> u32 {
+    DEFAULT_STARTUP_RETRIES
+}
+
+fn default_startup_retry_delay_secs() -> u64 {
+    DEFAULT_STARTUP_RETRY_DELAY_SECS
+}
+
+fn default_shutdown_grace_secs() -> u64 {
+    DEFAULT_SHUTDOWN_GRACE_SECS
+}
+
+fn default_max_concurrent_updates() -> usize {
+    DEFAULT_MAX_CONCURRENT_UPDATES
+}
+
+fn default_update_timeout_secs() -> u64 {
+    DEFAULT_UPDATE_TIMEOUT_SECS
+}
+
+fn default_record_comment() -> String {
+    DEFAULT_RECORD_COMMENT.to_string()
+}
+
+fn default_user_agent() -> String {
+    DEFAULT_USER_AGENT.to_string()
+}
+
 fn default_log_level() -> String {
     "info".to_string()
 }
@@ -43,8 +198,20 @@ fn default_log_level() -> String {
 /// Manages the application settings, allowing for loading and reloading configurations.
 pub struct ConfigManager {
     pub settings: Arc<RwLock<Settings>>,
-    pub _config_path: PathBuf,
+    pub config_path: PathBuf,
+    /// Signalled after every successful reload
+    pub reloads: watch::Sender<()>,
+    /// Keeps dry-run mode on across reloads when it was requested on the command line
+    pub forced_dry_run: bool,
+    /// Keeps foreign records protected across reloads when requested on the command line
+    pub forced_respect_foreign_records: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct ValidatedSettings(pub Settings);
+
+/// `FDDNS__`-prefixed environment variables as a configuration source.
+/// Numeric key segments address array elements, so
+/// `FDDNS__CLOUDFLARE__0__API_TOKEN` sets `cloudflare[0].api_token`.
+#[derive(Debug, Clone)]
+pub struct EnvOverrides(pub Environment);
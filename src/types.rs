@@ -0,0 +1,46 @@
+// Standard library
+use std::path::PathBuf;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::Duration;
+
+// Project imports
+use crate::ipc::types::EventLog;
+use crate::notifications::traits::Notifier;
+use crate::providers::types::{PushedIps, UpdateLimits, UpdateSchedule};
+use crate::providers::AnyDnsProvider;
+use crate::utility::ip_detector::types::IpDetector;
+
+/// Everything the main loop derives from the settings.
+/// It is rebuilt as a whole whenever the configuration is reloaded.
+pub struct UpdateContext {
+    /// When each provider is next due; providers may override the global interval
+    pub schedule: UpdateSchedule,
+    /// Upper bound in seconds of the random delay added to each interval
+    pub jitter_secs: u64,
+    /// Whether DNS changes are only logged, not applied
+    pub dry_run: bool,
+    /// Concurrency, timeouts and shutdown grace of the provider updates
+    pub limits: UpdateLimits,
+    /// File the metrics are written to after every cycle, if any
+    pub metrics_json_path: Option<PathBuf>,
+    pub ip_detector: IpDetector,
+    pub providers: Vec<Box<dyn AnyDnsProvider>>,
+    /// Last address each provider pushed, so unchanged providers are skipped
+    pub pushed_ips: PushedIps,
+    pub notifiers: Vec<Box<dyn Notifier>>,
+    /// Recent events for the status socket, kept across reloads
+    pub events: Arc<EventLog>,
+    /// Whether any enabled provider manages IPv4 records
+    pub need_ipv4: bool,
+    /// Whether any enabled provider manages IPv6 records
+    pub need_ipv6: bool,
+    /// Retries of a failed first detection before entering the normal loop
+    pub startup_retries: u32,
+    /// Delay before the first startup retry, doubled on every further attempt
+    pub startup_retry_delay: Duration,
+    /// Whether detection of the primary IP version failed in the last cycle
+    pub detection_failed: AtomicBool,
+    /// Set once the host was reported to lack IPv6, so it is logged only once
+    pub ipv6_unavailable_logged: AtomicBool,
+}
@@ -1,10 +1,19 @@
-// Project imports
-use crate::utility::ip_detector::types::IpService;
+// Standard library
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+// Current module imports
+use super::types::{DnsAnswer, DnsIpService, IpVersion};
 
 /// Default settings
 pub const DEFAULT_MAX_REQUESTS_PER_HOUR: u32 = 200;
 pub const DEFAULT_MIN_CONSENSUS: u32 = 4;
+pub const DEFAULT_PRIMARY_WEIGHT: u32 = 2;
 pub const DEFAULT_MAX_NETWORK_RETRY_INTERVAL: u64 = 30;
+pub const DEFAULT_STABLE_CYCLES: u32 = 1;
+pub const DEFAULT_PRIMARY_VERSION: IpVersion = IpVersion::V4;
+
+/// Agreeing responses needed for a partial consensus after `accept_partial_after_secs`
+pub const MIN_PARTIAL_CONSENSUS: usize = 2;
 
 /// Suspension settings
 /// // Number of consecutive failures before suspension
@@ -12,133 +21,98 @@ pub const MAX_CONSECUTIVE_FAILURES: u32 = 3;
 // Keep version suspended for 1 hour
 pub const SUSPENSION_DURATION_SECS: u64 = 3600;
 
+/// Per-service circuit breaker settings
+// Consecutive failures after which a service is skipped
+pub const BREAKER_FAILURE_THRESHOLD: u32 = 3;
+// Skip an open service for 10 minutes before probing it again
+pub const BREAKER_COOLDOWN_SECS: u64 = 600;
+
 /// HTTP client settings
-pub const REQUEST_TIMEOUT_SECS: u64 = 5;
-pub const MAX_RETRIES: u32 = 2;
-pub const RETRY_DELAY_MS: u64 = 500;
+pub const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 5;
+pub const MAX_REQUEST_TIMEOUT_SECS: u64 = 60;
+// Attempts per service query, the first one included
+pub const DEFAULT_MAX_RETRIES: u32 = 2;
+pub const MAX_MAX_RETRIES: u32 = 10;
+pub const DEFAULT_RETRY_DELAY_MS: u64 = 500;
+pub const MAX_RETRY_DELAY_MS: u64 = 30_000;
+
+/// Connectivity check settings
+// First delay after the network was found down, doubled up to network_retry_interval
+pub const NETWORK_RETRY_BASE_SECS: u64 = 1;
+// Services of each IP version asked whether the network is up
+pub const NETWORK_CHECK_SERVICES: usize = 2;
+
+/// Global address a UDP socket is connected to when probing for an IPv6 route.
+/// Connecting only consults the routing table; no packet is sent.
+pub const IPV6_PROBE_ADDR: &str = "[2001:4860:4860::8888]:53";
+
+/// Kernel table of the host's IPv6 addresses and their flags (Linux only)
+pub const IF_INET6_PATH: &str = "/proc/net/if_inet6";
+/// `IFA_F_TEMPORARY`, set on temporary (privacy extension) IPv6 addresses
+pub const IFA_F_TEMPORARY: u32 = 0x01;
 
-/// IPv4 detection services
-pub const IPV4_SERVICES: [IpService; 12] = [
+/// JSON fields tried, in order, for services that answer with JSON
+/// and configure no `json_field`
+pub const JSON_IP_FIELDS: [&str; 4] = ["ip", "address", "ipAddress", "query"];
+
+/// Built-in IPv4 detection services as `(base_url, path, is_primary)`
+pub const IPV4_SERVICES: [(&str, &str, bool); 12] = [
     // Primary services (highly reliable)
-    IpService {
-        base_url: "https://api.ipify.org",
-        path: "?format=text",
-        is_primary: true,
-    },
-    IpService {
-        base_url: "https://v4.ident.me",
-        path: "",
-        is_primary: true,
-    },
-    IpService {
-        base_url: "https://ipv4.icanhazip.com",
-        path: "",
-        is_primary: true,
-    },
-    IpService {
-        base_url: "https://ipecho.net",
-        path: "/plain",
-        is_primary: true,
-    },
+    ("https://api.ipify.org", "?format=text", true),
+    ("https://v4.ident.me", "", true),
+    ("https://ipv4.icanhazip.com", "", true),
+    ("https://ipecho.net", "/plain", true),
     // Secondary services (reliable backups)
-    IpService {
-        base_url: "https://api4.my-ip.io",
-        path: "/v2/ip.txt",
-        is_primary: false,
-    },
-    IpService {
-        base_url: "https://ip4.seeip.org",
-        path: "",
-        is_primary: false,
-    },
-    IpService {
-        base_url: "https://api4.ipaddress.com",
-        path: "/myip",
-        is_primary: false,
-    },
-    IpService {
-        base_url: "https://checkip.amazonaws.com",
-        path: "",
-        is_primary: false,
-    },
-    IpService {
-        base_url: "https://ipinfo.io",
-        path: "/ip",
-        is_primary: false,
-    },
-    IpService {
-        base_url: "https://wtfismyip.com",
-        path: "/text",
-        is_primary: false,
-    },
-    IpService {
-        base_url: "https://ip.tyk.nu",
-        path: "",
-        is_primary: false,
-    },
-    IpService {
-        base_url: "https://diagnostic.opendns.com",
-        path: "/myip",
-        is_primary: false,
-    },
+    ("https://api4.my-ip.io", "/v2/ip.txt", false),
+    ("https://ip4.seeip.org", "", false),
+    ("https://api4.ipaddress.com", "/myip", false),
+    ("https://checkip.amazonaws.com", "", false),
+    ("https://ipinfo.io", "/ip", false),
+    ("https://wtfismyip.com", "/text", false),
+    ("https://ip.tyk.nu", "", false),
+    ("https://diagnostic.opendns.com", "/myip", false),
 ];
 
-/// IPv6 detection services
-pub const IPV6_SERVICES: [IpService; 10] = [
+/// Built-in IPv6 detection services as `(base_url, path, is_primary)`
+pub const IPV6_SERVICES: [(&str, &str, bool); 9] = [
     // Primary services (highly reliable)
-    IpService {
-        base_url: "https://api6.ipify.org",
-        path: "?format=text",
-        is_primary: true,
-    },
-    IpService {
-        base_url: "https://v6.ident.me",
-        path: "",
-        is_primary: true,
-    },
-    IpService {
-        base_url: "https://ipv6.icanhazip.com",
-        path: "",
-        is_primary: true,
-    },
+    ("https://api6.ipify.org", "?format=text", true),
+    ("https://v6.ident.me", "", true),
+    ("https://ipv6.icanhazip.com", "", true),
     // Secondary services (reliable backups)
-    IpService {
-        base_url: "https://api6.my-ip.io",
-        path: "/ip",
-        is_primary: true,
-    },
-    IpService {
-        base_url: "https://ip6.seeip.org",
-        path: "",
-        is_primary: false,
-    },
-    IpService {
-        base_url: "https://v6.ipv6-test.com",
-        path: "/api/myip.php",
-        is_primary: false,
-    },
-    IpService {
-        base_url: "https://ipv6.wtfismyip.com",
-        path: "/text",
-        is_primary: false,
-    },
-    IpService {
-        base_url: "https://ipv6.ip.tyk.nu",
-        path: "",
-        is_primary: false,
-    },
-    IpService {
-        base_url: "https://v6.ident.me",
-        path: "/raw",
-        is_primary: false,
-    },
-    IpService {
-        base_url: "https://ipv6.test-ipv6.com",
-        path: "/ip/",
-        is_primary: false,
+    ("https://api6.my-ip.io", "/ip", true),
+    ("https://ip6.seeip.org", "", false),
+    ("https://v6.ipv6-test.com", "/api/myip.php", false),
+    ("https://ipv6.wtfismyip.com", "/text", false),
+    ("https://ipv6.ip.tyk.nu", "", false),
+    ("https://ipv6.test-ipv6.com", "/ip/", false),
+];
+
+/// Port of the DNS detection servers
+pub const DNS_PORT: u16 = 53;
+
+/// Built-in DNS detection services, queried in order until one answers
+pub const DNS_SERVICES: [DnsIpService; 2] = [
+    // myip.opendns.com A/AAAA @resolver1.opendns.com
+    DnsIpService {
+        name: "myip.opendns.com.",
+        answer: DnsAnswer::Address,
+        ipv4_server: Ipv4Addr::new(208, 67, 222, 222),
+        ipv6_server: Ipv6Addr::new(0x2620, 0x119, 0x35, 0, 0, 0, 0, 0x35),
+    },
+    // o-o.myaddr.l.google.com TXT @ns1.google.com
+    DnsIpService {
+        name: "o-o.myaddr.l.google.com.",
+        answer: DnsAnswer::Txt,
+        ipv4_server: Ipv4Addr::new(216, 239, 32, 10),
+        ipv6_server: Ipv6Addr::new(0x2001, 0x4860, 0x4802, 0x32, 0, 0, 0, 0xa),
     },
 ];
 
+pub fn default_json_ip_fields() -> Vec<String> {
+    JSON_IP_FIELDS.map(String::from).to_vec()
+}
+
 pub fn default_max_requests_per_hour() -> u32 {
     DEFAULT_MAX_REQUESTS_PER_HOUR
 }
@@ -147,6 +121,30 @@ pub fn default_min_consensus() -> u32 {
     DEFAULT_MIN_CONSENSUS
 }
 
+pub fn default_primary_weight() -> u32 {
+    DEFAULT_PRIMARY_WEIGHT
+}
+
 pub fn default_network_retry_interval() -> u64 {
     DEFAULT_MAX_NETWORK_RETRY_INTERVAL
 }
+
+pub fn default_request_timeout_secs() -> u64 {
+    DEFAULT_REQUEST_TIMEOUT_SECS
+}
+
+pub fn default_max_retries() -> u32 {
+    DEFAULT_MAX_RETRIES
+}
+
+pub fn default_retry_delay_ms() -> u64 {
+    DEFAULT_RETRY_DELAY_MS
+}
+
+pub fn default_stable_cycles() -> u32 {
+    DEFAULT_STABLE_CYCLES
+}
+
+pub fn default_primary_version() -> IpVersion {
+    DEFAULT_PRIMARY_VERSION
+}
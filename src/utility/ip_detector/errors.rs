@@ -1,8 +1,11 @@
+// Standard library
+use std::net::{IpAddr, Ipv6Addr};
+
 // 3rd party crates
 use thiserror::Error;
 
 // Current module imports
-use super::types::IpVersion;
+use super::types::{IpVersion, ObservedIps};
 
 #[derive(Debug, Error)]
 pub enum IpDetectionError {
@@ -19,18 +22,56 @@ pub enum IpDetectionError {
         got: IpVersion,
     },
 
+    #[error("{service} answered {ip}, an IPv6 address carrying an IPv4 one")]
+    EmbeddedIpv4 { service: String, ip: Ipv6Addr },
+
+    #[error("Invalid response from {service}: {reason}")]
+    InvalidResponse { service: String, reason: String },
+
     #[error("Rate limit exceeded for {service}")]
     RateLimitExceeded { service: String },
 
     #[error("Parse error from {service}: {error}")]
     ParseError { service: String, error: String },
 
-    #[error("Consensus not reached: got {responses} responses, need {required}")]
-    ConsensusNotReached { responses: usize, required: u32 },
+    #[error("DNS query to {service} failed: {error}")]
+    DnsQueryFailed { service: String, error: String },
+
+    #[error(
+        "Consensus not reached: got {responses} responses ({failed} services failed), \
+         need {required}; observed {observed}"
+    )]
+    ConsensusNotReached {
+        responses: usize,
+        failed: usize,
+        required: u32,
+        observed: ObservedIps,
+    },
 
     #[error("No IP detection services available")]
     NoServicesAvailable,
 
+    #[error("IPv6 is not available on this host")]
+    Ipv6Unavailable,
+
+    #[error("Failed to read network interfaces: {0}")]
+    InterfaceError(#[source] std::io::Error),
+
+    #[error("No usable {version:?} address on interface '{interface}'")]
+    NoInterfaceAddress {
+        interface: String,
+        version: IpVersion,
+    },
+
+    #[error("Detected address {0} is not globally routable")]
+    NonGlobalAddress(IpAddr),
+
+    #[error("Detected address {detected} disagrees with the DNS cross-check answer {dns}")]
+    DnsMismatch { detected: IpAddr, dns: IpAddr },
+
+    #[error("Detected IPv6 {0} is a temporary address")]
+    TemporaryIpv6(Ipv6Addr),
+
     #[error("{version:?} detection suspended for {remaining_secs} seconds")]
     VersionSuspended {
         version: IpVersion,
@@ -42,11 +83,38 @@ pub enum IpDetectionError {
 }
 
 #[derive(Debug, Error)]
+#[allow(clippy::enum_variant_names)]
 pub enum IpDetectionValidationError {
     #[error("Invalid max_requests_per_hour: {0}")]
     InvalidMaxRequests(String),
     #[error("Invalid min_consensus: {0}")]
     InvalidMinConsensus(String),
+    #[error("Invalid json_ip_fields: {0}")]
+    InvalidJsonIpFields(String),
+    #[error("Invalid primary_weight: {0}")]
+    InvalidPrimaryWeight(String),
+    #[error("Invalid stable_cycles: {0}")]
+    InvalidStableCycles(String),
+    #[error("Invalid accept_partial_after_secs: {0}")]
+    InvalidAcceptPartial(String),
     #[error("Invalid network_retry_interval: {0}")]
     InvalidRetryInterval(String),
+    #[error("Invalid request_timeout_secs: {0}")]
+    InvalidRequestTimeout(String),
+    #[error("Invalid max_retries: {0}")]
+    InvalidMaxRetries(String),
+    #[error("Invalid retry_delay_ms: {0}")]
+    InvalidRetryDelay(String),
+    #[error("An interface name is required when method is \"interface\"")]
+    MissingInterface,
+    #[error("reject_dns_mismatch requires dns_cross_check to be enabled")]
+    RejectWithoutCrossCheck,
+    #[error("Invalid custom service '{url}': {reason}")]
+    InvalidCustomService { url: String, reason: String },
+    #[error("Only {available} {version:?} services configured, min_consensus requires {required}")]
+    NotEnoughServices {
+        version: IpVersion,
+        available: usize,
+        required: u32,
+    },
 }
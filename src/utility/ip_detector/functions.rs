@@ -0,0 +1,106 @@
+// Standard library
+use std::net::{AddrParseError, IpAddr, Ipv4Addr, Ipv6Addr};
+
+// 3rd party crates
+use serde_json::Value;
+
+// Current module imports
+use super::constants::{IFA_F_TEMPORARY, IF_INET6_PATH};
+
+/// Checks whether `ip` is a temporary (privacy extension) address of this host.
+/// The kernel's address flags are the only reliable source, so this returns
+/// `None` if `ip` is not assigned to a local interface or the flags can't be
+/// read, e.g. when the address belongs to a router or on non-Linux hosts.
+pub fn is_temporary_ipv6(ip: &Ipv6Addr) -> Option<bool> {
+    // Each line: address (32 hex digits), ifindex, prefix length, scope, flags, name
+    let table = std::fs::read_to_string(IF_INET6_PATH).ok()?;
+    table.lines().find_map(|line| {
+        let mut fields = line.split_whitespace();
+        let address = u128::from_str_radix(fields.next()?, 16).ok()?;
+        if Ipv6Addr::from(address) != *ip {
+            return None;
+        }
+        let flags = u32::from_str_radix(fields.nth(3)?, 16).ok()?;
+        Some(flags & IFA_F_TEMPORARY != 0)
+    })
+}
+
+/// Returns the IPv4 address inside an IPv4-mapped (`::ffff:0:0/96`) or
+/// deprecated IPv4-compatible (`::/96`) IPv6 address.
+/// `::` and `::1` are not taken for IPv4-compatible addresses.
+pub fn embedded_ipv4(ip: &Ipv6Addr) -> Option<Ipv4Addr> {
+    ip.to_ipv4_mapped().or_else(|| {
+        let compatible = ip.segments()[..6] == [0; 6] && !ip.is_unspecified() && !ip.is_loopback();
+        compatible.then(|| ip.to_ipv4()).flatten()
+    })
+}
+
+/// Parses an address answered by a detection service.
+/// A trailing zone (`%eth0`) or prefix length (`/64`) some services append
+/// is dropped, since neither belongs in a DNS record.
+pub fn parse_ip_answer(text: &str) -> Result<IpAddr, AddrParseError> {
+    let text = text.trim();
+    let end = text.find(['%', '/']).unwrap_or(text.len());
+    text[..end].parse()
+}
+
+/// Looks up a dotted path such as "data.ip" in a JSON value.
+/// Numeric segments also index into arrays, e.g. "addresses.0".
+pub fn json_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(value, |value, segment| match value {
+            Value::Object(fields) => fields.get(segment),
+            Value::Array(items) => items.get(segment.parse::<usize>().ok()?),
+            _ => None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embedded_ipv4_unwraps_mapped_addresses() {
+        let ip: Ipv6Addr = "::ffff:192.0.2.1".parse().unwrap();
+        assert_eq!(embedded_ipv4(&ip), Some(Ipv4Addr::new(192, 0, 2, 1)));
+    }
+
+    #[test]
+    fn embedded_ipv4_unwraps_compatible_addresses() {
+        let ip: Ipv6Addr = "::192.0.2.1".parse().unwrap();
+        assert_eq!(embedded_ipv4(&ip), Some(Ipv4Addr::new(192, 0, 2, 1)));
+    }
+
+    #[test]
+    fn embedded_ipv4_skips_unspecified_and_loopback() {
+        assert_eq!(embedded_ipv4(&Ipv6Addr::UNSPECIFIED), None);
+        assert_eq!(embedded_ipv4(&Ipv6Addr::LOCALHOST), None);
+        let ip: Ipv6Addr = "2001:db8::c000:201".parse().unwrap();
+        assert_eq!(embedded_ipv4(&ip), None);
+    }
+
+    #[test]
+    fn parse_ip_answer_drops_zone_suffix() {
+        assert_eq!(
+            parse_ip_answer("fe80::1%eth0"),
+            Ok("fe80::1".parse::<IpAddr>().unwrap())
+        );
+    }
+
+    #[test]
+    fn parse_ip_answer_drops_prefix_length() {
+        assert_eq!(
+            parse_ip_answer("2001:db8::1/64"),
+            Ok("2001:db8::1".parse::<IpAddr>().unwrap())
+        );
+    }
+
+    #[test]
+    fn parse_ip_answer_trims_whitespace() {
+        assert_eq!(
+            parse_ip_answer("  192.0.2.1\r\n"),
+            Ok(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)))
+        );
+        assert!(parse_ip_answer("  ").is_err());
+    }
+}
@@ -1,35 +1,70 @@
 // Standard library
+use std::cmp::Reverse;
 use std::collections::HashMap;
-use std::net::IpAddr;
-use std::sync::Arc;
+use std::fmt;
+use std::net::{IpAddr, UdpSocket};
+use std::sync::{Arc, OnceLock};
 use std::time::{Duration, Instant};
 
 // 3rd party crates
+use futures::{stream::FuturesUnordered, StreamExt};
+use hickory_resolver::config::{NameServerConfigGroup, ResolverConfig, ResolverOpts};
+use hickory_resolver::proto::rr::{RData, RecordType};
+use hickory_resolver::TokioAsyncResolver;
+use reqwest::Url;
 use tokio::sync::RwLock;
-use tracing::{debug, error, warn};
+use tracing::{debug, error, info, warn};
 
 // Project imports
+use crate::settings::types::HttpClientOptions;
 use crate::utility::rate_limiter::traits::RateLimiter;
 use crate::utility::rate_limiter::types::{RateLimitConfig, TokenBucketRateLimiter};
 
 // Current module imports
 use super::constants::{
-    DEFAULT_MAX_NETWORK_RETRY_INTERVAL, DEFAULT_MAX_REQUESTS_PER_HOUR, DEFAULT_MIN_CONSENSUS,
-    IPV4_SERVICES, IPV6_SERVICES, MAX_CONSECUTIVE_FAILURES, MAX_RETRIES, REQUEST_TIMEOUT_SECS,
-    RETRY_DELAY_MS, SUSPENSION_DURATION_SECS,
+    default_json_ip_fields, BREAKER_COOLDOWN_SECS, BREAKER_FAILURE_THRESHOLD,
+    DEFAULT_MAX_NETWORK_RETRY_INTERVAL, DEFAULT_MAX_REQUESTS_PER_HOUR, DEFAULT_MAX_RETRIES,
+    DEFAULT_MIN_CONSENSUS, DEFAULT_PRIMARY_VERSION, DEFAULT_PRIMARY_WEIGHT,
+    DEFAULT_REQUEST_TIMEOUT_SECS, DEFAULT_RETRY_DELAY_MS, DEFAULT_STABLE_CYCLES, DNS_PORT,
+    DNS_SERVICES, IPV4_SERVICES, IPV6_PROBE_ADDR, IPV6_SERVICES, MAX_CONSECUTIVE_FAILURES,
+    MAX_MAX_RETRIES, MAX_REQUEST_TIMEOUT_SECS, MAX_RETRY_DELAY_MS, MIN_PARTIAL_CONSENSUS,
+    NETWORK_CHECK_SERVICES, SUSPENSION_DURATION_SECS,
 };
 use super::errors::{IpDetectionError, IpDetectionValidationError};
+use super::functions::{embedded_ipv4, is_temporary_ipv6, json_path, parse_ip_answer};
 use super::traits::IpVersionOps;
 use super::types::{
-    IpDetection, IpDetector, IpResponse, IpService, IpVersion, VersionSuspension, V4, V6,
+    DetectionMethod, DetectionStrategy, DnsAnswer, DnsIpService, IpCandidate, IpDetection,
+    IpDetector, IpResponse, IpService, IpVersion, ObservedIps, VersionSuspension, V4, V6,
 };
 
 impl Default for IpDetection {
     fn default() -> Self {
         Self {
             max_requests_per_hour: DEFAULT_MAX_REQUESTS_PER_HOUR,
+            strategy: DetectionStrategy::default(),
             min_consensus: DEFAULT_MIN_CONSENSUS,
+            weighted_consensus: false,
+            primary_weight: DEFAULT_PRIMARY_WEIGHT,
             network_retry_interval: DEFAULT_MAX_NETWORK_RETRY_INTERVAL,
+            request_timeout_secs: DEFAULT_REQUEST_TIMEOUT_SECS,
+            max_retries: DEFAULT_MAX_RETRIES,
+            retry_delay_ms: DEFAULT_RETRY_DELAY_MS,
+            method: DetectionMethod::default(),
+            interface: None,
+            allow_private_addresses: false,
+            reject_temporary_ipv6: false,
+            custom_services: Vec::new(),
+            replace_builtin_services: false,
+            json_ip_fields: default_json_ip_fields(),
+            strict_json: false,
+            dns_cross_check: false,
+            reject_dns_mismatch: false,
+            override_ipv4: None,
+            override_ipv6: None,
+            stable_cycles: DEFAULT_STABLE_CYCLES,
+            accept_partial_after_secs: None,
+            primary_version: DEFAULT_PRIMARY_VERSION,
         }
     }
 }
@@ -43,20 +78,101 @@ impl IpDetection {
             ));
         }
 
-        // Validate min_consensus (must be > 0 and <= total number of services)
+        // Validate min_consensus (must be > 0)
         if self.min_consensus == 0 {
             return Err(IpDetectionValidationError::InvalidMinConsensus(
                 "must be greater than 0".into(),
             ));
         }
 
-        // Get total number of services (IPv4 + IPv6)
-        let total_services = IPV4_SERVICES.len() + IPV6_SERVICES.len();
-        if self.min_consensus as usize > total_services {
-            return Err(IpDetectionValidationError::InvalidMinConsensus(format!(
-                "cannot be greater than total number of services ({})",
-                total_services
-            )));
+        // Validate stable_cycles (must be > 0)
+        if self.stable_cycles == 0 {
+            return Err(IpDetectionValidationError::InvalidStableCycles(
+                "must be greater than 0".into(),
+            ));
+        }
+
+        // Validate accept_partial_after_secs (must be > 0 when set)
+        if self.accept_partial_after_secs == Some(0) {
+            return Err(IpDetectionValidationError::InvalidAcceptPartial(
+                "must be greater than 0".into(),
+            ));
+        }
+
+        // Validate json_ip_fields (must name at least one field)
+        if self
+            .json_ip_fields
+            .iter()
+            .all(|field| field.trim().is_empty())
+        {
+            return Err(IpDetectionValidationError::InvalidJsonIpFields(
+                "must name at least one field".into(),
+            ));
+        }
+
+        // Validate primary_weight (must be > 0)
+        if self.primary_weight == 0 {
+            return Err(IpDetectionValidationError::InvalidPrimaryWeight(
+                "must be greater than 0".into(),
+            ));
+        }
+
+        // Interface detection needs to know which interface to read
+        if self.method == DetectionMethod::Interface
+            && self
+                .interface
+                .as_deref()
+                .is_none_or(|name| name.trim().is_empty())
+        {
+            return Err(IpDetectionValidationError::MissingInterface);
+        }
+
+        if self.reject_dns_mismatch && !self.dns_cross_check {
+            return Err(IpDetectionValidationError::RejectWithoutCrossCheck);
+        }
+
+        // Validate custom service URLs
+        for service in &self.custom_services {
+            let url = service.url.trim();
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                return Err(IpDetectionValidationError::InvalidCustomService {
+                    url: service.url.clone(),
+                    reason: "must start with http:// or https://".into(),
+                });
+            }
+            if let Some(field) = service.json_field.as_deref().map(str::trim) {
+                if !field.is_empty() && field.split('.').any(str::is_empty) {
+                    return Err(IpDetectionValidationError::InvalidCustomService {
+                        url: service.url.clone(),
+                        reason: format!(
+                            "json_field '{}' must be a field name or a dotted path such as \"data.ip\"",
+                            field
+                        ),
+                    });
+                }
+            }
+        }
+
+        // Every version with services must have enough of them to reach consensus.
+        // A version without any services is treated as disabled.
+        let required = self.required_consensus();
+        let ipv4_count = self.services(IpVersion::V4).len();
+        let ipv6_count = self.services(IpVersion::V6).len();
+        if ipv4_count == 0 && ipv6_count == 0 {
+            return Err(IpDetectionValidationError::NotEnoughServices {
+                version: IpVersion::V4,
+                available: 0,
+                required: required as u32,
+            });
+        }
+        for (version, available) in [(IpVersion::V4, ipv4_count), (IpVersion::V6, ipv6_count)] {
+            if available > 0 && required > available {
+                return Err(IpDetectionValidationError::NotEnoughServices {
+                    version,
+                    available,
+                    required: required as u32,
+                });
+            }
         }
 
         // Validate network_retry_interval (must be > 0 and <= max allowed)
@@ -72,41 +188,321 @@ impl IpDetection {
             )));
         }
 
+        // Validate request_timeout_secs (must be > 0 and <= max allowed)
+        if !(1..=MAX_REQUEST_TIMEOUT_SECS).contains(&self.request_timeout_secs) {
+            return Err(IpDetectionValidationError::InvalidRequestTimeout(format!(
+                "must be between 1 and {}, got {}",
+                MAX_REQUEST_TIMEOUT_SECS, self.request_timeout_secs
+            )));
+        }
+
+        // Validate the service query retries
+        if !(1..=MAX_MAX_RETRIES).contains(&self.max_retries) {
+            return Err(IpDetectionValidationError::InvalidMaxRetries(format!(
+                "must be between 1 and {}, got {}",
+                MAX_MAX_RETRIES, self.max_retries
+            )));
+        }
+        if self.retry_delay_ms > MAX_RETRY_DELAY_MS {
+            return Err(IpDetectionValidationError::InvalidRetryDelay(format!(
+                "cannot be greater than {}, got {}",
+                MAX_RETRY_DELAY_MS, self.retry_delay_ms
+            )));
+        }
+
         Ok(())
     }
+
+    /// Returns how many services must agree before an IP is accepted.
+    /// The "first" strategy accepts the first valid answer.
+    pub fn required_consensus(&self) -> usize {
+        match self.strategy {
+            DetectionStrategy::Consensus => self.min_consensus as usize,
+            DetectionStrategy::First => 1,
+        }
+    }
+
+    /// Returns the services to query for the given IP version, one per host.
+    /// See [`IpDetection::partition_services`].
+    pub fn services(&self, version: IpVersion) -> Vec<IpService> {
+        self.partition_services(version).0
+    }
+
+    /// Splits the services for the given IP version into the ones to query
+    /// and the ones dropped because an earlier service has the same host.
+    /// Several paths on one host reach the same backend, so they must only
+    /// count once towards consensus.
+    pub fn partition_services(&self, version: IpVersion) -> (Vec<IpService>, Vec<IpService>) {
+        let mut hosts: Vec<String> = Vec::new();
+        self.all_services(version).into_iter().partition(|service| {
+            let host = service.host();
+            if hosts.contains(&host) {
+                false
+            } else {
+                hosts.push(host);
+                true
+            }
+        })
+    }
+
+    /// Returns every configured service for the given IP version.
+    /// Custom services are appended to the built-in ones, or used on their
+    /// own when `replace_builtin_services` is set.
+    fn all_services(&self, version: IpVersion) -> Vec<IpService> {
+        let builtin: &[(&str, &str, bool)] = match version {
+            IpVersion::V4 => &IPV4_SERVICES,
+            IpVersion::V6 => &IPV6_SERVICES,
+        };
+
+        let mut services: Vec<IpService> = Vec::new();
+        if !self.replace_builtin_services {
+            services.extend(
+                builtin
+                    .iter()
+                    .map(|(base_url, path, is_primary)| IpService {
+                        base_url: base_url.to_string(),
+                        path: path.to_string(),
+                        is_primary: *is_primary,
+                        json_field: None,
+                    }),
+            );
+        }
+        services.extend(
+            self.custom_services
+                .iter()
+                .filter(|service| service.version == version)
+                .map(|service| IpService {
+                    base_url: service.url.trim().trim_end_matches('/').to_string(),
+                    path: service.path.clone(),
+                    is_primary: service.is_primary,
+                    json_field: service
+                        .json_field
+                        .as_deref()
+                        .map(str::trim)
+                        .filter(|field| !field.is_empty())
+                        .map(str::to_string),
+                }),
+        );
+
+        services
+    }
+}
+
+impl IpService {
+    /// Identifies the backend behind the service by its lowercase host.
+    /// Unparsable URLs fall back to the whole base URL.
+    pub fn host(&self) -> String {
+        Url::parse(&self.base_url)
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .unwrap_or_else(|| self.base_url.clone())
+            .to_lowercase()
+    }
 }
 
 impl IpDetector {
-    pub fn new(config: IpDetection) -> Self {
+    /// Creates a detector whose requests use the given User-Agent and proxy.
+    pub fn new(config: IpDetection, http_options: &HttpClientOptions) -> Self {
+        let (ipv4_services, ipv4_duplicates) = config.partition_services(IpVersion::V4);
+        let (ipv6_services, ipv6_duplicates) = config.partition_services(IpVersion::V6);
+        for service in ipv4_duplicates.iter().chain(&ipv6_duplicates) {
+            warn!(
+                url = %format!("{}{}", service.base_url, service.path),
+                "Ignoring IP detection service, another service on host {} is already used",
+                service.host()
+            );
+        }
+
         // Create rate limiters for both IPv4 and IPv6 services
         let mut rate_limiters = Vec::new();
-        rate_limiters.extend(V4::get_services().iter().map(|_| {
+        rate_limiters.extend(ipv4_services.iter().map(|_| {
             Arc::new(TokenBucketRateLimiter::new(RateLimitConfig {
                 max_requests: config.max_requests_per_hour,
                 window_secs: 3600, // 1 hour
             })) as Arc<dyn RateLimiter>
         }));
-        rate_limiters.extend(V6::get_services().iter().map(|_| {
+        rate_limiters.extend(ipv6_services.iter().map(|_| {
             Arc::new(TokenBucketRateLimiter::new(RateLimitConfig {
                 max_requests: config.max_requests_per_hour,
                 window_secs: 3600, // 1 hour
             })) as Arc<dyn RateLimiter>
         }));
 
+        let client = http_options
+            .apply(reqwest::Client::builder())
+            .timeout(Duration::from_secs(config.request_timeout_secs))
+            .build()
+            .unwrap_or_default();
+
         Self {
             config,
+            ipv4_services,
+            ipv6_services,
             rate_limiters,
-            client: reqwest::Client::builder()
-                .timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS))
-                .user_agent("fariba-ddns/1.0")
-                .build()
-                .unwrap_or_default(),
+            client,
             suspended_versions: Arc::new(RwLock::new(HashMap::new())),
+            candidates: Arc::new(RwLock::new(HashMap::new())),
+            breakers: Arc::new(RwLock::new(HashMap::new())),
+            primary_rotation: Arc::new(RwLock::new(HashMap::new())),
+            ipv6_available: OnceLock::new(),
+        }
+    }
+
+    /// Whether the host can reach the IPv6 internet at all.
+    /// Probed once per detector, so a configuration reload probes again.
+    fn has_ipv6(&self) -> bool {
+        *self.ipv6_available.get_or_init(|| {
+            let routed = UdpSocket::bind("[::]:0")
+                .and_then(|socket| socket.connect(IPV6_PROBE_ADDR))
+                .inspect_err(|e| debug!("IPv6 route probe failed: {}", e))
+                .is_ok();
+            debug!("IPv6 route probe: available = {}", routed);
+            routed
+        })
+    }
+
+    /// Checks whether the detection services can be reached at all, so a
+    /// network outage can be told apart from failing services.
+    /// A few services of each needed IP version that isn't pinned, primaries
+    /// first, are asked concurrently; any HTTP response counts. Detection
+    /// that needs no HTTP services always reports the network as up.
+    pub async fn check_network(&self, need_ipv4: bool, need_ipv6: bool) -> bool {
+        if self.config.method != DetectionMethod::Services {
+            return true;
+        }
+
+        fn probe_services(services: &[IpService]) -> Vec<&IpService> {
+            let mut services: Vec<&IpService> = services.iter().collect();
+            services.sort_by_key(|service| !service.is_primary);
+            services.truncate(NETWORK_CHECK_SERVICES);
+            services
+        }
+        let mut services = Vec::new();
+        if need_ipv4 && self.config.override_ipv4.is_none() {
+            services.extend(probe_services(&self.ipv4_services));
+        }
+        if need_ipv6 && self.config.override_ipv6.is_none() && self.has_ipv6() {
+            services.extend(probe_services(&self.ipv6_services));
+        }
+        if services.is_empty() {
+            return true;
+        }
+
+        let mut probes: FuturesUnordered<_> = services
+            .into_iter()
+            .map(|service| self.client.head(&service.base_url).send())
+            .collect();
+        while let Some(result) = probes.next().await {
+            match result {
+                Ok(_) => return true,
+                Err(e) => debug!("Network check failed: {}", e),
+            }
+        }
+        false
+    }
+
+    /// Whether the circuit breaker of `service` is open, i.e. the service
+    /// failed too often and its cooldown has not ended yet.
+    async fn is_breaker_open(&self, service: &IpService) -> bool {
+        self.breakers
+            .read()
+            .await
+            .get(&service.base_url)
+            .and_then(|breaker| breaker.opened_at)
+            .is_some_and(|opened_at| opened_at.elapsed().as_secs() < BREAKER_COOLDOWN_SECS)
+    }
+
+    /// Updates the circuit breaker of `service` with the outcome of a query.
+    /// A success closes it; a failure past the threshold (re)opens it.
+    async fn record_service_result(&self, service: &IpService, succeeded: bool) {
+        let mut breakers = self.breakers.write().await;
+        if succeeded {
+            if let Some(breaker) = breakers.remove(&service.base_url) {
+                if breaker.opened_at.is_some() {
+                    info!(
+                        "IP service {} recovered, closing its circuit breaker",
+                        service.base_url
+                    );
+                }
+            }
+            return;
+        }
+
+        let breaker = breakers.entry(service.base_url.clone()).or_default();
+        breaker.consecutive_failures += 1;
+        if breaker.consecutive_failures >= BREAKER_FAILURE_THRESHOLD {
+            breaker.opened_at = Some(Instant::now());
+            warn!(
+                "Skipping IP service {} for {} seconds after {} consecutive failures",
+                service.base_url, BREAKER_COOLDOWN_SECS, breaker.consecutive_failures
+            );
         }
     }
 
+    /// Records another cycle in which `ip` was detected as a change and
+    /// returns whether it has now been stable for `stable_cycles` cycles.
+    /// A different address restarts the count.
+    pub async fn observe_candidate(&self, ip_version: IpVersion, ip: IpAddr) -> bool {
+        let mut candidates = self.candidates.write().await;
+        let candidate = candidates
+            .entry(ip_version)
+            .and_modify(|candidate| {
+                if candidate.ip == ip {
+                    candidate.cycles += 1;
+                } else {
+                    *candidate = IpCandidate { ip, cycles: 1 };
+                }
+            })
+            .or_insert(IpCandidate { ip, cycles: 1 });
+
+        if candidate.cycles >= self.config.stable_cycles {
+            return true;
+        }
+        debug!(
+            "{:?} address {} pending stabilization ({}/{} cycles)",
+            ip_version, ip, candidate.cycles, self.config.stable_cycles
+        );
+        false
+    }
+
+    /// Forgets the pending candidate once the detected IP is the known one again.
+    pub async fn clear_candidate(&self, ip_version: IpVersion) {
+        self.candidates.write().await.remove(&ip_version);
+    }
+
     /// Detects the current public IP address with consensus validation
     pub async fn detect_ip(&self, ip_version: IpVersion) -> Result<IpAddr, IpDetectionError> {
+        // A pinned address replaces detection entirely
+        let pinned = match ip_version {
+            IpVersion::V4 => self.config.override_ipv4.map(IpAddr::V4),
+            IpVersion::V6 => self.config.override_ipv6.map(IpAddr::V6),
+        };
+        if let Some(ip) = pinned {
+            debug!("Using pinned {:?} address {}", ip_version, ip);
+            return Ok(ip);
+        }
+
+        // Interface addresses are read locally, no services or consensus involved
+        if self.config.method == DetectionMethod::Interface {
+            return self
+                .detect_ip_from_interface(ip_version)
+                .and_then(|ip| self.check_temporary_ipv6(ip));
+        }
+
+        // Without an IPv6 route every service would just time out
+        if ip_version == IpVersion::V6 && !self.has_ipv6() {
+            return Err(IpDetectionError::Ipv6Unavailable);
+        }
+
+        // DNS detection asks its own fixed servers, one after another
+        if self.config.method == DetectionMethod::Dns {
+            return self
+                .detect_ip_via_dns(ip_version)
+                .await
+                .and_then(|ip| self.check_global_address(ip))
+                .and_then(|ip| self.check_temporary_ipv6(ip));
+        }
+
         // Check if version is suspended
         if let Some(suspension) = self.suspended_versions.read().await.get(&ip_version) {
             let elapsed = suspension.suspended_since.elapsed();
@@ -125,34 +521,289 @@ impl IpDetector {
             self.suspended_versions.write().await.remove(&ip_version);
         }
 
-        match ip_version {
-            IpVersion::V4 => self.detect_ip_for_version::<V4>().await,
-            IpVersion::V6 => self.detect_ip_for_version::<V6>().await,
+        let ip = match ip_version {
+            IpVersion::V4 => self
+                .detect_ip_for_version::<V4>()
+                .await
+                .and_then(|ip| self.check_global_address(ip))?,
+            IpVersion::V6 => self
+                .detect_ip_for_version::<V6>()
+                .await
+                .and_then(|ip| self.check_global_address(ip))
+                .and_then(|ip| self.check_temporary_ipv6(ip))?,
+        };
+
+        if self.config.dns_cross_check {
+            self.cross_check_via_dns(ip, ip_version).await
+        } else {
+            Ok(ip)
         }
     }
 
+    /// Compares the address the services agreed on with the one the DNS
+    /// detection services see, so a hijacked or tampered service can't
+    /// redirect the records unnoticed. A mismatch is a warning, or an error
+    /// with `reject_dns_mismatch`. When DNS can't be asked at all the
+    /// services' address is kept, as there is nothing to compare against.
+    async fn cross_check_via_dns(
+        &self,
+        ip: IpAddr,
+        ip_version: IpVersion,
+    ) -> Result<IpAddr, IpDetectionError> {
+        let dns_ip = match self.detect_ip_via_dns(ip_version).await {
+            Ok(dns_ip) => dns_ip,
+            Err(e) => {
+                warn!(
+                    "DNS cross-check of {} not possible, keeping the detected address: {}",
+                    ip, e
+                );
+                return Ok(ip);
+            }
+        };
+
+        if dns_ip == ip {
+            debug!("DNS cross-check confirmed {}", ip);
+            return Ok(ip);
+        }
+
+        if self.config.reject_dns_mismatch {
+            warn!(
+                "Detection services agreed on {} but DNS answered {}, not updating",
+                ip, dns_ip
+            );
+            return Err(IpDetectionError::DnsMismatch {
+                detected: ip,
+                dns: dns_ip,
+            });
+        }
+        warn!(
+            "Detection services agreed on {} but DNS answered {}, \
+             set reject_dns_mismatch to refuse such addresses",
+            ip, dns_ip
+        );
+        Ok(ip)
+    }
+
+    /// Rejects an agreed-on address that can't be published in DNS, e.g. a
+    /// link-local or documentation address a service answered with.
+    /// Private ranges pass with `allow_private_addresses`, for networks
+    /// where the services are reached without NAT.
+    fn check_global_address(&self, ip: IpAddr) -> Result<IpAddr, IpDetectionError> {
+        if self.is_publishable_address(&ip) {
+            return Ok(ip);
+        }
+        warn!(
+            "Detected address {} is not globally routable, not updating",
+            ip
+        );
+        Err(IpDetectionError::NonGlobalAddress(ip))
+    }
+
+    /// Warns if `ip` is a temporary IPv6 address of this host, since privacy
+    /// extensions rotate it and the record would keep changing.
+    /// With `reject_temporary_ipv6` such an address is an error instead.
+    fn check_temporary_ipv6(&self, ip: IpAddr) -> Result<IpAddr, IpDetectionError> {
+        let IpAddr::V6(ipv6) = ip else {
+            return Ok(ip);
+        };
+        if is_temporary_ipv6(&ipv6) != Some(true) {
+            return Ok(ip);
+        }
+        if self.config.reject_temporary_ipv6 {
+            warn!("Detected IPv6 {} is a temporary address, not updating", ip);
+            return Err(IpDetectionError::TemporaryIpv6(ipv6));
+        }
+        warn!(
+            "Detected IPv6 {} is a temporary address that will rotate; \
+             set reject_temporary_ipv6 to skip it",
+            ip
+        );
+        Ok(ip)
+    }
+
+    /// Asks the DNS detection services in turn and returns the first valid
+    /// answer. Each query goes out over the IP version being detected, since
+    /// the servers answer with the address the query came from.
+    async fn detect_ip_via_dns(&self, ip_version: IpVersion) -> Result<IpAddr, IpDetectionError> {
+        let mut last_error = None;
+        for service in &DNS_SERVICES {
+            match self.query_dns_service(service, ip_version).await {
+                Ok(ip) => {
+                    debug!(
+                        "Successfully got IP {} from DNS service {}",
+                        ip, service.name
+                    );
+                    return Ok(ip);
+                }
+                Err(e) => {
+                    warn!("Failed to query DNS service {}: {}", service.name, e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or(IpDetectionError::NoServicesAvailable))
+    }
+
+    /// Sends the query of one DNS detection service to its server.
+    /// Nothing is cached, so every query reaches the server.
+    async fn query_dns_service(
+        &self,
+        service: &DnsIpService,
+        ip_version: IpVersion,
+    ) -> Result<IpAddr, IpDetectionError> {
+        let (server, address_type) = match ip_version {
+            IpVersion::V4 => (IpAddr::V4(service.ipv4_server), RecordType::A),
+            IpVersion::V6 => (IpAddr::V6(service.ipv6_server), RecordType::AAAA),
+        };
+        let record_type = match service.answer {
+            DnsAnswer::Address => address_type,
+            DnsAnswer::Txt => RecordType::TXT,
+        };
+
+        let config = ResolverConfig::from_parts(
+            None,
+            Vec::new(),
+            NameServerConfigGroup::from_ips_clear(&[server], DNS_PORT, true),
+        );
+        let mut options = ResolverOpts::default();
+        options.timeout = Duration::from_secs(self.config.request_timeout_secs);
+        options.attempts = self.config.max_retries as usize;
+        options.cache_size = 0;
+        options.use_hosts_file = false;
+        let resolver = TokioAsyncResolver::tokio(config, options);
+
+        let lookup = resolver
+            .lookup(service.name, record_type)
+            .await
+            .map_err(|e| IpDetectionError::DnsQueryFailed {
+                service: service.name.to_string(),
+                error: e.to_string(),
+            })?;
+        let ip = lookup
+            .iter()
+            .find_map(|rdata| match rdata {
+                RData::A(a) => Some(IpAddr::V4(a.0)),
+                RData::AAAA(aaaa) => Some(IpAddr::V6(aaaa.0)),
+                RData::TXT(txt) => txt.to_string().trim().parse().ok(),
+                _ => None,
+            })
+            .ok_or_else(|| IpDetectionError::ParseError {
+                service: service.name.to_string(),
+                error: "no address in the DNS answer".to_string(),
+            })?;
+
+        self.validate_ip_version(ip, ip_version, service.name)
+    }
+
+    /// Returns the first usable address of the given version on the configured interface
+    fn detect_ip_from_interface(&self, ip_version: IpVersion) -> Result<IpAddr, IpDetectionError> {
+        let interface = self.config.interface.as_deref().unwrap_or_default().trim();
+        let addresses = if_addrs::get_if_addrs().map_err(IpDetectionError::InterfaceError)?;
+
+        addresses
+            .iter()
+            .filter(|address| address.name == interface)
+            .map(|address| address.ip())
+            .find(|ip| {
+                let matches_version = match ip_version {
+                    IpVersion::V4 => ip.is_ipv4(),
+                    IpVersion::V6 => ip.is_ipv6(),
+                };
+                matches_version
+                    && self.is_publishable_address(ip)
+                    && !self.is_rejected_temporary(ip)
+            })
+            .inspect(|ip| debug!("Using {} from interface {}", ip, interface))
+            .ok_or_else(|| IpDetectionError::NoInterfaceAddress {
+                interface: interface.to_string(),
+                version: ip_version,
+            })
+    }
+
+    /// Checks whether `ip` is a temporary IPv6 address that
+    /// `reject_temporary_ipv6` keeps from being used, so a stable address
+    /// of the interface is picked instead.
+    fn is_rejected_temporary(&self, ip: &IpAddr) -> bool {
+        match ip {
+            IpAddr::V6(ipv6) if self.config.reject_temporary_ipv6 => {
+                is_temporary_ipv6(ipv6) == Some(true)
+            }
+            _ => false,
+        }
+    }
+
+    /// Checks whether an address can be published in DNS.
+    /// Loopback, unspecified, multicast and documentation addresses never
+    /// qualify; private, shared, unique-local and link-local ones only with
+    /// `allow_private_addresses`.
+    fn is_publishable_address(&self, ip: &IpAddr) -> bool {
+        let (is_unusable, is_private) = match ip {
+            IpAddr::V4(ipv4) => (
+                ipv4.is_loopback()
+                    || ipv4.is_unspecified()
+                    || ipv4.is_multicast()
+                    || ipv4.is_broadcast()
+                    // TEST-NET-1, TEST-NET-2 and TEST-NET-3
+                    || matches!(
+                        ipv4.octets(),
+                        [192, 0, 2, _] | [198, 51, 100, _] | [203, 0, 113, _]
+                    ),
+                ipv4.is_private()
+                    || ipv4.is_link_local()
+                    // Shared address space used for CGNAT (100.64.0.0/10)
+                    || (ipv4.octets()[0] == 100 && (ipv4.octets()[1] & 0xc0) == 64),
+            ),
+            IpAddr::V6(ipv6) => (
+                ipv6.is_loopback()
+                    || ipv6.is_unspecified()
+                    || ipv6.is_multicast()
+                    // Documentation prefix 2001:db8::/32
+                    || matches!(ipv6.segments(), [0x2001, 0x0db8, ..]),
+                ipv6.is_unique_local() || ipv6.is_unicast_link_local(),
+            ),
+        };
+
+        !is_unusable && (!is_private || self.config.allow_private_addresses)
+    }
+
+    /// Returns the position of the primary service to query first, and moves
+    /// the rotation on by one for the next detection of this version.
+    async fn next_primary_start(&self, version: IpVersion, primaries: usize) -> usize {
+        if primaries == 0 {
+            return 0;
+        }
+        let mut rotation = self.primary_rotation.write().await;
+        let next = rotation.entry(version).or_default();
+        let start = *next % primaries;
+        *next = start + 1;
+        start
+    }
+
     /// Generic IP detection for a specific version
     async fn detect_ip_for_version<V: IpVersionOps>(&self) -> Result<IpAddr, IpDetectionError> {
         let mut responses = Vec::new();
         let mut errors = Vec::new();
-        let services = V::get_services();
-        let offset = V::rate_limiter_offset();
-        let min_consensus = self.config.min_consensus as usize;
+        let services = V::get_services(self);
+        let offset = V::rate_limiter_offset(self);
+        // With the "first" strategy the first valid answer already is consensus
+        let min_consensus = self.config.required_consensus();
         let version = V::version();
+        let started = Instant::now();
 
         // Helper function to check consensus and cleanup
         let check_consensus_and_cleanup =
             |responses: &[IpResponse],
              version: IpVersion,
-             rate_limiter_idx: usize,
              suspended_versions: &Arc<RwLock<HashMap<IpVersion, VersionSuspension>>>|
              -> Option<Result<IpAddr, IpDetectionError>> {
-                if let Ok(consensus_ip) = self.check_consensus(responses, min_consensus) {
+                let consensus_ip = self
+                    .check_consensus(responses, min_consensus)
+                    .or_else(|| self.check_partial_consensus(responses, started, version));
+                if let Some(consensus_ip) = consensus_ip {
                     // Clone the Arc before moving into the spawned task
                     let suspended_versions = Arc::clone(suspended_versions);
-                    let rate_limiter = Arc::clone(&self.rate_limiters[rate_limiter_idx]);
                     tokio::spawn(async move {
-                        rate_limiter.release().await;
                         suspended_versions.write().await.remove(&version);
                     });
                     return Some(Ok(consensus_ip));
@@ -170,10 +821,19 @@ impl IpDetector {
             errors: &mut Vec<IpDetectionError>,
             check_consensus: impl Fn(&[IpResponse]) -> Option<Result<IpAddr, IpDetectionError>>,
         ) -> Option<Result<IpAddr, IpDetectionError>> {
+            // Persistently failing services are skipped until their cooldown ends
+            if detector.is_breaker_open(service).await {
+                debug!(
+                    "Skipping IP service {}, its circuit breaker is open",
+                    service.base_url
+                );
+                return None;
+            }
+
             // Check rate limit
             if !detector.rate_limiters[rate_limiter_idx].acquire().await {
                 errors.push(IpDetectionError::RateLimitExceeded {
-                    service: service.base_url.to_string(),
+                    service: service.base_url.clone(),
                 });
                 return None;
             }
@@ -187,7 +847,9 @@ impl IpDetector {
                     responses.push(IpResponse {
                         ip,
                         is_primary: service.is_primary,
+                        service: service.base_url.clone(),
                     });
+                    detector.record_service_result(service, true).await;
 
                     // Check if we have consensus
                     check_consensus(responses)
@@ -195,19 +857,19 @@ impl IpDetector {
                 Err(e) => {
                     error!("Failed to query IP service {}: {}", service.base_url, e);
                     errors.push(e);
+                    detector.record_service_result(service, false).await;
                     None
                 }
             };
 
-            detector.rate_limiters[rate_limiter_idx].release().await;
             result
         }
 
-        // Helper function to try services until consensus is reached
+        // Helper function to try services, each paired with its rate limiter
+        // index, until consensus is reached
         async fn try_services<'a>(
             detector: &'a IpDetector,
-            services: &[&'a IpService],
-            base_offset: usize,
+            services: &[(usize, &'a IpService)],
             version: IpVersion,
             responses: &mut Vec<IpResponse>,
             errors: &mut Vec<IpDetectionError>,
@@ -215,13 +877,11 @@ impl IpDetector {
             check_consensus_and_cleanup: impl Fn(
                 &[IpResponse],
                 IpVersion,
-                usize,
                 &Arc<RwLock<HashMap<IpVersion, VersionSuspension>>>,
             )
                 -> Option<Result<IpAddr, IpDetectionError>>,
         ) -> Option<Result<IpAddr, IpDetectionError>> {
-            for (idx, service) in services.iter().enumerate() {
-                let rate_limiter_idx = idx + base_offset;
+            for &(rate_limiter_idx, service) in services {
                 if let Some(result) = query_service(
                     detector,
                     service,
@@ -229,14 +889,7 @@ impl IpDetector {
                     version,
                     responses,
                     errors,
-                    |responses| {
-                        check_consensus_and_cleanup(
-                            responses,
-                            version,
-                            rate_limiter_idx,
-                            suspended_versions,
-                        )
-                    },
+                    |responses| check_consensus_and_cleanup(responses, version, suspended_versions),
                 )
                 .await
                 {
@@ -246,12 +899,21 @@ impl IpDetector {
             None
         }
 
-        // Try primary services first
-        let primary_services: Vec<_> = services.iter().filter(|s| s.is_primary).collect();
+        // Try primary services first, starting at a different one every time
+        // so that no single service takes the brunt of the queries
+        let mut primary_services: Vec<_> = services
+            .iter()
+            .filter(|s| s.is_primary)
+            .enumerate()
+            .map(|(idx, service)| (offset + idx, service))
+            .collect();
+        let start = self
+            .next_primary_start(version, primary_services.len())
+            .await;
+        primary_services.rotate_left(start);
         if let Some(result) = try_services(
             self,
             &primary_services,
-            offset,
             version,
             &mut responses,
             &mut errors,
@@ -264,11 +926,15 @@ impl IpDetector {
         }
 
         // If no consensus from primary services, try secondary services
-        let secondary_services: Vec<_> = services.iter().filter(|s| !s.is_primary).collect();
+        let secondary_services: Vec<_> = services
+            .iter()
+            .filter(|s| !s.is_primary)
+            .enumerate()
+            .map(|(idx, service)| (offset + primary_services.len() + idx, service))
+            .collect();
         if let Some(result) = try_services(
             self,
             &secondary_services,
-            offset + primary_services.len(),
             version,
             &mut responses,
             &mut errors,
@@ -280,6 +946,12 @@ impl IpDetector {
             return result;
         }
 
+        // Every service has been asked; a partial consensus may still do
+        if let Some(ip) = self.check_partial_consensus(&responses, started, version) {
+            self.suspended_versions.write().await.remove(&version);
+            return Ok(ip);
+        }
+
         // Handle failures and suspension
         let mut suspended_versions = self.suspended_versions.write().await;
         match suspended_versions.get_mut(&version) {
@@ -305,31 +977,75 @@ impl IpDetector {
         }
 
         // If we get here, we don't have consensus
+        let observed = ObservedIps::from_responses(&responses);
+        if observed.0.len() > 1 {
+            warn!("{:?} detection services disagree: {}", version, observed);
+        }
         Err(IpDetectionError::ConsensusNotReached {
             responses: responses.len(),
-            required: self.config.min_consensus,
+            failed: errors.len(),
+            required: min_consensus as u32,
+            observed,
         })
     }
 
     /// Check if we have consensus among the responses
-    fn check_consensus(
-        &self,
-        responses: &[IpResponse],
-        min_consensus: usize,
-    ) -> Result<IpAddr, IpDetectionError> {
+    /// With `weighted_consensus`, primary responses count `primary_weight` times.
+    fn check_consensus(&self, responses: &[IpResponse], min_consensus: usize) -> Option<IpAddr> {
         let mut ip_counts = HashMap::new();
         for response in responses {
-            *ip_counts.entry(response.ip).or_insert(0) += 1;
+            let weight = if self.config.weighted_consensus && response.is_primary {
+                self.config.primary_weight as usize
+            } else {
+                1
+            };
+            *ip_counts.entry(response.ip).or_insert(0) += weight;
 
             // If any IP has reached the minimum consensus, return it
             if ip_counts[&response.ip] >= min_consensus {
-                return Ok(response.ip);
+                return Some(response.ip);
             }
         }
-        Err(IpDetectionError::ConsensusNotReached {
-            responses: responses.len(),
-            required: self.config.min_consensus,
-        })
+        None
+    }
+
+    /// Falls back to the address most services agree on once the detection
+    /// has run for `accept_partial_after_secs`, if at least
+    /// `MIN_PARTIAL_CONSENSUS` responses agree and no other address ties with it.
+    fn check_partial_consensus(
+        &self,
+        responses: &[IpResponse],
+        started: Instant,
+        version: IpVersion,
+    ) -> Option<IpAddr> {
+        let deadline = Duration::from_secs(self.config.accept_partial_after_secs?);
+        if started.elapsed() < deadline {
+            return None;
+        }
+
+        let observed = ObservedIps::from_responses(responses);
+        let mut counts: Vec<(usize, IpAddr)> = observed
+            .0
+            .iter()
+            .map(|(ip, services)| (services.len(), *ip))
+            .collect();
+        counts.sort_unstable_by_key(|(agreeing, _)| Reverse(*agreeing));
+        let (agreeing, ip) = *counts.first()?;
+        if agreeing < MIN_PARTIAL_CONSENSUS || counts.get(1).is_some_and(|next| next.0 == agreeing)
+        {
+            return None;
+        }
+
+        warn!(
+            "{:?} consensus not reached after {} seconds, accepting {} from {} of {} responses: {}",
+            version,
+            deadline.as_secs(),
+            ip,
+            agreeing,
+            responses.len(),
+            observed
+        );
+        Some(ip)
     }
 
     /// Query IP service with retry logic
@@ -340,13 +1056,14 @@ impl IpDetector {
     ) -> Result<IpAddr, IpDetectionError> {
         let mut last_error = None;
 
-        for retry in 0..MAX_RETRIES {
+        let max_retries = self.config.max_retries;
+        for retry in 0..max_retries {
             return match self.query_ip_service(service, ip_version).await {
                 Ok(ip) => Ok(ip),
                 Err(e) => {
-                    if retry < MAX_RETRIES - 1 {
+                    if retry < max_retries - 1 {
                         warn!("Query failed for {}, retrying: {}", service.base_url, e);
-                        tokio::time::sleep(Duration::from_millis(RETRY_DELAY_MS)).await;
+                        tokio::time::sleep(Duration::from_millis(self.config.retry_delay_ms)).await;
                         last_error = Some(e);
                         continue;
                     }
@@ -370,7 +1087,7 @@ impl IpDetector {
                 .send()
                 .await
                 .map_err(|e| IpDetectionError::NetworkError {
-                    service: service.base_url.to_string(),
+                    service: service.base_url.clone(),
                     error: e,
                 })?;
 
@@ -378,44 +1095,91 @@ impl IpDetector {
             .text()
             .await
             .map_err(|e| IpDetectionError::NetworkError {
-                service: service.base_url.to_string(),
+                service: service.base_url.clone(),
                 error: e,
             })?;
 
+        // A service that names its JSON field must answer with it
+        if let Some(field) = &service.json_field {
+            let ip = serde_json::from_str::<serde_json::Value>(&text)
+                .ok()
+                .and_then(|json| parse_ip_answer(json_path(&json, field)?.as_str()?).ok())
+                .ok_or_else(|| IpDetectionError::ParseError {
+                    service: service.base_url.clone(),
+                    error: format!("no IP address in JSON field '{}'", field),
+                })?;
+            return self.validate_ip_version(ip, ip_version, &service.base_url);
+        }
+
         // Try to parse as JSON first (for services that return JSON)
         if text.trim().starts_with('{') {
-            if let Ok(json) = serde_json::from_str::<serde_json::Value>(&text) {
-                // Try common JSON fields for IP addresses
-                for field in ["ip", "address", "ipAddress", "query"] {
-                    if let Some(ip_str) = json.get(field).and_then(|v| v.as_str()) {
-                        if let Ok(ip) = ip_str.parse() {
-                            return self.validate_ip_version(ip, ip_version, service);
-                        }
+            let json = serde_json::from_str::<serde_json::Value>(&text).ok();
+            // Addresses in the trusted JSON fields, in the configured order
+            let candidates: Vec<IpAddr> = json
+                .iter()
+                .flat_map(|json| {
+                    self.config
+                        .json_ip_fields
+                        .iter()
+                        .filter_map(|field| json.get(field)?.as_str())
+                })
+                .filter_map(|ip_str| parse_ip_answer(ip_str).ok())
+                .collect();
+
+            // Strict mode never falls back to the raw body, and fields that
+            // disagree hint at a proxied or tampered answer
+            if self.config.strict_json {
+                let invalid = |reason: &str| IpDetectionError::InvalidResponse {
+                    service: service.base_url.clone(),
+                    reason: reason.to_string(),
+                };
+                return match candidates.split_first() {
+                    None => Err(invalid("no address in a trusted JSON field")),
+                    Some((ip, rest)) if rest.iter().any(|other| other != ip) => {
+                        Err(invalid("trusted JSON fields hold different addresses"))
                     }
-                }
+                    Some((ip, _)) => self.validate_ip_version(*ip, ip_version, &service.base_url),
+                };
+            }
+
+            if let Some(ip) = candidates.first() {
+                return self.validate_ip_version(*ip, ip_version, &service.base_url);
             }
         }
 
         // Try direct parsing if not JSON or JSON parsing failed
-        text.trim()
-            .parse()
-            .map_err(|e: std::net::AddrParseError| IpDetectionError::ParseError {
-                service: service.base_url.to_string(),
+        parse_ip_answer(&text)
+            .map_err(|e| IpDetectionError::ParseError {
+                service: service.base_url.clone(),
                 error: e.to_string(),
             })
-            .and_then(|ip| self.validate_ip_version(ip, ip_version, service))
+            .and_then(|ip| self.validate_ip_version(ip, ip_version, &service.base_url))
     }
 
     fn validate_ip_version(
         &self,
         ip: IpAddr,
         expected_version: IpVersion,
-        service: &IpService,
+        service: &str,
     ) -> Result<IpAddr, IpDetectionError> {
+        // An IPv6 address carrying an IPv4 one is never a usable AAAA,
+        // but answers IPv4 detection once unwrapped
+        if let IpAddr::V6(ipv6) = ip {
+            if let Some(ipv4) = embedded_ipv4(&ipv6) {
+                return match expected_version {
+                    IpVersion::V4 => Ok(IpAddr::V4(ipv4)),
+                    IpVersion::V6 => Err(IpDetectionError::EmbeddedIpv4 {
+                        service: service.to_string(),
+                        ip: ipv6,
+                    }),
+                };
+            }
+        }
+
         match (ip, expected_version) {
             (IpAddr::V4(_), IpVersion::V4) | (IpAddr::V6(_), IpVersion::V6) => Ok(ip),
             (got_ip, _) => Err(IpDetectionError::VersionMismatch {
-                service: service.base_url.to_string(),
+                service: service.to_string(),
                 expected: expected_version,
                 got: if matches!(got_ip, IpAddr::V4(_)) {
                     IpVersion::V4
@@ -428,10 +1192,10 @@ impl IpDetector {
 }
 
 impl IpVersionOps for V4 {
-    fn get_services() -> &'static [IpService] {
-        &IPV4_SERVICES
+    fn get_services(detector: &IpDetector) -> &[IpService] {
+        &detector.ipv4_services
     }
-    fn rate_limiter_offset() -> usize {
+    fn rate_limiter_offset(_detector: &IpDetector) -> usize {
         0
     }
     fn version() -> IpVersion {
@@ -440,17 +1204,26 @@ impl IpVersionOps for V4 {
 }
 
 impl IpVersionOps for V6 {
-    fn get_services() -> &'static [IpService] {
-        &IPV6_SERVICES
+    fn get_services(detector: &IpDetector) -> &[IpService] {
+        &detector.ipv6_services
     }
-    fn rate_limiter_offset() -> usize {
-        IPV4_SERVICES.len()
+    fn rate_limiter_offset(detector: &IpDetector) -> usize {
+        detector.ipv4_services.len()
     }
     fn version() -> IpVersion {
         IpVersion::V6
     }
 }
 
+impl From<&IpAddr> for IpVersion {
+    fn from(ip: &IpAddr) -> Self {
+        match ip {
+            IpAddr::V4(_) => IpVersion::V4,
+            IpAddr::V6(_) => IpVersion::V6,
+        }
+    }
+}
+
 impl VersionSuspension {
     pub fn new() -> Self {
         Self {
@@ -459,3 +1232,59 @@ impl VersionSuspension {
         }
     }
 }
+
+impl ObservedIps {
+    /// Groups the responses by reported address.
+    pub fn from_responses(responses: &[IpResponse]) -> Self {
+        let mut observed = Self::default();
+        for response in responses {
+            observed
+                .0
+                .entry(response.ip)
+                .or_default()
+                .push(response.service.clone());
+        }
+        observed
+    }
+}
+
+impl fmt::Display for ObservedIps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "no addresses");
+        }
+        for (index, (ip, services)) in self.0.iter().enumerate() {
+            if index > 0 {
+                write!(f, "; ")?;
+            }
+            write!(f, "{} from {}", ip, services.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detector() -> IpDetector {
+        IpDetector::new(IpDetection::default(), &HttpClientOptions::default())
+    }
+
+    #[test]
+    fn mapped_address_answers_ipv4_detection() {
+        let ip: IpAddr = "::ffff:192.0.2.1".parse().unwrap();
+        let validated = detector().validate_ip_version(ip, IpVersion::V4, "https://example.com");
+        assert_eq!(validated.unwrap(), "192.0.2.1".parse::<IpAddr>().unwrap());
+    }
+
+    #[test]
+    fn mapped_address_is_rejected_for_ipv6_detection() {
+        let ip: IpAddr = "::ffff:192.0.2.1".parse().unwrap();
+        let validated = detector().validate_ip_version(ip, IpVersion::V6, "https://example.com");
+        assert!(matches!(
+            validated,
+            Err(IpDetectionError::EmbeddedIpv4 { .. })
+        ));
+    }
+}
@@ -57,13 +57,7 @@
 
 pub mod constants;
 pub mod errors;
+pub mod functions;
 pub mod impls;
 pub mod traits;
 pub mod types;
-
-// Re-export commonly used items
-pub use constants::*;
-pub use errors::*;
-pub use impls::detector::IpDetector;
-pub use traits::*;
-pub use types::*;
@@ -1,12 +1,12 @@
 // Project imports
-use super::types::{IpService, IpVersion};
+use super::types::{IpDetector, IpService, IpVersion};
 
 /// Trait for IP version-specific operations
 pub trait IpVersionOps {
-    /// Get the services for this IP version
-    fn get_services() -> &'static [IpService];
+    /// Get the detector's services for this IP version
+    fn get_services(detector: &IpDetector) -> &[IpService];
     /// Get the rate limiter offset for this IP version
-    fn rate_limiter_offset() -> usize;
+    fn rate_limiter_offset(detector: &IpDetector) -> usize;
     /// Get the version enum for this IP version
     fn version() -> IpVersion;
 }
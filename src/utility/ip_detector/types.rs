@@ -1,7 +1,7 @@
 // Standard library
-use std::collections::HashMap;
-use std::net::IpAddr;
-use std::sync::Arc;
+use std::collections::{BTreeMap, HashMap};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::{Arc, OnceLock};
 use std::time::Instant;
 
 // 3rd party crates
@@ -12,20 +12,155 @@ use tokio::sync::RwLock;
 use crate::utility::rate_limiter::traits::RateLimiter;
 
 use super::constants::{
-    default_max_requests_per_hour, default_min_consensus, default_network_retry_interval,
+    default_json_ip_fields, default_max_requests_per_hour, default_max_retries,
+    default_min_consensus, default_network_retry_interval, default_primary_version,
+    default_primary_weight, default_request_timeout_secs, default_retry_delay_ms,
+    default_stable_cycles,
 };
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct IpDetection {
+    /// How the public IP is detected
+    #[serde(default)]
+    pub method: DetectionMethod,
+    /// Network interface to read addresses from when `method` is "interface"
+    #[serde(default)]
+    pub interface: Option<String>,
+    /// Accept private, shared (CGNAT), unique-local and link-local addresses,
+    /// from an interface or as the detected address
+    #[serde(default)]
+    pub allow_private_addresses: bool,
+    /// Never publish temporary (privacy extension) IPv6 addresses
+    #[serde(default)]
+    pub reject_temporary_ipv6: bool,
     /// Maximum requests per hour to each IP detection service
     #[serde(default = "default_max_requests_per_hour")]
     pub max_requests_per_hour: u32,
+    /// Whether services must agree on the IP or the first answer is trusted
+    #[serde(default)]
+    pub strategy: DetectionStrategy,
     /// Minimum number of services that must agree on the IP
     #[serde(default = "default_min_consensus")]
     pub min_consensus: u32,
-    /// Network check interval when connectivity is lost (in seconds)
+    /// Count primary service responses `primary_weight` times towards consensus
+    #[serde(default)]
+    pub weighted_consensus: bool,
+    /// Weight of a primary service response when `weighted_consensus` is on
+    #[serde(default = "default_primary_weight")]
+    pub primary_weight: u32,
+    /// Longest delay between network checks while connectivity is lost (in seconds)
     #[serde(default = "default_network_retry_interval")]
     pub network_retry_interval: u64,
+    /// HTTP timeout for a single detection service request (in seconds)
+    #[serde(default = "default_request_timeout_secs")]
+    pub request_timeout_secs: u64,
+    /// Attempts per detection service query, the first one included
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    /// Delay between the attempts of a detection service query (in milliseconds)
+    #[serde(default = "default_retry_delay_ms")]
+    pub retry_delay_ms: u64,
+    /// Additional services to query, e.g. a self-hosted echo service
+    #[serde(default)]
+    pub custom_services: Vec<CustomIpService>,
+    /// Use only `custom_services` instead of adding them to the built-in list
+    #[serde(default)]
+    pub replace_builtin_services: bool,
+    /// JSON fields an address is read from, in order, for services without a `json_field`
+    #[serde(default = "default_json_ip_fields")]
+    pub json_ip_fields: Vec<String>,
+    /// Reject JSON answers without an address in `json_ip_fields`, or whose
+    /// fields hold different addresses, instead of parsing the raw body
+    #[serde(default)]
+    pub strict_json: bool,
+    /// Confirm the address the services agreed on through DNS detection,
+    /// an independent channel, and warn when the two disagree
+    #[serde(default)]
+    pub dns_cross_check: bool,
+    /// Refuse to use an address the DNS cross-check disagrees with
+    #[serde(default)]
+    pub reject_dns_mismatch: bool,
+    /// Fixed IPv4 address used instead of detecting one
+    #[serde(default)]
+    pub override_ipv4: Option<Ipv4Addr>,
+    /// Fixed IPv6 address used instead of detecting one
+    #[serde(default)]
+    pub override_ipv6: Option<Ipv6Addr>,
+    /// Consecutive cycles a changed IP must be detected before it is pushed
+    #[serde(default = "default_stable_cycles")]
+    pub stable_cycles: u32,
+    /// Seconds into a detection after which fewer agreeing services than
+    /// `min_consensus` are accepted, as long as at least two agree
+    #[serde(default)]
+    pub accept_partial_after_secs: Option<u64>,
+    /// IP version whose detection failures are logged as warnings, the other one at debug level
+    #[serde(default = "default_primary_version")]
+    pub primary_version: IpVersion,
+}
+
+/// Source of the detected public IP
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DetectionMethod {
+    /// Ask external services and require consensus
+    #[default]
+    Services,
+    /// Read the address bound to a local network interface
+    Interface,
+    /// Ask DNS servers that answer with the address the query came from
+    Dns,
+}
+
+/// A DNS server that answers a special query with the client's address
+#[derive(Debug, Clone, Copy)]
+pub struct DnsIpService {
+    /// The name to query
+    pub name: &'static str,
+    /// Whether the address comes as an A/AAAA or a TXT record
+    pub answer: DnsAnswer,
+    /// Server queried for the IPv4 address
+    pub ipv4_server: Ipv4Addr,
+    /// Server queried for the IPv6 address
+    pub ipv6_server: Ipv6Addr,
+}
+
+/// Record type a DNS detection service answers with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DnsAnswer {
+    /// An A or AAAA record, matching the detected IP version
+    Address,
+    /// A TXT record holding the address as text
+    Txt,
+}
+
+/// How the answers of the detection services are combined
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DetectionStrategy {
+    /// Wait until `min_consensus` services agree (default)
+    #[default]
+    Consensus,
+    /// Use the first valid answer, for connections the user trusts
+    First,
+}
+
+/// A user-defined IP detection service
+#[derive(Debug, Deserialize, Clone)]
+pub struct CustomIpService {
+    /// Base URL of the service (e.g., "https://ip.example.com")
+    pub url: String,
+    /// Path appended to the URL
+    #[serde(default)]
+    pub path: String,
+    /// Whether the service is queried in the first round
+    #[serde(default)]
+    pub is_primary: bool,
+    /// The IP version the service returns ("v4" or "v6")
+    pub version: IpVersion,
+    /// JSON field holding the address, as a dotted path for nested fields
+    /// (e.g. "data.ip"); without one the common field names are tried
+    #[serde(default)]
+    pub json_field: Option<String>,
 }
 
 /// Suspension state for an IP version
@@ -35,34 +170,71 @@ pub struct VersionSuspension {
     pub consecutive_failures: u32,
 }
 
+/// Circuit breaker state of a single detection service.
+/// After `BREAKER_FAILURE_THRESHOLD` consecutive failures the breaker opens
+/// and the service is skipped; once the cooldown is over the next query is
+/// a probe that closes the breaker on success or reopens it on failure.
+#[derive(Debug, Default)]
+pub struct BreakerState {
+    pub consecutive_failures: u32,
+    /// When the breaker last opened, if it is open
+    pub opened_at: Option<Instant>,
+}
+
+/// A changed IP that has not yet been detected for `stable_cycles` cycles
+#[derive(Debug)]
+pub struct IpCandidate {
+    pub ip: IpAddr,
+    pub cycles: u32,
+}
+
 pub struct IpDetector {
     pub config: IpDetection,
+    pub ipv4_services: Vec<IpService>,
+    pub ipv6_services: Vec<IpService>,
     pub rate_limiters: Vec<Arc<dyn RateLimiter>>,
     pub client: reqwest::Client,
     pub suspended_versions: Arc<RwLock<HashMap<IpVersion, VersionSuspension>>>,
+    pub candidates: Arc<RwLock<HashMap<IpVersion, IpCandidate>>>,
+    /// Circuit breakers keyed by service base URL
+    pub breakers: Arc<RwLock<HashMap<String, BreakerState>>>,
+    /// Position of the primary service queried first in the next detection
+    pub primary_rotation: Arc<RwLock<HashMap<IpVersion, usize>>>,
+    /// Whether the host has an IPv6 route, probed on first use
+    pub ipv6_available: OnceLock<bool>,
 }
 
 /// Service configuration for IP detection
+#[derive(Debug, Clone)]
 pub struct IpService {
-    pub base_url: &'static str,
-    pub path: &'static str,
+    pub base_url: String,
+    pub path: String,
     pub is_primary: bool,
+    /// Dotted path of the JSON field holding the address, if the service names one
+    pub json_field: Option<String>,
 }
 
 #[derive(Debug)]
-#[allow(unused)]
 pub struct IpResponse {
     pub ip: IpAddr,
     pub is_primary: bool,
+    /// Base URL of the service that reported the address
+    pub service: String,
 }
 
+/// Every address reported during a detection attempt, with the services
+/// that reported it
+#[derive(Debug, Default)]
+pub struct ObservedIps(pub BTreeMap<IpAddr, Vec<String>>);
+
 /// IPv4 version operations
 pub struct V4;
 
 /// IPv6 version operations
 pub struct V6;
 
-#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[derive(Debug, Deserialize, Clone, Copy, Eq, Hash, PartialEq)]
+#[serde(rename_all = "lowercase")]
 pub enum IpVersion {
     V4,
     V6,
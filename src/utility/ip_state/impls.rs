@@ -0,0 +1,70 @@
+// Standard library
+use std::path::Path;
+use std::{env, fs};
+
+// 3rd party crates
+use tracing::{debug, info, warn};
+
+// Current module imports
+use super::types::{IpState, StateStore};
+
+impl StateStore {
+    /// Creates a store at `FDDNS_STATE_PATH`, or next to the config file
+    /// as `state.json` when the variable is not set.
+    pub fn new(config_path: &Path) -> Self {
+        let path = match env::var("FDDNS_STATE_PATH") {
+            Ok(path) if !path.is_empty() => path.into(),
+            _ => config_path.with_file_name("state.json"),
+        };
+        Self { path }
+    }
+
+    /// Loads the persisted state.
+    /// A missing or unreadable file is treated as an empty state.
+    pub fn load(&self) -> IpState {
+        let content = match fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(e) => {
+                debug!("No IP state loaded from {:?}: {}", self.path, e);
+                return IpState::default();
+            }
+        };
+
+        match serde_json::from_str(&content) {
+            Ok(state) => {
+                info!("Loaded last known IPs from {:?}", self.path);
+                state
+            }
+            Err(e) => {
+                warn!("Ignoring corrupt IP state file {:?}: {}", self.path, e);
+                IpState::default()
+            }
+        }
+    }
+
+    /// Writes the state to disk. Failures are logged, never fatal.
+    pub fn save(&self, state: &IpState) {
+        let content = match serde_json::to_string_pretty(state) {
+            Ok(content) => content,
+            Err(e) => {
+                warn!("Failed to serialize IP state: {}", e);
+                return;
+            }
+        };
+
+        if let Some(parent_dir) = self.path.parent() {
+            if let Err(e) = fs::create_dir_all(parent_dir) {
+                warn!(
+                    "Failed to create IP state directory {:?}: {}",
+                    parent_dir, e
+                );
+                return;
+            }
+        }
+
+        match fs::write(&self.path, content) {
+            Ok(()) => debug!("Saved IP state to {:?}", self.path),
+            Err(e) => warn!("Failed to write IP state to {:?}: {}", self.path, e),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+// Standard library
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::path::PathBuf;
+
+// 3rd party crates
+use serde::{Deserialize, Serialize};
+
+/// Last public IP addresses that were successfully pushed to the providers.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct IpState {
+    #[serde(default)]
+    pub ipv4: Option<Ipv4Addr>,
+    #[serde(default)]
+    pub ipv6: Option<Ipv6Addr>,
+}
+
+/// Persists `IpState` as JSON so restarts don't force a full DNS update.
+#[derive(Debug, Clone)]
+pub struct StateStore {
+    pub path: PathBuf,
+}
@@ -1,2 +1,3 @@
 pub mod ip_detector;
+pub mod ip_state;
 pub mod rate_limiter;
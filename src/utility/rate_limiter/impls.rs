@@ -13,23 +13,58 @@ use super::types::{RateLimitConfig, TokenBucketRateLimiter};
 impl TokenBucketRateLimiter {
     /// Create a new token bucket rate limiter
     pub fn new(config: RateLimitConfig) -> Self {
+        let max_requests = config.max_requests as usize;
         Self {
-            semaphore: Arc::new(Semaphore::new(config.max_requests as usize)),
+            semaphore: Arc::new(Semaphore::new(max_requests)),
+            max_requests,
             window: Duration::from_secs(config.window_secs),
             last_refill: tokio::sync::Mutex::new(Instant::now()),
+            paused_until: tokio::sync::Mutex::new(None),
         }
     }
 
-    /// Refill the token bucket if enough time has passed
+    /// Time it takes to replenish a single token
+    fn refill_interval(&self) -> Duration {
+        if self.max_requests == 0 {
+            return self.window;
+        }
+        self.window / self.max_requests as u32
+    }
+
+    /// Replenish tokens in proportion to the time elapsed since the last refill.
+    /// Tokens accrue at `max_requests` per `window` and are capped at `max_requests`,
+    /// so a burst early in a window recovers gradually instead of at a hard reset.
     async fn try_refill(&self) {
         let mut last_refill = self.last_refill.lock().await;
         let now = Instant::now();
+        let available = self.semaphore.available_permits();
+
+        if available >= self.max_requests {
+            // A full bucket doesn't bank time towards future tokens
+            *last_refill = now;
+            return;
+        }
+
+        let refill_interval = self.refill_interval();
+        if refill_interval.is_zero() {
+            self.semaphore.add_permits(self.max_requests - available);
+            *last_refill = now;
+            return;
+        }
+
         let elapsed = now.duration_since(*last_refill);
+        let earned = (elapsed.as_nanos() / refill_interval.as_nanos()) as usize;
+        if earned == 0 {
+            return;
+        }
 
-        if elapsed >= self.window {
-            self.semaphore
-                .add_permits(self.semaphore.available_permits());
+        let added = earned.min(self.max_requests - available);
+        self.semaphore.add_permits(added);
+        if available + added >= self.max_requests {
             *last_refill = now;
+        } else {
+            // Keep the fractional progress towards the next token
+            *last_refill += refill_interval * earned as u32;
         }
     }
 }
@@ -37,11 +72,97 @@ impl TokenBucketRateLimiter {
 #[async_trait]
 impl RateLimiter for TokenBucketRateLimiter {
     async fn acquire(&self) -> bool {
+        // Copy the instant out so the lock isn't held while sleeping
+        let paused_until = *self.paused_until.lock().await;
+        if let Some(until) = paused_until {
+            tokio::time::sleep_until(until).await;
+        }
+
+        self.try_refill().await;
+        match self.semaphore.try_acquire() {
+            Ok(permit) => {
+                // The token stays consumed until it is refilled
+                permit.forget();
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    async fn pause_until(&self, until: Instant) {
+        let mut paused_until = self.paused_until.lock().await;
+        if paused_until.is_none_or(|current| current < until) {
+            *paused_until = Some(until);
+        }
+    }
+
+    async fn available(&self) -> usize {
+        // Count the tokens earned since the last request as well
         self.try_refill().await;
-        self.semaphore.try_acquire().is_ok()
+        self.semaphore.available_permits()
+    }
+
+    fn max_requests(&self) -> usize {
+        self.max_requests
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limiter(max_requests: u32, window_secs: u64) -> TokenBucketRateLimiter {
+        TokenBucketRateLimiter::new(RateLimitConfig {
+            max_requests,
+            window_secs,
+        })
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn acquired_tokens_stay_spent() {
+        let limiter = limiter(3, 60);
+        for _ in 0..3 {
+            assert!(limiter.acquire().await);
+        }
+        assert!(!limiter.acquire().await);
+        let status = limiter.status().await;
+        assert_eq!((status.available, status.max_requests), (0, 3));
     }
 
-    async fn release(&self) {
-        self.semaphore.add_permits(1);
+    #[tokio::test(start_paused = true)]
+    async fn half_a_window_refills_half_the_capacity() {
+        let limiter = limiter(100, 60);
+        for _ in 0..100 {
+            assert!(limiter.acquire().await);
+        }
+        assert_eq!(limiter.available().await, 0);
+
+        tokio::time::advance(Duration::from_secs(30)).await;
+        let available = limiter.available().await;
+        assert!((49..=51).contains(&available), "{} tokens back", available);
+
+        tokio::time::advance(Duration::from_secs(30)).await;
+        assert_eq!(limiter.available().await, 100);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn acquire_across_window_boundary_stays_under_ceiling() {
+        let limiter = limiter(5, 10);
+        let mut granted = 0;
+        // Three windows of requests, far more often than tokens are earned
+        for _ in 0..300 {
+            if limiter.acquire().await {
+                granted += 1;
+            }
+            assert!(limiter.available().await <= 5);
+            assert!(limiter.semaphore.available_permits() <= 5);
+            tokio::time::advance(Duration::from_millis(100)).await;
+        }
+        // The initial bucket plus one token per refill interval (2s) over 30s
+        assert!(granted <= 5 + 15, "granted {} requests", granted);
+
+        // An idle stretch refills to the ceiling, never past it
+        tokio::time::advance(Duration::from_secs(60)).await;
+        assert_eq!(limiter.available().await, 5);
     }
 }
@@ -1,11 +1,26 @@
 // 3rd party crates
 use async_trait::async_trait;
+use tokio::time::Instant;
+
+use super::types::RateLimitStatus;
 
 /// Rate limiter trait for implementing different rate limiting strategies
 #[async_trait]
 pub trait RateLimiter: Send + Sync {
     /// Acquire permission to make a request
     async fn acquire(&self) -> bool;
-    /// Release a request slot
-    async fn release(&self);
+    /// Hold back every further acquire until `until`, e.g. after the API
+    /// answered with a `Retry-After`. An earlier pause is only ever extended.
+    async fn pause_until(&self, until: Instant);
+    /// Number of requests that could be made right now without waiting
+    async fn available(&self) -> usize;
+    /// Most requests that can be made in a row, i.e. the bucket size
+    fn max_requests(&self) -> usize;
+    /// Current tokens next to the bucket size, for diagnostics
+    async fn status(&self) -> RateLimitStatus {
+        RateLimitStatus {
+            available: self.available().await,
+            max_requests: self.max_requests(),
+        }
+    }
 }
@@ -15,9 +15,23 @@ pub struct RateLimitConfig {
     pub window_secs: u64,
 }
 
-/// A token bucket rate limiter implementation
+/// Tokens a rate limiter has left, as reported by the `status` command
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitStatus {
+    /// Requests that can be made right now without waiting
+    pub available: usize,
+    /// Size of the bucket, i.e. the requests allowed per window
+    pub max_requests: usize,
+}
+
+/// A token bucket rate limiter implementation.
+/// Tokens are replenished continuously at `max_requests` per `window`.
 pub struct TokenBucketRateLimiter {
     pub semaphore: Arc<Semaphore>,
+    /// Ceiling for the available permits
+    pub max_requests: usize,
     pub window: Duration,
     pub last_refill: tokio::sync::Mutex<Instant>,
+    /// Acquires wait until this instant, set when the API reports a rate limit
+    pub paused_until: tokio::sync::Mutex<Option<Instant>>,
 }